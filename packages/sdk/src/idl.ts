@@ -30,41 +30,13 @@ export type Referral = {
           isMut: false;
           isSigner: false;
         },
-      ];
-      args: [
-        {
-          name: "params";
-          type: {
-            defined: "InitializeProjectParams";
-          };
-        },
-      ];
-    },
-    {
-      name: "initializeReferralAccount";
-      accounts: [
-        {
-          name: "payer";
-          isMut: true;
-          isSigner: true;
-        },
-        {
-          name: "partner";
-          isMut: false;
-          isSigner: false;
-        },
         {
-          name: "project";
+          name: "eventAuthority";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "referralAccount";
-          isMut: true;
-          isSigner: true;
-        },
-        {
-          name: "systemProgram";
+          name: "program";
           isMut: false;
           isSigner: false;
         },
@@ -73,60 +45,32 @@ export type Referral = {
         {
           name: "params";
           type: {
-            defined: "InitializeReferralAccountParams";
+            defined: "InitializeProjectParams";
           };
         },
       ];
     },
     {
-      name: "initializeReferralAccountWithName";
+      name: "updateProject";
       accounts: [
         {
-          name: "payer";
-          isMut: true;
-          isSigner: true;
-        },
-        {
-          name: "partner";
+          name: "admin";
           isMut: false;
-          isSigner: false;
+          isSigner: true;
         },
         {
           name: "project";
-          isMut: false;
-          isSigner: false;
-        },
-        {
-          name: "referralAccount";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "systemProgram";
+          name: "eventAuthority";
           isMut: false;
           isSigner: false;
         },
-      ];
-      args: [
-        {
-          name: "params";
-          type: {
-            defined: "InitializeReferralAccountWithNameParams";
-          };
-        },
-      ];
-    },
-    {
-      name: "updateProject";
-      accounts: [
         {
-          name: "admin";
+          name: "program";
           isMut: false;
-          isSigner: true;
-        },
-        {
-          name: "project";
-          isMut: true;
           isSigner: false;
         },
       ];
@@ -157,6 +101,16 @@ export type Referral = {
           isMut: true;
           isSigner: false;
         },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        },
       ];
       args: [
         {
@@ -185,6 +139,16 @@ export type Referral = {
           isMut: true;
           isSigner: false;
         },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        },
       ];
       args: [
         {
@@ -196,21 +160,26 @@ export type Referral = {
       ];
     },
     {
-      name: "transferReferralAccount";
+      name: "updateReferralAccounts";
       accounts: [
         {
-          name: "partner";
+          name: "admin";
           isMut: false;
           isSigner: true;
         },
         {
-          name: "newPartner";
+          name: "project";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "referralAccount";
-          isMut: true;
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
           isSigner: false;
         },
       ];
@@ -218,16 +187,16 @@ export type Referral = {
         {
           name: "params";
           type: {
-            defined: "TransferReferralAccountParams";
+            defined: "UpdateReferralAccountsParams";
           };
         },
       ];
     },
     {
-      name: "initializeReferralTokenAccount";
+      name: "withdrawFromProject";
       accounts: [
         {
-          name: "payer";
+          name: "admin";
           isMut: true;
           isSigner: true;
         },
@@ -237,44 +206,46 @@ export type Referral = {
           isSigner: false;
         },
         {
-          name: "referralAccount";
-          isMut: false;
-          isSigner: false;
-        },
-        {
-          name: "referralTokenAccount";
+          name: "projectAuthority";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "mint";
+          name: "systemProgram";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "systemProgram";
+          name: "eventAuthority";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "tokenProgram";
+          name: "program";
           isMut: false;
           isSigner: false;
         },
       ];
-      args: [];
+      args: [
+        {
+          name: "params";
+          type: {
+            defined: "WithdrawFromProjectParams";
+          };
+        },
+      ];
     },
     {
-      name: "claim";
+      name: "createAdminTokenAccount";
       accounts: [
         {
-          name: "payer";
+          name: "project";
           isMut: true;
-          isSigner: true;
+          isSigner: false;
         },
         {
-          name: "project";
-          isMut: false;
+          name: "projectAuthority";
+          isMut: true;
           isSigner: false;
         },
         {
@@ -288,42 +259,50 @@ export type Referral = {
           isSigner: false;
         },
         {
-          name: "referralAccount";
+          name: "mint";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "referralTokenAccount";
-          isMut: true;
+          name: "systemProgram";
+          isMut: false;
           isSigner: false;
         },
         {
-          name: "partner";
+          name: "tokenProgram";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "partnerTokenAccount";
-          isMut: true;
+          name: "associatedTokenProgram";
+          isMut: false;
           isSigner: false;
         },
         {
-          name: "mint";
+          name: "mintPolicy";
           isMut: false;
           isSigner: false;
+          isOptional: true;
         },
         {
-          name: "associatedTokenProgram";
+          name: "referralAccount";
           isMut: false;
           isSigner: false;
+          isOptional: true;
         },
         {
-          name: "systemProgram";
+          name: "referralTokenAccount";
           isMut: false;
           isSigner: false;
+          isOptional: true;
         },
         {
-          name: "tokenProgram";
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
           isMut: false;
           isSigner: false;
         },
@@ -331,71 +310,58 @@ export type Referral = {
       args: [];
     },
     {
-      name: "createAdminTokenAccount";
+      name: "setProjectSwapProgram";
       accounts: [
-        {
-          name: "project";
-          isMut: false;
-          isSigner: false;
-        },
-        {
-          name: "projectAuthority";
-          isMut: true;
-          isSigner: false;
-        },
         {
           name: "admin";
           isMut: false;
-          isSigner: false;
+          isSigner: true;
         },
         {
-          name: "projectAdminTokenAccount";
+          name: "project";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "mint";
-          isMut: false;
-          isSigner: false;
-        },
-        {
-          name: "systemProgram";
+          name: "eventAuthority";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "tokenProgram";
+          name: "program";
           isMut: false;
           isSigner: false;
         },
+      ];
+      args: [
         {
-          name: "associatedTokenProgram";
-          isMut: false;
-          isSigner: false;
+          name: "params";
+          type: {
+            defined: "SetProjectSwapProgramParams";
+          };
         },
       ];
-      args: [];
     },
     {
-      name: "withdrawFromProject";
+      name: "setProjectMintExtensionPolicy";
       accounts: [
         {
           name: "admin";
-          isMut: true;
+          isMut: false;
           isSigner: true;
         },
         {
           name: "project";
-          isMut: false;
+          isMut: true;
           isSigner: false;
         },
         {
-          name: "projectAuthority";
-          isMut: true;
+          name: "eventAuthority";
+          isMut: false;
           isSigner: false;
         },
         {
-          name: "systemProgram";
+          name: "program";
           isMut: false;
           isSigner: false;
         },
@@ -404,13 +370,13 @@ export type Referral = {
         {
           name: "params";
           type: {
-            defined: "WithdrawFromProjectParams";
+            defined: "SetProjectMintExtensionPolicyParams";
           };
         },
       ];
     },
     {
-      name: "claimV2";
+      name: "initializeProjectMintPolicy";
       accounts: [
         {
           name: "payer";
@@ -418,69 +384,82 @@ export type Referral = {
           isSigner: true;
         },
         {
-          name: "project";
+          name: "admin";
           isMut: false;
-          isSigner: false;
+          isSigner: true;
         },
         {
-          name: "admin";
-          isMut: false;
+          name: "project";
+          isMut: true;
           isSigner: false;
         },
         {
-          name: "projectAdminTokenAccount";
+          name: "mintPolicy";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "referralAccount";
+          name: "systemProgram";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "referralTokenAccount";
-          isMut: true;
+          name: "eventAuthority";
+          isMut: false;
           isSigner: false;
         },
         {
-          name: "partner";
+          name: "program";
           isMut: false;
           isSigner: false;
         },
+      ];
+      args: [];
+    },
+    {
+      name: "setProjectMintPolicyMode";
+      accounts: [
         {
-          name: "partnerTokenAccount";
-          isMut: true;
-          isSigner: false;
+          name: "admin";
+          isMut: false;
+          isSigner: true;
         },
         {
-          name: "mint";
+          name: "project";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "systemProgram";
-          isMut: false;
+          name: "mintPolicy";
+          isMut: true;
           isSigner: false;
         },
         {
-          name: "tokenProgram";
+          name: "eventAuthority";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "associatedTokenProgram";
+          name: "program";
           isMut: false;
           isSigner: false;
         },
       ];
-      args: [];
+      args: [
+        {
+          name: "params";
+          type: {
+            defined: "SetProjectMintPolicyModeParams";
+          };
+        },
+      ];
     },
     {
-      name: "closeReferralTokenAccountV2";
+      name: "addProjectPolicyMint";
       accounts: [
         {
           name: "admin";
-          isMut: true;
+          isMut: false;
           isSigner: true;
         },
         {
@@ -489,1040 +468,10864 @@ export type Referral = {
           isSigner: false;
         },
         {
-          name: "referralAccount";
-          isMut: false;
-          isSigner: false;
-        },
-        {
-          name: "referralTokenAccount";
-          isMut: true;
-          isSigner: false;
-        },
-        {
-          name: "partner";
+          name: "mintPolicy";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "mint";
+          name: "eventAuthority";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "tokenProgram";
+          name: "program";
           isMut: false;
           isSigner: false;
         },
+      ];
+      args: [
         {
-          name: "associatedTokenProgram";
-          isMut: false;
+          name: "params";
+          type: {
+            defined: "ProjectPolicyMintParams";
+          };
+        },
+      ];
+    },
+    {
+      name: "removeProjectPolicyMint";
+      accounts: [
+        {
+          name: "admin";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "project";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "mintPolicy";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: "params";
+          type: {
+            defined: "ProjectPolicyMintParams";
+          };
+        },
+      ];
+    },
+    {
+      name: "initializeProjectClaimThresholds";
+      accounts: [
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "admin";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "project";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "claimThresholds";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
           isSigner: false;
         },
       ];
       args: [];
     },
-  ];
-  accounts: [
     {
-      name: "project";
+      name: "setProjectMinClaimAmount";
+      accounts: [
+        {
+          name: "admin";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "project";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "claimThresholds";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: "params";
+          type: {
+            defined: "SetProjectMinClaimAmountParams";
+          };
+        },
+      ];
+    },
+    {
+      name: "setProjectKeeperFee";
+      accounts: [
+        {
+          name: "admin";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "project";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: "params";
+          type: {
+            defined: "SetProjectKeeperFeeParams";
+          };
+        },
+      ];
+    },
+    {
+      name: "setProjectMaxKeeperFee";
+      accounts: [
+        {
+          name: "admin";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "project";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "claimThresholds";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: "params";
+          type: {
+            defined: "SetProjectMaxKeeperFeeParams";
+          };
+        },
+      ];
+    },
+    {
+      name: "setProjectSponsorship";
+      accounts: [
+        {
+          name: "admin";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "project";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: "params";
+          type: {
+            defined: "SetProjectSponsorshipParams";
+          };
+        },
+      ];
+    },
+    {
+      name: "setProjectAdminTokenAccountPolicy";
+      accounts: [
+        {
+          name: "admin";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "project";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: "params";
+          type: {
+            defined: "SetProjectAdminTokenAccountPolicyParams";
+          };
+        },
+      ];
+    },
+    {
+      name: "initializeProjectMetadata";
+      accounts: [
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "admin";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "project";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "metadata";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [];
+    },
+    {
+      name: "setProjectMetadata";
+      accounts: [
+        {
+          name: "admin";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "project";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "metadata";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: "params";
+          type: {
+            defined: "SetMetadataParams";
+          };
+        },
+      ];
+    },
+    {
+      name: "setProjectAttributionPolicy";
+      accounts: [
+        {
+          name: "admin";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "project";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: "params";
+          type: {
+            defined: "SetProjectAttributionPolicyParams";
+          };
+        },
+      ];
+    },
+    {
+      name: "fundProjectAuthority";
+      accounts: [
+        {
+          name: "funder";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "project";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "projectAuthority";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: "params";
+          type: {
+            defined: "FundProjectAuthorityParams";
+          };
+        },
+      ];
+    },
+    {
+      name: "migrateProject";
+      accounts: [
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "admin";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "project";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [];
+    },
+    {
+      name: "setReferralAccountShareMode";
+      accounts: [
+        {
+          name: "admin";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "project";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "referralAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: "params";
+          type: {
+            defined: "SetReferralAccountShareModeParams";
+          };
+        },
+      ];
+    },
+    {
+      name: "setReferralAccountRebate";
+      accounts: [
+        {
+          name: "admin";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "project";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "referralAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: "params";
+          type: {
+            defined: "SetReferralAccountRebateParams";
+          };
+        },
+      ];
+    },
+    {
+      name: "publishRebateRoot";
+      accounts: [
+        {
+          name: "admin";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "project";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "rebatePool";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: "params";
+          type: {
+            defined: "PublishRebateRootParams";
+          };
+        },
+      ];
+    },
+    {
+      name: "setProjectMultisig";
+      accounts: [
+        {
+          name: "admin";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "project";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: "params";
+          type: {
+            defined: "SetProjectMultisigParams";
+          };
+        },
+      ];
+    },
+    {
+      name: "createProjectProposal";
+      accounts: [
+        {
+          name: "proposer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "project";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "proposal";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: "params";
+          type: {
+            defined: "CreateProjectProposalParams";
+          };
+        },
+      ];
+    },
+    {
+      name: "approveProjectProposal";
+      accounts: [
+        {
+          name: "signer";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "project";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "proposal";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [];
+    },
+    {
+      name: "executeProjectProposal";
+      accounts: [
+        {
+          name: "executor";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "project";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "proposal";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "referralAccount";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "projectAuthority";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "admin";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "mintPolicy";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "claimThresholds";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "metadata";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "rebatePool";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [];
+    },
+    {
+      name: "closeProjectProposal";
+      accounts: [
+        {
+          name: "proposer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "proposal";
+          isMut: true;
+          isSigner: false;
+        },
+      ];
+      args: [];
+    },
+    {
+      name: "initializeReferralAccount";
+      accounts: [
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "partner";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "project";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "referralAccount";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: "params";
+          type: {
+            defined: "InitializeReferralAccountParams";
+          };
+        },
+      ];
+    },
+    {
+      name: "initializeReferralAccountWithName";
+      accounts: [
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "partner";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "project";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "referralAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: "params";
+          type: {
+            defined: "InitializeReferralAccountWithNameParams";
+          };
+        },
+      ];
+    },
+    {
+      name: "transferReferralAccount";
+      accounts: [
+        {
+          name: "partner";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "newPartner";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "referralAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: "params";
+          type: {
+            defined: "TransferReferralAccountParams";
+          };
+        },
+      ];
+    },
+    {
+      name: "setReferralAccountDisplayName";
+      accounts: [
+        {
+          name: "partner";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "referralAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: "params";
+          type: {
+            defined: "SetReferralAccountDisplayNameParams";
+          };
+        },
+      ];
+    },
+    {
+      name: "createReferralAlias";
+      accounts: [
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "partner";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "project";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "referralAccount";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "alias";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: "params";
+          type: {
+            defined: "CreateReferralAliasParams";
+          };
+        },
+      ];
+    },
+    {
+      name: "deleteReferralAlias";
+      accounts: [
+        {
+          name: "partner";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "referralAccount";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "alias";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [];
+    },
+    {
+      name: "resolveReferralAlias";
+      accounts: [
+        {
+          name: "alias";
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [];
+      returns: "publicKey";
+    },
+    {
+      name: "attributeUser";
+      accounts: [
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "user";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "project";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "referralAccount";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "userAttribution";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [];
+    },
+    {
+      name: "resolveUserAttribution";
+      accounts: [
+        {
+          name: "project";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "user";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "userAttribution";
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [];
+      returns: {
+        option: "publicKey";
+      };
+    },
+    {
+      name: "setReferralAccountMetadata";
+      accounts: [
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "partner";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "referralAccount";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "metadata";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: "params";
+          type: {
+            defined: "SetMetadataParams";
+          };
+        },
+      ];
+    },
+    {
+      name: "initializeSponsoredReferralAccountWithName";
+      accounts: [
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "partner";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "project";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "projectAuthority";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "referralAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "partnerSponsorship";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: "params";
+          type: {
+            defined: "InitializeReferralAccountWithNameParams";
+          };
+        },
+      ];
+    },
+    {
+      name: "migrateReferralAccount";
+      accounts: [
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "referralAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [];
+    },
+    {
+      name: "initializeReferralTokenAccount";
+      accounts: [
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "project";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "referralAccount";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "referralTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "mint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "mintPolicy";
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [];
+    },
+    {
+      name: "initializeReferralTokenAccountV2";
+      accounts: [
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "project";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "referralAccount";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "referralTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "mint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "associatedTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "mintPolicy";
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [];
+    },
+    {
+      name: "initializeSponsoredReferralTokenAccount";
+      accounts: [
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "partner";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "project";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "projectAuthority";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "referralAccount";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "referralTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "partnerSponsorship";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "mint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "associatedTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "mintPolicy";
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [];
+    },
+    {
+      name: "claim";
+      accounts: [
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "project";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "admin";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "projectAdminTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "referralAccount";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "referralTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "partner";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "partnerTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "mint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "associatedTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "mintPolicy";
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "claimThresholds";
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [];
+    },
+    {
+      name: "claimAmount";
+      accounts: [
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "project";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "admin";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "projectAdminTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "referralAccount";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "referralTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "partner";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "partnerTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "mint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "associatedTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "mintPolicy";
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "claimThresholds";
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: "params";
+          type: {
+            defined: "ClaimAmountParams";
+          };
+        },
+      ];
+    },
+    {
+      name: "closeReferralTokenAccount";
+      accounts: [
+        {
+          name: "admin";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "project";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "referralAccount";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "referralTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "partner";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "mint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [];
+    },
+    {
+      name: "claimV2";
+      accounts: [
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "project";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "admin";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "projectAdminTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "referralAccount";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "referralTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "partner";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "partnerTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "mint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "associatedTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "mintPolicy";
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "claimThresholds";
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "keeperTokenAccount";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "rebatePoolTokenAccount";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [];
+    },
+    {
+      name: "claimAmountV2";
+      accounts: [
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "project";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "admin";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "projectAdminTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "referralAccount";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "referralTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "partner";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "partnerTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "mint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "associatedTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "mintPolicy";
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "claimThresholds";
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "keeperTokenAccount";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "rebatePoolTokenAccount";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: "params";
+          type: {
+            defined: "ClaimAmountParams";
+          };
+        },
+      ];
+    },
+    {
+      name: "claimManyV2";
+      accounts: [
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "project";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "admin";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "referralAccount";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "partner";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "associatedTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "mintPolicy";
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "claimThresholds";
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [];
+    },
+    {
+      name: "claimNativeV2";
+      accounts: [
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "project";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "admin";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "projectAdminTokenAccount";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "referralAccount";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "referralTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "partner";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "partnerTokenAccount";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "temporaryTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "mint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "associatedTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "mintPolicy";
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "claimThresholds";
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: "params";
+          type: {
+            defined: "ClaimNativeV2Params";
+          };
+        },
+      ];
+    },
+    {
+      name: "claimAndSwap";
+      accounts: [
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "project";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "admin";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "projectAdminTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "referralAccount";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "referralTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "partner";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "partnerSettlementTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "swapAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "swapTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "mint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "settlementMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "swapProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "settlementTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "associatedTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "mintPolicy";
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "claimThresholds";
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: "params";
+          type: {
+            defined: "ClaimAndSwapParams";
+          };
+        },
+      ];
+    },
+    {
+      name: "closeReferralTokenAccountV2";
+      accounts: [
+        {
+          name: "admin";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "project";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "referralAccount";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "referralTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "partner";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "mint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "associatedTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [];
+    },
+    {
+      name: "harvestReferralTokenAccountFees";
+      accounts: [
+        {
+          name: "referralAccount";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "referralTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "mint";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [];
+    },
+    {
+      name: "initializeRebatePool";
+      accounts: [
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "project";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "referralAccount";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "rebatePool";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "rebatePoolTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "mint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "associatedTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [];
+    },
+    {
+      name: "claimRebate";
+      accounts: [
+        {
+          name: "trader";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "rebatePool";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "rebatePoolTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "traderTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "rebateBitmap";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "mint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "associatedTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: "params";
+          type: {
+            defined: "ClaimRebateParams";
+          };
+        },
+      ];
+    },
+    {
+      name: "collectFee";
+      accounts: [
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "authority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "project";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "referralAccount";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "referralTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "sourceTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "feeStats";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "mint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: "params";
+          type: {
+            defined: "CollectFeeParams";
+          };
+        },
+      ];
+    },
+  ];
+  accounts: [
+    {
+      name: "project";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "base";
+            type: "publicKey";
+          },
+          {
+            name: "admin";
+            type: "publicKey";
+          },
+          {
+            name: "name";
+            type: "string";
+          },
+          {
+            name: "defaultShareBps";
+            type: "u16";
+          },
+          {
+            name: "multisigThreshold";
+            type: "u8";
+          },
+          {
+            name: "multisigSigners";
+            type: {
+              vec: "publicKey";
+            };
+          },
+          {
+            name: "shareDecreaseNoticePeriod";
+            type: "i64";
+          },
+          {
+            name: "pendingNoticePeriod";
+            type: {
+              option: {
+                defined: "PendingNoticePeriodChange";
+              };
+            };
+          },
+          {
+            name: "pendingDefaultShare";
+            type: {
+              option: {
+                defined: "PendingShareChange";
+              };
+            };
+          },
+          {
+            name: "swapProgram";
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "mintExtensionPolicy";
+            type: {
+              defined: "MintExtensionPolicy";
+            };
+          },
+          {
+            name: "hasMintPolicy";
+            type: "bool";
+          },
+          {
+            name: "hasClaimThresholds";
+            type: "bool";
+          },
+          {
+            name: "keeperFeeBps";
+            type: "u16";
+          },
+          {
+            name: "sponsorshipEnabled";
+            type: "bool";
+          },
+          {
+            name: "maxSponsoredLamportsPerPartner";
+            type: "u64";
+          },
+          {
+            name: "adminTokenAccountPolicy";
+            type: {
+              defined: "AdminTokenAccountPolicy";
+            };
+          },
+          {
+            name: "adminTokenAccountSpendEpoch";
+            type: "u64";
+          },
+          {
+            name: "adminTokenAccountSpentLamports";
+            type: "u64";
+          },
+          {
+            name: "version";
+            type: "u8";
+          },
+          {
+            name: "attributionPolicy";
+            type: {
+              defined: "AttributionPolicy";
+            };
+          },
+          {
+            name: "reserved";
+            type: {
+              array: [
+                "u8",
+                55,
+              ];
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: "referralAccount";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "partner";
+            type: "publicKey";
+          },
+          {
+            name: "project";
+            type: "publicKey";
+          },
+          {
+            name: "shareBps";
+            type: "u16";
+          },
+          {
+            name: "name";
+            type: {
+              option: "string";
+            };
+          },
+          {
+            name: "pendingShare";
+            type: {
+              option: {
+                defined: "PendingShareChange";
+              };
+            };
+          },
+          {
+            name: "inheritDefault";
+            type: "bool";
+          },
+          {
+            name: "version";
+            type: "u8";
+          },
+          {
+            name: "displayName";
+            type: {
+              option: "string";
+            };
+          },
+          {
+            name: "rebateBps";
+            type: "u16";
+          },
+          {
+            name: "pendingRebate";
+            type: {
+              option: {
+                defined: "PendingShareChange";
+              };
+            };
+          },
+          {
+            name: "reserved";
+            type: {
+              array: [
+                "u8",
+                51,
+              ];
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: "userAttribution";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "project";
+            type: "publicKey";
+          },
+          {
+            name: "user";
+            type: "publicKey";
+          },
+          {
+            name: "referralAccount";
+            type: "publicKey";
+          },
+          {
+            name: "attributedAt";
+            type: "i64";
+          },
+        ];
+      };
+    },
+    {
+      name: "partnerSponsorship";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "project";
+            type: "publicKey";
+          },
+          {
+            name: "partner";
+            type: "publicKey";
+          },
+          {
+            name: "sponsoredLamports";
+            type: "u64";
+          },
+        ];
+      };
+    },
+    {
+      name: "projectMintPolicy";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "project";
+            type: "publicKey";
+          },
+          {
+            name: "mode";
+            type: {
+              defined: "MintPolicyMode";
+            };
+          },
+          {
+            name: "mints";
+            type: {
+              vec: "publicKey";
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: "projectClaimThresholds";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "project";
+            type: "publicKey";
+          },
+          {
+            name: "defaultMinAmount";
+            type: "u64";
+          },
+          {
+            name: "mints";
+            type: {
+              vec: {
+                defined: "MintClaimThreshold";
+              };
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: "metadata";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "target";
+            type: "publicKey";
+          },
+          {
+            name: "uri";
+            type: "string";
+          },
+          {
+            name: "contentHash";
+            type: {
+              array: [
+                "u8",
+                32,
+              ];
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: "alias";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "project";
+            type: "publicKey";
+          },
+          {
+            name: "referralAccount";
+            type: "publicKey";
+          },
+          {
+            name: "alias";
+            type: "string";
+          },
+        ];
+      };
+    },
+    {
+      name: "rebatePool";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "project";
+            type: "publicKey";
+          },
+          {
+            name: "referralAccount";
+            type: "publicKey";
+          },
+          {
+            name: "mint";
+            type: "publicKey";
+          },
+          {
+            name: "round";
+            type: "u64";
+          },
+          {
+            name: "merkleRoot";
+            type: {
+              array: [
+                "u8",
+                32,
+              ];
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: "rebateBitmap";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "rebatePool";
+            type: "publicKey";
+          },
+          {
+            name: "round";
+            type: "u64";
+          },
+          {
+            name: "chunk";
+            type: "u64";
+          },
+          {
+            name: "bits";
+            type: {
+              array: [
+                "u8",
+                512,
+              ];
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: "feeStats";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "referralAccount";
+            type: "publicKey";
+          },
+          {
+            name: "mint";
+            type: "publicKey";
+          },
+          {
+            name: "authority";
+            type: "publicKey";
+          },
+          {
+            name: "sourceTag";
+            type: "string";
+          },
+          {
+            name: "grossVolume";
+            type: "u64";
+          },
+          {
+            name: "feeAmount";
+            type: "u64";
+          },
+          {
+            name: "collections";
+            type: "u64";
+          },
+        ];
+      };
+    },
+    {
+      name: "projectProposal";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "project";
+            type: "publicKey";
+          },
+          {
+            name: "proposer";
+            type: "publicKey";
+          },
+          {
+            name: "id";
+            type: "u64";
+          },
+          {
+            name: "action";
+            type: {
+              defined: "ProjectAction";
+            };
+          },
+          {
+            name: "approvals";
+            type: {
+              vec: "publicKey";
+            };
+          },
+          {
+            name: "expiresAt";
+            type: "i64";
+          },
+          {
+            name: "executed";
+            type: "bool";
+          },
+        ];
+      };
+    },
+  ];
+  types: [
+    {
+      name: "ProjectPolicyMintParams";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "mint";
+            type: "publicKey";
+          },
+        ];
+      };
+    },
+    {
+      name: "ClaimAmountParams";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "amount";
+            type: "u64";
+          },
+        ];
+      };
+    },
+    {
+      name: "ClaimAndSwapParams";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "swapData";
+            type: "bytes";
+          },
+          {
+            name: "minimumOut";
+            type: "u64";
+          },
+        ];
+      };
+    },
+    {
+      name: "ClaimNativeV2Params";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "unwrapReferral";
+            type: "bool";
+          },
+          {
+            name: "unwrapProject";
+            type: "bool";
+          },
+        ];
+      };
+    },
+    {
+      name: "ClaimRebateParams";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "index";
+            type: "u64";
+          },
+          {
+            name: "amount";
+            type: "u64";
+          },
+          {
+            name: "proof";
+            type: {
+              vec: {
+                array: [
+                  "u8",
+                  32,
+                ];
+              };
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: "CollectFeeParams";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "feeAmount";
+            type: "u64";
+          },
+          {
+            name: "grossVolume";
+            type: "u64";
+          },
+          {
+            name: "sourceTag";
+            type: "string";
+          },
+        ];
+      };
+    },
+    {
+      name: "CreateProjectProposalParams";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "id";
+            type: "u64";
+          },
+          {
+            name: "action";
+            type: {
+              defined: "ProjectAction";
+            };
+          },
+          {
+            name: "expiresAt";
+            type: "i64";
+          },
+        ];
+      };
+    },
+    {
+      name: "CreateReferralAliasParams";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "alias";
+            type: "string";
+          },
+        ];
+      };
+    },
+    {
+      name: "FundProjectAuthorityParams";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "amount";
+            type: "u64";
+          },
+        ];
+      };
+    },
+    {
+      name: "InitializeProjectParams";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "name";
+            type: "string";
+          },
+          {
+            name: "defaultShareBps";
+            type: "u16";
+          },
+        ];
+      };
+    },
+    {
+      name: "InitializeReferralAccountWithNameParams";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "name";
+            type: "string";
+          },
+        ];
+      };
+    },
+    {
+      name: "InitializeReferralAccountParams";
+      type: {
+        kind: "struct";
+        fields: [];
+      };
+    },
+    {
+      name: "PublishRebateRootParams";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "merkleRoot";
+            type: {
+              array: [
+                "u8",
+                32,
+              ];
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: "SetProjectAdminTokenAccountPolicyParams";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "policy";
+            type: {
+              defined: "AdminTokenAccountPolicy";
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: "SetProjectAttributionPolicyParams";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "policy";
+            type: {
+              defined: "AttributionPolicy";
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: "SetProjectKeeperFeeParams";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "keeperFeeBps";
+            type: "u16";
+          },
+        ];
+      };
+    },
+    {
+      name: "SetProjectMaxKeeperFeeParams";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "mint";
+            type: "publicKey";
+          },
+          {
+            name: "maxKeeperFee";
+            type: "u64";
+          },
+        ];
+      };
+    },
+    {
+      name: "SetMetadataParams";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "uri";
+            type: "string";
+          },
+          {
+            name: "contentHash";
+            type: {
+              array: [
+                "u8",
+                32,
+              ];
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: "SetProjectMinClaimAmountParams";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "mint";
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "minAmount";
+            type: {
+              option: "u64";
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: "SetProjectMintExtensionPolicyParams";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "policy";
+            type: {
+              defined: "MintExtensionPolicy";
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: "SetProjectMintPolicyModeParams";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "mode";
+            type: {
+              defined: "MintPolicyMode";
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: "SetProjectMultisigParams";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "signers";
+            type: {
+              vec: "publicKey";
+            };
+          },
+          {
+            name: "threshold";
+            type: "u8";
+          },
+        ];
+      };
+    },
+    {
+      name: "SetProjectSponsorshipParams";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "enabled";
+            type: "bool";
+          },
+          {
+            name: "maxSponsoredLamportsPerPartner";
+            type: "u64";
+          },
+        ];
+      };
+    },
+    {
+      name: "SetProjectSwapProgramParams";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "swapProgram";
+            type: {
+              option: "publicKey";
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: "SetReferralAccountDisplayNameParams";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "displayName";
+            type: {
+              option: "string";
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: "SetReferralAccountRebateParams";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "rebateBps";
+            type: "u16";
+          },
+        ];
+      };
+    },
+    {
+      name: "SetReferralAccountShareModeParams";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "inheritDefault";
+            type: "bool";
+          },
+        ];
+      };
+    },
+    {
+      name: "TransferProjectParams";
+      type: {
+        kind: "struct";
+        fields: [];
+      };
+    },
+    {
+      name: "TransferReferralAccountParams";
+      type: {
+        kind: "struct";
+        fields: [];
+      };
+    },
+    {
+      name: "UpdateProjectParams";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "name";
+            type: {
+              option: "string";
+            };
+          },
+          {
+            name: "defaultShareBps";
+            type: {
+              option: "u16";
+            };
+          },
+          {
+            name: "shareDecreaseNoticePeriod";
+            type: {
+              option: "i64";
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: "UpdateReferralAccountsParams";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "shareBps";
+            type: {
+              vec: "u16";
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: "UpdateReferralAccountParams";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "shareBps";
+            type: "u16";
+          },
+        ];
+      };
+    },
+    {
+      name: "WithdrawFromProjectParams";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "amount";
+            type: "u64";
+          },
+        ];
+      };
+    },
+    {
+      name: "PendingShareChange";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "shareBps";
+            type: "u16";
+          },
+          {
+            name: "effectiveAt";
+            type: "i64";
+          },
+        ];
+      };
+    },
+    {
+      name: "PendingNoticePeriodChange";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "noticePeriod";
+            type: "i64";
+          },
+          {
+            name: "effectiveAt";
+            type: "i64";
+          },
+        ];
+      };
+    },
+    {
+      name: "MintExtensionPolicy";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "allowPermanentDelegate";
+            type: "bool";
+          },
+          {
+            name: "allowDefaultFrozen";
+            type: "bool";
+          },
+          {
+            name: "allowNonTransferable";
+            type: "bool";
+          },
+          {
+            name: "allowPausable";
+            type: "bool";
+          },
+        ];
+      };
+    },
+    {
+      name: "AdminTokenAccountPolicy";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "requireAllowedMint";
+            type: "bool";
+          },
+          {
+            name: "minReferralBalance";
+            type: "u64";
+          },
+          {
+            name: "epochBudgetLamports";
+            type: "u64";
+          },
+        ];
+      };
+    },
+    {
+      name: "AttributionPolicy";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "allowOverride";
+            type: "bool";
+          },
+          {
+            name: "expiry";
+            type: "i64";
+          },
+        ];
+      };
+    },
+    {
+      name: "MintClaimThreshold";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "mint";
+            type: "publicKey";
+          },
+          {
+            name: "minAmount";
+            type: {
+              option: "u64";
+            };
+          },
+          {
+            name: "maxKeeperFee";
+            type: "u64";
+          },
+        ];
+      };
+    },
+    {
+      name: "MintPolicyMode";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "Allow";
+          },
+          {
+            name: "Deny";
+          },
+        ];
+      };
+    },
+    {
+      name: "ProjectAction";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "UpdateProject";
+            fields: [
+              {
+                defined: "UpdateProjectParams";
+              },
+            ];
+          },
+          {
+            name: "UpdateReferralAccount";
+            fields: [
+              {
+                name: "referralAccount";
+                type: "publicKey";
+              },
+              {
+                name: "shareBps";
+                type: "u16";
+              },
+            ];
+          },
+          {
+            name: "UpdateReferralAccounts";
+            fields: [
+              {
+                name: "referralAccounts";
+                type: {
+                  vec: "publicKey";
+                };
+              },
+              {
+                name: "shareBps";
+                type: {
+                  vec: "u16";
+                };
+              },
+            ];
+          },
+          {
+            name: "TransferProject";
+            fields: [
+              {
+                name: "newAdmin";
+                type: "publicKey";
+              },
+            ];
+          },
+          {
+            name: "WithdrawFromProject";
+            fields: [
+              {
+                defined: "WithdrawFromProjectParams";
+              },
+            ];
+          },
+          {
+            name: "SetMultisig";
+            fields: [
+              {
+                defined: "SetProjectMultisigParams";
+              },
+            ];
+          },
+          {
+            name: "SetReferralAccountShareMode";
+            fields: [
+              {
+                name: "referralAccount";
+                type: "publicKey";
+              },
+              {
+                name: "inheritDefault";
+                type: "bool";
+              },
+            ];
+          },
+          {
+            name: "SetSwapProgram";
+            fields: [
+              {
+                defined: "SetProjectSwapProgramParams";
+              },
+            ];
+          },
+          {
+            name: "SetMintExtensionPolicy";
+            fields: [
+              {
+                defined: "SetProjectMintExtensionPolicyParams";
+              },
+            ];
+          },
+          {
+            name: "SetMintPolicyMode";
+            fields: [
+              {
+                defined: "SetProjectMintPolicyModeParams";
+              },
+            ];
+          },
+          {
+            name: "AddPolicyMint";
+            fields: [
+              {
+                defined: "ProjectPolicyMintParams";
+              },
+            ];
+          },
+          {
+            name: "RemovePolicyMint";
+            fields: [
+              {
+                defined: "ProjectPolicyMintParams";
+              },
+            ];
+          },
+          {
+            name: "SetMinClaimAmount";
+            fields: [
+              {
+                defined: "SetProjectMinClaimAmountParams";
+              },
+            ];
+          },
+          {
+            name: "SetKeeperFee";
+            fields: [
+              {
+                defined: "SetProjectKeeperFeeParams";
+              },
+            ];
+          },
+          {
+            name: "SetMaxKeeperFee";
+            fields: [
+              {
+                defined: "SetProjectMaxKeeperFeeParams";
+              },
+            ];
+          },
+          {
+            name: "SetSponsorship";
+            fields: [
+              {
+                defined: "SetProjectSponsorshipParams";
+              },
+            ];
+          },
+          {
+            name: "SetAdminTokenAccountPolicy";
+            fields: [
+              {
+                defined: "SetProjectAdminTokenAccountPolicyParams";
+              },
+            ];
+          },
+          {
+            name: "SetMetadata";
+            fields: [
+              {
+                defined: "SetMetadataParams";
+              },
+            ];
+          },
+          {
+            name: "SetAttributionPolicy";
+            fields: [
+              {
+                defined: "SetProjectAttributionPolicyParams";
+              },
+            ];
+          },
+          {
+            name: "SetReferralAccountRebate";
+            fields: [
+              {
+                name: "referralAccount";
+                type: "publicKey";
+              },
+              {
+                name: "rebateBps";
+                type: "u16";
+              },
+            ];
+          },
+          {
+            name: "PublishRebateRoot";
+            fields: [
+              {
+                name: "rebatePool";
+                type: "publicKey";
+              },
+              {
+                name: "merkleRoot";
+                type: {
+                  array: [
+                    "u8",
+                    32,
+                  ];
+                };
+              },
+            ];
+          },
+        ];
+      };
+    },
+  ];
+  events: [
+    {
+      name: "InitializeProjectEvent";
+      fields: [
+        {
+          name: "project";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "admin";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "name";
+          type: "string";
+          index: false;
+        },
+        {
+          name: "defaultShareBps";
+          type: "u16";
+          index: false;
+        },
+      ];
+    },
+    {
+      name: "UpdateProjectEvent";
+      fields: [
+        {
+          name: "project";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "previousName";
+          type: "string";
+          index: false;
+        },
+        {
+          name: "name";
+          type: "string";
+          index: false;
+        },
+        {
+          name: "previousDefaultShareBps";
+          type: "u16";
+          index: false;
+        },
+        {
+          name: "defaultShareBps";
+          type: "u16";
+          index: false;
+        },
+        {
+          name: "previousShareDecreaseNoticePeriod";
+          type: "i64";
+          index: false;
+        },
+        {
+          name: "shareDecreaseNoticePeriod";
+          type: "i64";
+          index: false;
+        },
+        {
+          name: "pendingNoticePeriod";
+          type: {
+            option: {
+              defined: "PendingNoticePeriodChange";
+            };
+          };
+          index: false;
+        },
+        {
+          name: "pendingDefaultShare";
+          type: {
+            option: {
+              defined: "PendingShareChange";
+            };
+          };
+          index: false;
+        },
+      ];
+    },
+    {
+      name: "InitializeReferralAccountEvent";
+      fields: [
+        {
+          name: "project";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "partner";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "referralAccount";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "shareBps";
+          type: "u16";
+          index: false;
+        },
+        {
+          name: "name";
+          type: {
+            option: "string";
+          };
+          index: false;
+        },
+      ];
+    },
+    {
+      name: "UpdateReferralAccountEvent";
+      fields: [
+        {
+          name: "referralAccount";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "previousShareBps";
+          type: "u16";
+          index: false;
+        },
+        {
+          name: "shareBps";
+          type: "u16";
+          index: false;
+        },
+      ];
+    },
+    {
+      name: "UpdateReferralAccountMetadataEvent";
+      fields: [
+        {
+          name: "project";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "referralAccount";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "name";
+          type: {
+            option: "string";
+          };
+          index: false;
+        },
+        {
+          name: "previousDisplayName";
+          type: {
+            option: "string";
+          };
+          index: false;
+        },
+        {
+          name: "displayName";
+          type: {
+            option: "string";
+          };
+          index: false;
+        },
+      ];
+    },
+    {
+      name: "QueueReferralAccountShareDecreaseEvent";
+      fields: [
+        {
+          name: "referralAccount";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "shareBps";
+          type: "u16";
+          index: false;
+        },
+        {
+          name: "pendingShareBps";
+          type: "u16";
+          index: false;
+        },
+        {
+          name: "effectiveAt";
+          type: "i64";
+          index: false;
+        },
+      ];
+    },
+    {
+      name: "UpdateReferralAccountShareModeEvent";
+      fields: [
+        {
+          name: "referralAccount";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "inheritDefault";
+          type: "bool";
+          index: false;
+        },
+        {
+          name: "shareBps";
+          type: "u16";
+          index: false;
+        },
+      ];
+    },
+    {
+      name: "InitializeReferralTokenAccountEvent";
+      fields: [
+        {
+          name: "project";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "referralAccount";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "referralTokenAccount";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "mint";
+          type: "publicKey";
+          index: false;
+        },
+      ];
+    },
+    {
+      name: "ClaimEvent";
+      fields: [
+        {
+          name: "project";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "projectAdminTokenAccount";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "referralAccount";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "referralTokenAccount";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "partnerTokenAccount";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "mint";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "referralAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "projectAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "shareBps";
+          type: "u16";
+          index: false;
+        },
+        {
+          name: "inheritDefault";
+          type: "bool";
+          index: false;
+        },
+        {
+          name: "referralTransferFee";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "referralNetAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "projectTransferFee";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "projectNetAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "keeperTokenAccount";
+          type: {
+            option: "publicKey";
+          };
+          index: false;
+        },
+        {
+          name: "keeperAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "keeperTransferFee";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "keeperNetAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "rebateTokenAccount";
+          type: {
+            option: "publicKey";
+          };
+          index: false;
+        },
+        {
+          name: "rebateAmount";
+          type: "u64";
+          index: false;
+        },
+      ];
+    },
+    {
+      name: "ClaimNativeEvent";
+      fields: [
+        {
+          name: "project";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "referralAccount";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "referralTokenAccount";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "mint";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "partner";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "partnerTokenAccount";
+          type: {
+            option: "publicKey";
+          };
+          index: false;
+        },
+        {
+          name: "admin";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "projectAdminTokenAccount";
+          type: {
+            option: "publicKey";
+          };
+          index: false;
+        },
+        {
+          name: "referralAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "projectAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "shareBps";
+          type: "u16";
+          index: false;
+        },
+        {
+          name: "inheritDefault";
+          type: "bool";
+          index: false;
+        },
+        {
+          name: "unwrapReferral";
+          type: "bool";
+          index: false;
+        },
+        {
+          name: "unwrapProject";
+          type: "bool";
+          index: false;
+        },
+        {
+          name: "unwrappedAmount";
+          type: "u64";
+          index: false;
+        },
+      ];
+    },
+    {
+      name: "ClaimAndSwapEvent";
+      fields: [
+        {
+          name: "referralAccount";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "mint";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "inputAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "settlementMint";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "partnerSettlementTokenAccount";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "outputAmount";
+          type: "u64";
+          index: false;
+        },
+      ];
+    },
+    {
+      name: "HarvestReferralTokenAccountFeesEvent";
+      fields: [
+        {
+          name: "referralAccount";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "referralTokenAccount";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "mint";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "withheldAmount";
+          type: "u64";
+          index: false;
+        },
+      ];
+    },
+    {
+      name: "SetProjectSwapProgramEvent";
+      fields: [
+        {
+          name: "project";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "swapProgram";
+          type: {
+            option: "publicKey";
+          };
+          index: false;
+        },
+      ];
+    },
+    {
+      name: "SetProjectMintExtensionPolicyEvent";
+      fields: [
+        {
+          name: "project";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "policy";
+          type: {
+            defined: "MintExtensionPolicy";
+          };
+          index: false;
+        },
+      ];
+    },
+    {
+      name: "InitializeProjectMintPolicyEvent";
+      fields: [
+        {
+          name: "project";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "mintPolicy";
+          type: "publicKey";
+          index: false;
+        },
+      ];
+    },
+    {
+      name: "SetProjectMintPolicyModeEvent";
+      fields: [
+        {
+          name: "project";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "mode";
+          type: {
+            defined: "MintPolicyMode";
+          };
+          index: false;
+        },
+      ];
+    },
+    {
+      name: "AddProjectPolicyMintEvent";
+      fields: [
+        {
+          name: "project";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "mint";
+          type: "publicKey";
+          index: false;
+        },
+      ];
+    },
+    {
+      name: "RemoveProjectPolicyMintEvent";
+      fields: [
+        {
+          name: "project";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "mint";
+          type: "publicKey";
+          index: false;
+        },
+      ];
+    },
+    {
+      name: "InitializeProjectClaimThresholdsEvent";
+      fields: [
+        {
+          name: "project";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "claimThresholds";
+          type: "publicKey";
+          index: false;
+        },
+      ];
+    },
+    {
+      name: "SetProjectMinClaimAmountEvent";
+      fields: [
+        {
+          name: "project";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "mint";
+          type: {
+            option: "publicKey";
+          };
+          index: false;
+        },
+        {
+          name: "minAmount";
+          type: {
+            option: "u64";
+          };
+          index: false;
+        },
+      ];
+    },
+    {
+      name: "SetProjectKeeperFeeEvent";
+      fields: [
+        {
+          name: "project";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "keeperFeeBps";
+          type: "u16";
+          index: false;
+        },
+      ];
+    },
+    {
+      name: "SetProjectMaxKeeperFeeEvent";
+      fields: [
+        {
+          name: "project";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "mint";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "maxKeeperFee";
+          type: "u64";
+          index: false;
+        },
+      ];
+    },
+    {
+      name: "SetProjectSponsorshipEvent";
+      fields: [
+        {
+          name: "project";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "enabled";
+          type: "bool";
+          index: false;
+        },
+        {
+          name: "maxSponsoredLamportsPerPartner";
+          type: "u64";
+          index: false;
+        },
+      ];
+    },
+    {
+      name: "FundProjectAuthorityEvent";
+      fields: [
+        {
+          name: "project";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "projectAuthority";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "funder";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "amount";
+          type: "u64";
+          index: false;
+        },
+      ];
+    },
+    {
+      name: "SponsorRentEvent";
+      fields: [
+        {
+          name: "project";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "partner";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "payer";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "lamports";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "sponsoredLamports";
+          type: "u64";
+          index: false;
+        },
+      ];
+    },
+    {
+      name: "SetProjectAdminTokenAccountPolicyEvent";
+      fields: [
+        {
+          name: "project";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "policy";
+          type: {
+            defined: "AdminTokenAccountPolicy";
+          };
+          index: false;
+        },
+      ];
+    },
+    {
+      name: "CreateAdminTokenAccountEvent";
+      fields: [
+        {
+          name: "project";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "admin";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "projectAdminTokenAccount";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "mint";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "lamports";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "epoch";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "epochSpentLamports";
+          type: "u64";
+          index: false;
+        },
+      ];
+    },
+    {
+      name: "TransferProjectEvent";
+      fields: [
+        {
+          name: "project";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "previousAdmin";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "newAdmin";
+          type: "publicKey";
+          index: false;
+        },
+      ];
+    },
+    {
+      name: "TransferReferralAccountEvent";
+      fields: [
+        {
+          name: "project";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "referralAccount";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "previousPartner";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "newPartner";
+          type: "publicKey";
+          index: false;
+        },
+      ];
+    },
+    {
+      name: "WithdrawFromProjectEvent";
+      fields: [
+        {
+          name: "project";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "projectAuthority";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "destination";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "amount";
+          type: "u64";
+          index: false;
+        },
+      ];
+    },
+    {
+      name: "CloseReferralTokenAccountEvent";
+      fields: [
+        {
+          name: "project";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "referralAccount";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "referralTokenAccount";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "mint";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "partner";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "lamports";
+          type: "u64";
+          index: false;
+        },
+      ];
+    },
+    {
+      name: "MigrateProjectEvent";
+      fields: [
+        {
+          name: "project";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "previousVersion";
+          type: "u8";
+          index: false;
+        },
+        {
+          name: "version";
+          type: "u8";
+          index: false;
+        },
+      ];
+    },
+    {
+      name: "MigrateReferralAccountEvent";
+      fields: [
+        {
+          name: "project";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "referralAccount";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "previousVersion";
+          type: "u8";
+          index: false;
+        },
+        {
+          name: "version";
+          type: "u8";
+          index: false;
+        },
+      ];
+    },
+    {
+      name: "InitializeProjectMetadataEvent";
+      fields: [
+        {
+          name: "project";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "metadata";
+          type: "publicKey";
+          index: false;
+        },
+      ];
+    },
+    {
+      name: "SetProjectMetadataEvent";
+      fields: [
+        {
+          name: "project";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "metadata";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "uri";
+          type: "string";
+          index: false;
+        },
+        {
+          name: "contentHash";
+          type: {
+            array: [
+              "u8",
+              32,
+            ];
+          };
+          index: false;
+        },
+      ];
+    },
+    {
+      name: "SetReferralAccountMetadataEvent";
+      fields: [
+        {
+          name: "project";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "referralAccount";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "metadata";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "uri";
+          type: "string";
+          index: false;
+        },
+        {
+          name: "contentHash";
+          type: {
+            array: [
+              "u8",
+              32,
+            ];
+          };
+          index: false;
+        },
+      ];
+    },
+    {
+      name: "CreateReferralAliasEvent";
+      fields: [
+        {
+          name: "project";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "referralAccount";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "aliasAccount";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "alias";
+          type: "string";
+          index: false;
+        },
+      ];
+    },
+    {
+      name: "DeleteReferralAliasEvent";
+      fields: [
+        {
+          name: "project";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "referralAccount";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "aliasAccount";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "alias";
+          type: "string";
+          index: false;
+        },
+      ];
+    },
+    {
+      name: "SetProjectAttributionPolicyEvent";
+      fields: [
+        {
+          name: "project";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "policy";
+          type: {
+            defined: "AttributionPolicy";
+          };
+          index: false;
+        },
+      ];
+    },
+    {
+      name: "AttributeUserEvent";
+      fields: [
+        {
+          name: "project";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "user";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "userAttribution";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "referralAccount";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "previousReferralAccount";
+          type: {
+            option: "publicKey";
+          };
+          index: false;
+        },
+        {
+          name: "attributedAt";
+          type: "i64";
+          index: false;
+        },
+      ];
+    },
+    {
+      name: "SetReferralAccountRebateEvent";
+      fields: [
+        {
+          name: "referralAccount";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "previousRebateBps";
+          type: "u16";
+          index: false;
+        },
+        {
+          name: "rebateBps";
+          type: "u16";
+          index: false;
+        },
+      ];
+    },
+    {
+      name: "QueueReferralAccountRebateIncreaseEvent";
+      fields: [
+        {
+          name: "referralAccount";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "rebateBps";
+          type: "u16";
+          index: false;
+        },
+        {
+          name: "pendingRebateBps";
+          type: "u16";
+          index: false;
+        },
+        {
+          name: "effectiveAt";
+          type: "i64";
+          index: false;
+        },
+      ];
+    },
+    {
+      name: "InitializeRebatePoolEvent";
+      fields: [
+        {
+          name: "project";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "referralAccount";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "rebatePool";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "rebatePoolTokenAccount";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "mint";
+          type: "publicKey";
+          index: false;
+        },
+      ];
+    },
+    {
+      name: "PublishRebateRootEvent";
+      fields: [
+        {
+          name: "project";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "rebatePool";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "round";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "merkleRoot";
+          type: {
+            array: [
+              "u8",
+              32,
+            ];
+          };
+          index: false;
+        },
+      ];
+    },
+    {
+      name: "ClaimRebateEvent";
+      fields: [
+        {
+          name: "project";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "referralAccount";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "rebatePool";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "trader";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "traderTokenAccount";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "mint";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "round";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "index";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "amount";
+          type: "u64";
+          index: false;
+        },
+      ];
+    },
+    {
+      name: "FeeCollectedEvent";
+      fields: [
+        {
+          name: "project";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "referralAccount";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "referralTokenAccount";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "mint";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "sourceTokenAccount";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "feeStats";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "sourceTag";
+          type: "string";
+          index: false;
+        },
+        {
+          name: "grossVolume";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "feeAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "transferFee";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "netAmount";
+          type: "u64";
+          index: false;
+        },
+      ];
+    },
+    {
+      name: "SetProjectMultisigEvent";
+      fields: [
+        {
+          name: "project";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "signers";
+          type: {
+            vec: "publicKey";
+          };
+          index: false;
+        },
+        {
+          name: "threshold";
+          type: "u8";
+          index: false;
+        },
+      ];
+    },
+    {
+      name: "CreateProjectProposalEvent";
+      fields: [
+        {
+          name: "project";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "proposal";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "proposer";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "id";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "action";
+          type: {
+            defined: "ProjectAction";
+          };
+          index: false;
+        },
+        {
+          name: "expiresAt";
+          type: "i64";
+          index: false;
+        },
+      ];
+    },
+    {
+      name: "ApproveProjectProposalEvent";
+      fields: [
+        {
+          name: "project";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "proposal";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "signer";
+          type: "publicKey";
+          index: false;
+        },
+      ];
+    },
+    {
+      name: "ExecuteProjectProposalEvent";
+      fields: [
+        {
+          name: "project";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "proposal";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "executor";
+          type: "publicKey";
+          index: false;
+        },
+      ];
+    },
+  ];
+  errors: [
+    {
+      code: 6000;
+      name: "InvalidCalculation";
+    },
+    {
+      code: 6001;
+      name: "InvalidSharePercentage";
+    },
+    {
+      code: 6002;
+      name: "NameTooLong";
+    },
+    {
+      code: 6003;
+      name: "MultisigRequired";
+    },
+    {
+      code: 6004;
+      name: "InvalidMultisigConfig";
+    },
+    {
+      code: 6005;
+      name: "NotMultisigSigner";
+    },
+    {
+      code: 6006;
+      name: "InvalidProposalExpiry";
+    },
+    {
+      code: 6007;
+      name: "ProposalExpired";
+    },
+    {
+      code: 6008;
+      name: "ProposalAlreadyExecuted";
+    },
+    {
+      code: 6009;
+      name: "ProposalAlreadyApproved";
+    },
+    {
+      code: 6010;
+      name: "ProposalThresholdNotMet";
+    },
+    {
+      code: 6011;
+      name: "ProposalNotClosable";
+    },
+    {
+      code: 6012;
+      name: "MissingProposalAccount";
+    },
+    {
+      code: 6013;
+      name: "InvalidNoticePeriod";
+    },
+    {
+      code: 6014;
+      name: "ShareModeChangeDecreasesShare";
+    },
+    {
+      code: 6015;
+      name: "InvalidReferralAccountsUpdate";
+    },
+    {
+      code: 6016;
+      name: "ClaimAmountExceedsBalance";
+    },
+    {
+      code: 6017;
+      name: "InvalidClaimManyAccounts";
+    },
+    {
+      code: 6018;
+      name: "MissingPayoutTokenAccount";
+    },
+    {
+      code: 6019;
+      name: "SwapProgramNotAllowed";
+    },
+    {
+      code: 6020;
+      name: "SwapInputNotConsumed";
+    },
+    {
+      code: 6021;
+      name: "SwapOutputTooLow";
+    },
+    {
+      code: 6022;
+      name: "MintHasPermanentDelegate";
+    },
+    {
+      code: 6023;
+      name: "MintDefaultFrozen";
+    },
+    {
+      code: 6024;
+      name: "MintNonTransferable";
+    },
+    {
+      code: 6025;
+      name: "MintPausable";
+    },
+    {
+      code: 6026;
+      name: "MissingMintPolicyAccount";
+    },
+    {
+      code: 6027;
+      name: "MintNotAllowed";
+    },
+    {
+      code: 6028;
+      name: "MintPolicyFull";
+    },
+    {
+      code: 6029;
+      name: "MintAlreadyInPolicy";
+    },
+    {
+      code: 6030;
+      name: "MintNotInPolicy";
+    },
+    {
+      code: 6031;
+      name: "MissingClaimThresholdsAccount";
+    },
+    {
+      code: 6032;
+      name: "ClaimAmountBelowMinimum";
+    },
+    {
+      code: 6033;
+      name: "ClaimThresholdsFull";
+    },
+    {
+      code: 6034;
+      name: "SponsorshipDisabled";
+    },
+    {
+      code: 6035;
+      name: "SponsorshipCapExceeded";
+    },
+    {
+      code: 6036;
+      name: "MissingReferralTokenAccount";
+    },
+    {
+      code: 6037;
+      name: "ReferralBalanceTooLow";
+    },
+    {
+      code: 6038;
+      name: "EpochBudgetExceeded";
+    },
+    {
+      code: 6039;
+      name: "MetadataUriTooLong";
+    },
+    {
+      code: 6040;
+      name: "InvalidAlias";
+    },
+    {
+      code: 6041;
+      name: "InvalidAttributionExpiry";
+    },
+    {
+      code: 6042;
+      name: "UserAlreadyAttributed";
+    },
+    {
+      code: 6043;
+      name: "RebateRequiresClaimV2";
+    },
+    {
+      code: 6044;
+      name: "MissingRebatePoolAccount";
+    },
+    {
+      code: 6045;
+      name: "InvalidRebatePoolAccount";
+    },
+    {
+      code: 6046;
+      name: "InvalidMerkleProof";
+    },
+    {
+      code: 6047;
+      name: "RebateAlreadyClaimed";
+    },
+    {
+      code: 6048;
+      name: "InvalidSourceTag";
+    },
+    {
+      code: 6049;
+      name: "MintAllowlistRequired";
+    },
+    {
+      code: 6050;
+      name: "InvalidReferralTokenAccount";
+    },
+    {
+      code: 6051;
+      name: "InvalidFeeAmount";
+    },
+    {
+      code: 6052;
+      name: "InvalidKeeperFee";
+    },
+    {
+      code: 6053;
+      name: "ReferralAccountNotMigrated";
+    },
+  ];
+};
+
+export const IDL: Referral = {
+  version: "0.1.0",
+  name: "referral",
+  instructions: [
+    {
+      name: "initializeProject",
+      accounts: [
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "base",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "admin",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "project",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "params",
+          type: {
+            defined: "InitializeProjectParams",
+          },
+        },
+      ],
+    },
+    {
+      name: "updateProject",
+      accounts: [
+        {
+          name: "admin",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "project",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "params",
+          type: {
+            defined: "UpdateProjectParams",
+          },
+        },
+      ],
+    },
+    {
+      name: "transferProject",
+      accounts: [
+        {
+          name: "admin",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "newAdmin",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "project",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "params",
+          type: {
+            defined: "TransferProjectParams",
+          },
+        },
+      ],
+    },
+    {
+      name: "updateReferralAccount",
+      accounts: [
+        {
+          name: "admin",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "project",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "referralAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "params",
+          type: {
+            defined: "UpdateReferralAccountParams",
+          },
+        },
+      ],
+    },
+    {
+      name: "updateReferralAccounts",
+      accounts: [
+        {
+          name: "admin",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "project",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "params",
+          type: {
+            defined: "UpdateReferralAccountsParams",
+          },
+        },
+      ],
+    },
+    {
+      name: "withdrawFromProject",
+      accounts: [
+        {
+          name: "admin",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "project",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "projectAuthority",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "params",
+          type: {
+            defined: "WithdrawFromProjectParams",
+          },
+        },
+      ],
+    },
+    {
+      name: "createAdminTokenAccount",
+      accounts: [
+        {
+          name: "project",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "projectAuthority",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "admin",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "projectAdminTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "associatedTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "mintPolicy",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "referralAccount",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "referralTokenAccount",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "setProjectSwapProgram",
+      accounts: [
+        {
+          name: "admin",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "project",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "params",
+          type: {
+            defined: "SetProjectSwapProgramParams",
+          },
+        },
+      ],
+    },
+    {
+      name: "setProjectMintExtensionPolicy",
+      accounts: [
+        {
+          name: "admin",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "project",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "params",
+          type: {
+            defined: "SetProjectMintExtensionPolicyParams",
+          },
+        },
+      ],
+    },
+    {
+      name: "initializeProjectMintPolicy",
+      accounts: [
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "admin",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "project",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mintPolicy",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "setProjectMintPolicyMode",
+      accounts: [
+        {
+          name: "admin",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "project",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "mintPolicy",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "params",
+          type: {
+            defined: "SetProjectMintPolicyModeParams",
+          },
+        },
+      ],
+    },
+    {
+      name: "addProjectPolicyMint",
+      accounts: [
+        {
+          name: "admin",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "project",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "mintPolicy",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "params",
+          type: {
+            defined: "ProjectPolicyMintParams",
+          },
+        },
+      ],
+    },
+    {
+      name: "removeProjectPolicyMint",
+      accounts: [
+        {
+          name: "admin",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "project",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "mintPolicy",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "params",
+          type: {
+            defined: "ProjectPolicyMintParams",
+          },
+        },
+      ],
+    },
+    {
+      name: "initializeProjectClaimThresholds",
+      accounts: [
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "admin",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "project",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "claimThresholds",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "setProjectMinClaimAmount",
+      accounts: [
+        {
+          name: "admin",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "project",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "claimThresholds",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "params",
+          type: {
+            defined: "SetProjectMinClaimAmountParams",
+          },
+        },
+      ],
+    },
+    {
+      name: "setProjectKeeperFee",
+      accounts: [
+        {
+          name: "admin",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "project",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "params",
+          type: {
+            defined: "SetProjectKeeperFeeParams",
+          },
+        },
+      ],
+    },
+    {
+      name: "setProjectMaxKeeperFee",
+      accounts: [
+        {
+          name: "admin",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "project",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "claimThresholds",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "params",
+          type: {
+            defined: "SetProjectMaxKeeperFeeParams",
+          },
+        },
+      ],
+    },
+    {
+      name: "setProjectSponsorship",
+      accounts: [
+        {
+          name: "admin",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "project",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "params",
+          type: {
+            defined: "SetProjectSponsorshipParams",
+          },
+        },
+      ],
+    },
+    {
+      name: "setProjectAdminTokenAccountPolicy",
+      accounts: [
+        {
+          name: "admin",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "project",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "params",
+          type: {
+            defined: "SetProjectAdminTokenAccountPolicyParams",
+          },
+        },
+      ],
+    },
+    {
+      name: "initializeProjectMetadata",
+      accounts: [
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "admin",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "project",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "metadata",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "setProjectMetadata",
+      accounts: [
+        {
+          name: "admin",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "project",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "metadata",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "params",
+          type: {
+            defined: "SetMetadataParams",
+          },
+        },
+      ],
+    },
+    {
+      name: "setProjectAttributionPolicy",
+      accounts: [
+        {
+          name: "admin",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "project",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "params",
+          type: {
+            defined: "SetProjectAttributionPolicyParams",
+          },
+        },
+      ],
+    },
+    {
+      name: "fundProjectAuthority",
+      accounts: [
+        {
+          name: "funder",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "project",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "projectAuthority",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "params",
+          type: {
+            defined: "FundProjectAuthorityParams",
+          },
+        },
+      ],
+    },
+    {
+      name: "migrateProject",
+      accounts: [
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "admin",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "project",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "setReferralAccountShareMode",
+      accounts: [
+        {
+          name: "admin",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "project",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "referralAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "params",
+          type: {
+            defined: "SetReferralAccountShareModeParams",
+          },
+        },
+      ],
+    },
+    {
+      name: "setReferralAccountRebate",
+      accounts: [
+        {
+          name: "admin",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "project",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "referralAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "params",
+          type: {
+            defined: "SetReferralAccountRebateParams",
+          },
+        },
+      ],
+    },
+    {
+      name: "publishRebateRoot",
+      accounts: [
+        {
+          name: "admin",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "project",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rebatePool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "params",
+          type: {
+            defined: "PublishRebateRootParams",
+          },
+        },
+      ],
+    },
+    {
+      name: "setProjectMultisig",
+      accounts: [
+        {
+          name: "admin",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "project",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "params",
+          type: {
+            defined: "SetProjectMultisigParams",
+          },
+        },
+      ],
+    },
+    {
+      name: "createProjectProposal",
+      accounts: [
+        {
+          name: "proposer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "project",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "proposal",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "params",
+          type: {
+            defined: "CreateProjectProposalParams",
+          },
+        },
+      ],
+    },
+    {
+      name: "approveProjectProposal",
+      accounts: [
+        {
+          name: "signer",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "project",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "proposal",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "executeProjectProposal",
+      accounts: [
+        {
+          name: "executor",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "project",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "proposal",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "referralAccount",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "projectAuthority",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "admin",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "mintPolicy",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "claimThresholds",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "metadata",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "rebatePool",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "closeProjectProposal",
+      accounts: [
+        {
+          name: "proposer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "proposal",
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "initializeReferralAccount",
+      accounts: [
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "partner",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "project",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "referralAccount",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "params",
+          type: {
+            defined: "InitializeReferralAccountParams",
+          },
+        },
+      ],
+    },
+    {
+      name: "initializeReferralAccountWithName",
+      accounts: [
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "partner",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "project",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "referralAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "params",
+          type: {
+            defined: "InitializeReferralAccountWithNameParams",
+          },
+        },
+      ],
+    },
+    {
+      name: "transferReferralAccount",
+      accounts: [
+        {
+          name: "partner",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "newPartner",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "referralAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "params",
+          type: {
+            defined: "TransferReferralAccountParams",
+          },
+        },
+      ],
+    },
+    {
+      name: "setReferralAccountDisplayName",
+      accounts: [
+        {
+          name: "partner",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "referralAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "params",
+          type: {
+            defined: "SetReferralAccountDisplayNameParams",
+          },
+        },
+      ],
+    },
+    {
+      name: "createReferralAlias",
+      accounts: [
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "partner",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "project",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "referralAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "alias",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "params",
+          type: {
+            defined: "CreateReferralAliasParams",
+          },
+        },
+      ],
+    },
+    {
+      name: "deleteReferralAlias",
+      accounts: [
+        {
+          name: "partner",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "referralAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "alias",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "resolveReferralAlias",
+      accounts: [
+        {
+          name: "alias",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+      returns: "publicKey",
+    },
+    {
+      name: "attributeUser",
+      accounts: [
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "user",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "project",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "referralAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "userAttribution",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "resolveUserAttribution",
+      accounts: [
+        {
+          name: "project",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "user",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "userAttribution",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+      returns: {
+        option: "publicKey",
+      },
+    },
+    {
+      name: "setReferralAccountMetadata",
+      accounts: [
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "partner",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "referralAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "metadata",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "params",
+          type: {
+            defined: "SetMetadataParams",
+          },
+        },
+      ],
+    },
+    {
+      name: "initializeSponsoredReferralAccountWithName",
+      accounts: [
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "partner",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "project",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "projectAuthority",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "referralAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "partnerSponsorship",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "params",
+          type: {
+            defined: "InitializeReferralAccountWithNameParams",
+          },
+        },
+      ],
+    },
+    {
+      name: "migrateReferralAccount",
+      accounts: [
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "referralAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "initializeReferralTokenAccount",
+      accounts: [
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "project",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "referralAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "referralTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "mintPolicy",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "initializeReferralTokenAccountV2",
+      accounts: [
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "project",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "referralAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "referralTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "associatedTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "mintPolicy",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "initializeSponsoredReferralTokenAccount",
+      accounts: [
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "partner",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "project",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "projectAuthority",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "referralAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "referralTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "partnerSponsorship",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "associatedTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "mintPolicy",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "claim",
+      accounts: [
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "project",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "admin",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "projectAdminTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "referralAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "referralTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "partner",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "partnerTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "associatedTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "mintPolicy",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "claimThresholds",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "claimAmount",
+      accounts: [
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "project",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "admin",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "projectAdminTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "referralAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "referralTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "partner",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "partnerTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "associatedTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "mintPolicy",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "claimThresholds",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "params",
+          type: {
+            defined: "ClaimAmountParams",
+          },
+        },
+      ],
+    },
+    {
+      name: "closeReferralTokenAccount",
+      accounts: [
+        {
+          name: "admin",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "project",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "referralAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "referralTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "partner",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "claimV2",
+      accounts: [
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "project",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "admin",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "projectAdminTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "referralAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "referralTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "partner",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "partnerTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "associatedTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "mintPolicy",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "claimThresholds",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "keeperTokenAccount",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "rebatePoolTokenAccount",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "claimAmountV2",
+      accounts: [
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "project",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "admin",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "projectAdminTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "referralAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "referralTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "partner",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "partnerTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "associatedTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "mintPolicy",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "claimThresholds",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "keeperTokenAccount",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "rebatePoolTokenAccount",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "params",
+          type: {
+            defined: "ClaimAmountParams",
+          },
+        },
+      ],
+    },
+    {
+      name: "claimManyV2",
+      accounts: [
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "project",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "admin",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "referralAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "partner",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "associatedTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "mintPolicy",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "claimThresholds",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "claimNativeV2",
+      accounts: [
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "project",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "admin",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "projectAdminTokenAccount",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "referralAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "referralTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "partner",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "partnerTokenAccount",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "temporaryTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "associatedTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "mintPolicy",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "claimThresholds",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "params",
+          type: {
+            defined: "ClaimNativeV2Params",
+          },
+        },
+      ],
+    },
+    {
+      name: "claimAndSwap",
+      accounts: [
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "project",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "admin",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "projectAdminTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "referralAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "referralTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "partner",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "partnerSettlementTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "swapAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "swapTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "settlementMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "swapProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "settlementTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "associatedTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "mintPolicy",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "claimThresholds",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "params",
+          type: {
+            defined: "ClaimAndSwapParams",
+          },
+        },
+      ],
+    },
+    {
+      name: "closeReferralTokenAccountV2",
+      accounts: [
+        {
+          name: "admin",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "project",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "referralAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "referralTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "partner",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "associatedTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "harvestReferralTokenAccountFees",
+      accounts: [
+        {
+          name: "referralAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "referralTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mint",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "initializeRebatePool",
+      accounts: [
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "project",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "referralAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rebatePool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "rebatePoolTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "associatedTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "claimRebate",
+      accounts: [
+        {
+          name: "trader",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "rebatePool",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rebatePoolTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "traderTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "rebateBitmap",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "associatedTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "params",
+          type: {
+            defined: "ClaimRebateParams",
+          },
+        },
+      ],
+    },
+    {
+      name: "collectFee",
+      accounts: [
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "project",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "referralAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "referralTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "sourceTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "feeStats",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "params",
+          type: {
+            defined: "CollectFeeParams",
+          },
+        },
+      ],
+    },
+  ],
+  accounts: [
+    {
+      name: "project",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "base",
+            type: "publicKey",
+          },
+          {
+            name: "admin",
+            type: "publicKey",
+          },
+          {
+            name: "name",
+            type: "string",
+          },
+          {
+            name: "defaultShareBps",
+            type: "u16",
+          },
+          {
+            name: "multisigThreshold",
+            type: "u8",
+          },
+          {
+            name: "multisigSigners",
+            type: {
+              vec: "publicKey",
+            },
+          },
+          {
+            name: "shareDecreaseNoticePeriod",
+            type: "i64",
+          },
+          {
+            name: "pendingNoticePeriod",
+            type: {
+              option: {
+                defined: "PendingNoticePeriodChange",
+              },
+            },
+          },
+          {
+            name: "pendingDefaultShare",
+            type: {
+              option: {
+                defined: "PendingShareChange",
+              },
+            },
+          },
+          {
+            name: "swapProgram",
+            type: {
+              option: "publicKey",
+            },
+          },
+          {
+            name: "mintExtensionPolicy",
+            type: {
+              defined: "MintExtensionPolicy",
+            },
+          },
+          {
+            name: "hasMintPolicy",
+            type: "bool",
+          },
+          {
+            name: "hasClaimThresholds",
+            type: "bool",
+          },
+          {
+            name: "keeperFeeBps",
+            type: "u16",
+          },
+          {
+            name: "sponsorshipEnabled",
+            type: "bool",
+          },
+          {
+            name: "maxSponsoredLamportsPerPartner",
+            type: "u64",
+          },
+          {
+            name: "adminTokenAccountPolicy",
+            type: {
+              defined: "AdminTokenAccountPolicy",
+            },
+          },
+          {
+            name: "adminTokenAccountSpendEpoch",
+            type: "u64",
+          },
+          {
+            name: "adminTokenAccountSpentLamports",
+            type: "u64",
+          },
+          {
+            name: "version",
+            type: "u8",
+          },
+          {
+            name: "attributionPolicy",
+            type: {
+              defined: "AttributionPolicy",
+            },
+          },
+          {
+            name: "reserved",
+            type: {
+              array: [
+                "u8",
+                55,
+              ],
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "referralAccount",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "partner",
+            type: "publicKey",
+          },
+          {
+            name: "project",
+            type: "publicKey",
+          },
+          {
+            name: "shareBps",
+            type: "u16",
+          },
+          {
+            name: "name",
+            type: {
+              option: "string",
+            },
+          },
+          {
+            name: "pendingShare",
+            type: {
+              option: {
+                defined: "PendingShareChange",
+              },
+            },
+          },
+          {
+            name: "inheritDefault",
+            type: "bool",
+          },
+          {
+            name: "version",
+            type: "u8",
+          },
+          {
+            name: "displayName",
+            type: {
+              option: "string",
+            },
+          },
+          {
+            name: "rebateBps",
+            type: "u16",
+          },
+          {
+            name: "pendingRebate",
+            type: {
+              option: {
+                defined: "PendingShareChange",
+              },
+            },
+          },
+          {
+            name: "reserved",
+            type: {
+              array: [
+                "u8",
+                51,
+              ],
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "userAttribution",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "project",
+            type: "publicKey",
+          },
+          {
+            name: "user",
+            type: "publicKey",
+          },
+          {
+            name: "referralAccount",
+            type: "publicKey",
+          },
+          {
+            name: "attributedAt",
+            type: "i64",
+          },
+        ],
+      },
+    },
+    {
+      name: "partnerSponsorship",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "project",
+            type: "publicKey",
+          },
+          {
+            name: "partner",
+            type: "publicKey",
+          },
+          {
+            name: "sponsoredLamports",
+            type: "u64",
+          },
+        ],
+      },
+    },
+    {
+      name: "projectMintPolicy",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "project",
+            type: "publicKey",
+          },
+          {
+            name: "mode",
+            type: {
+              defined: "MintPolicyMode",
+            },
+          },
+          {
+            name: "mints",
+            type: {
+              vec: "publicKey",
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "projectClaimThresholds",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "project",
+            type: "publicKey",
+          },
+          {
+            name: "defaultMinAmount",
+            type: "u64",
+          },
+          {
+            name: "mints",
+            type: {
+              vec: {
+                defined: "MintClaimThreshold",
+              },
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "metadata",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "target",
+            type: "publicKey",
+          },
+          {
+            name: "uri",
+            type: "string",
+          },
+          {
+            name: "contentHash",
+            type: {
+              array: [
+                "u8",
+                32,
+              ],
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "alias",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "project",
+            type: "publicKey",
+          },
+          {
+            name: "referralAccount",
+            type: "publicKey",
+          },
+          {
+            name: "alias",
+            type: "string",
+          },
+        ],
+      },
+    },
+    {
+      name: "rebatePool",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "project",
+            type: "publicKey",
+          },
+          {
+            name: "referralAccount",
+            type: "publicKey",
+          },
+          {
+            name: "mint",
+            type: "publicKey",
+          },
+          {
+            name: "round",
+            type: "u64",
+          },
+          {
+            name: "merkleRoot",
+            type: {
+              array: [
+                "u8",
+                32,
+              ],
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "rebateBitmap",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "rebatePool",
+            type: "publicKey",
+          },
+          {
+            name: "round",
+            type: "u64",
+          },
+          {
+            name: "chunk",
+            type: "u64",
+          },
+          {
+            name: "bits",
+            type: {
+              array: [
+                "u8",
+                512,
+              ],
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "feeStats",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "referralAccount",
+            type: "publicKey",
+          },
+          {
+            name: "mint",
+            type: "publicKey",
+          },
+          {
+            name: "authority",
+            type: "publicKey",
+          },
+          {
+            name: "sourceTag",
+            type: "string",
+          },
+          {
+            name: "grossVolume",
+            type: "u64",
+          },
+          {
+            name: "feeAmount",
+            type: "u64",
+          },
+          {
+            name: "collections",
+            type: "u64",
+          },
+        ],
+      },
+    },
+    {
+      name: "projectProposal",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "project",
+            type: "publicKey",
+          },
+          {
+            name: "proposer",
+            type: "publicKey",
+          },
+          {
+            name: "id",
+            type: "u64",
+          },
+          {
+            name: "action",
+            type: {
+              defined: "ProjectAction",
+            },
+          },
+          {
+            name: "approvals",
+            type: {
+              vec: "publicKey",
+            },
+          },
+          {
+            name: "expiresAt",
+            type: "i64",
+          },
+          {
+            name: "executed",
+            type: "bool",
+          },
+        ],
+      },
+    },
+  ],
+  types: [
+    {
+      name: "ProjectPolicyMintParams",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "mint",
+            type: "publicKey",
+          },
+        ],
+      },
+    },
+    {
+      name: "ClaimAmountParams",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "amount",
+            type: "u64",
+          },
+        ],
+      },
+    },
+    {
+      name: "ClaimAndSwapParams",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "swapData",
+            type: "bytes",
+          },
+          {
+            name: "minimumOut",
+            type: "u64",
+          },
+        ],
+      },
+    },
+    {
+      name: "ClaimNativeV2Params",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "unwrapReferral",
+            type: "bool",
+          },
+          {
+            name: "unwrapProject",
+            type: "bool",
+          },
+        ],
+      },
+    },
+    {
+      name: "ClaimRebateParams",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "index",
+            type: "u64",
+          },
+          {
+            name: "amount",
+            type: "u64",
+          },
+          {
+            name: "proof",
+            type: {
+              vec: {
+                array: [
+                  "u8",
+                  32,
+                ],
+              },
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "CollectFeeParams",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "feeAmount",
+            type: "u64",
+          },
+          {
+            name: "grossVolume",
+            type: "u64",
+          },
+          {
+            name: "sourceTag",
+            type: "string",
+          },
+        ],
+      },
+    },
+    {
+      name: "CreateProjectProposalParams",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "id",
+            type: "u64",
+          },
+          {
+            name: "action",
+            type: {
+              defined: "ProjectAction",
+            },
+          },
+          {
+            name: "expiresAt",
+            type: "i64",
+          },
+        ],
+      },
+    },
+    {
+      name: "CreateReferralAliasParams",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "alias",
+            type: "string",
+          },
+        ],
+      },
+    },
+    {
+      name: "FundProjectAuthorityParams",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "amount",
+            type: "u64",
+          },
+        ],
+      },
+    },
+    {
+      name: "InitializeProjectParams",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "name",
+            type: "string",
+          },
+          {
+            name: "defaultShareBps",
+            type: "u16",
+          },
+        ],
+      },
+    },
+    {
+      name: "InitializeReferralAccountWithNameParams",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "name",
+            type: "string",
+          },
+        ],
+      },
+    },
+    {
+      name: "InitializeReferralAccountParams",
+      type: {
+        kind: "struct",
+        fields: [],
+      },
+    },
+    {
+      name: "PublishRebateRootParams",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "merkleRoot",
+            type: {
+              array: [
+                "u8",
+                32,
+              ],
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "SetProjectAdminTokenAccountPolicyParams",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "policy",
+            type: {
+              defined: "AdminTokenAccountPolicy",
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "SetProjectAttributionPolicyParams",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "policy",
+            type: {
+              defined: "AttributionPolicy",
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "SetProjectKeeperFeeParams",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "keeperFeeBps",
+            type: "u16",
+          },
+        ],
+      },
+    },
+    {
+      name: "SetProjectMaxKeeperFeeParams",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "mint",
+            type: "publicKey",
+          },
+          {
+            name: "maxKeeperFee",
+            type: "u64",
+          },
+        ],
+      },
+    },
+    {
+      name: "SetMetadataParams",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "uri",
+            type: "string",
+          },
+          {
+            name: "contentHash",
+            type: {
+              array: [
+                "u8",
+                32,
+              ],
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "SetProjectMinClaimAmountParams",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "mint",
+            type: {
+              option: "publicKey",
+            },
+          },
+          {
+            name: "minAmount",
+            type: {
+              option: "u64",
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "SetProjectMintExtensionPolicyParams",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "policy",
+            type: {
+              defined: "MintExtensionPolicy",
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "SetProjectMintPolicyModeParams",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "mode",
+            type: {
+              defined: "MintPolicyMode",
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "SetProjectMultisigParams",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "signers",
+            type: {
+              vec: "publicKey",
+            },
+          },
+          {
+            name: "threshold",
+            type: "u8",
+          },
+        ],
+      },
+    },
+    {
+      name: "SetProjectSponsorshipParams",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "enabled",
+            type: "bool",
+          },
+          {
+            name: "maxSponsoredLamportsPerPartner",
+            type: "u64",
+          },
+        ],
+      },
+    },
+    {
+      name: "SetProjectSwapProgramParams",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "swapProgram",
+            type: {
+              option: "publicKey",
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "SetReferralAccountDisplayNameParams",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "displayName",
+            type: {
+              option: "string",
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "SetReferralAccountRebateParams",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "rebateBps",
+            type: "u16",
+          },
+        ],
+      },
+    },
+    {
+      name: "SetReferralAccountShareModeParams",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "inheritDefault",
+            type: "bool",
+          },
+        ],
+      },
+    },
+    {
+      name: "TransferProjectParams",
+      type: {
+        kind: "struct",
+        fields: [],
+      },
+    },
+    {
+      name: "TransferReferralAccountParams",
+      type: {
+        kind: "struct",
+        fields: [],
+      },
+    },
+    {
+      name: "UpdateProjectParams",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "name",
+            type: {
+              option: "string",
+            },
+          },
+          {
+            name: "defaultShareBps",
+            type: {
+              option: "u16",
+            },
+          },
+          {
+            name: "shareDecreaseNoticePeriod",
+            type: {
+              option: "i64",
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "UpdateReferralAccountsParams",
       type: {
-        kind: "struct";
+        kind: "struct",
         fields: [
           {
-            name: "base";
-            type: "publicKey";
+            name: "shareBps",
+            type: {
+              vec: "u16",
+            },
           },
+        ],
+      },
+    },
+    {
+      name: "UpdateReferralAccountParams",
+      type: {
+        kind: "struct",
+        fields: [
           {
-            name: "admin";
-            type: "publicKey";
+            name: "shareBps",
+            type: "u16",
+          },
+        ],
+      },
+    },
+    {
+      name: "WithdrawFromProjectParams",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "amount",
+            type: "u64",
           },
+        ],
+      },
+    },
+    {
+      name: "PendingShareChange",
+      type: {
+        kind: "struct",
+        fields: [
           {
-            name: "name";
-            type: "string";
+            name: "shareBps",
+            type: "u16",
           },
           {
-            name: "defaultShareBps";
-            type: "u16";
+            name: "effectiveAt",
+            type: "i64",
           },
-        ];
-      };
+        ],
+      },
     },
     {
-      name: "referralAccount";
+      name: "PendingNoticePeriodChange",
       type: {
-        kind: "struct";
+        kind: "struct",
         fields: [
           {
-            name: "partner";
-            type: "publicKey";
+            name: "noticePeriod",
+            type: "i64",
           },
           {
-            name: "project";
-            type: "publicKey";
+            name: "effectiveAt",
+            type: "i64",
           },
+        ],
+      },
+    },
+    {
+      name: "MintExtensionPolicy",
+      type: {
+        kind: "struct",
+        fields: [
           {
-            name: "shareBps";
-            type: "u16";
+            name: "allowPermanentDelegate",
+            type: "bool",
           },
           {
-            name: "name";
-            type: {
-              option: "string";
-            };
+            name: "allowDefaultFrozen",
+            type: "bool",
           },
-        ];
-      };
+          {
+            name: "allowNonTransferable",
+            type: "bool",
+          },
+          {
+            name: "allowPausable",
+            type: "bool",
+          },
+        ],
+      },
     },
-  ];
-  types: [
     {
-      name: "InitializeProjectParams";
+      name: "AdminTokenAccountPolicy",
       type: {
-        kind: "struct";
+        kind: "struct",
         fields: [
           {
-            name: "name";
-            type: "string";
+            name: "requireAllowedMint",
+            type: "bool",
           },
           {
-            name: "defaultShareBps";
-            type: "u16";
+            name: "minReferralBalance",
+            type: "u64",
           },
-        ];
-      };
+          {
+            name: "epochBudgetLamports",
+            type: "u64",
+          },
+        ],
+      },
+    },
+    {
+      name: "AttributionPolicy",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "allowOverride",
+            type: "bool",
+          },
+          {
+            name: "expiry",
+            type: "i64",
+          },
+        ],
+      },
+    },
+    {
+      name: "MintClaimThreshold",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "mint",
+            type: "publicKey",
+          },
+          {
+            name: "minAmount",
+            type: {
+              option: "u64",
+            },
+          },
+          {
+            name: "maxKeeperFee",
+            type: "u64",
+          },
+        ],
+      },
+    },
+    {
+      name: "MintPolicyMode",
+      type: {
+        kind: "enum",
+        variants: [
+          {
+            name: "Allow",
+          },
+          {
+            name: "Deny",
+          },
+        ],
+      },
+    },
+    {
+      name: "ProjectAction",
+      type: {
+        kind: "enum",
+        variants: [
+          {
+            name: "UpdateProject",
+            fields: [
+              {
+                defined: "UpdateProjectParams",
+              },
+            ],
+          },
+          {
+            name: "UpdateReferralAccount",
+            fields: [
+              {
+                name: "referralAccount",
+                type: "publicKey",
+              },
+              {
+                name: "shareBps",
+                type: "u16",
+              },
+            ],
+          },
+          {
+            name: "UpdateReferralAccounts",
+            fields: [
+              {
+                name: "referralAccounts",
+                type: {
+                  vec: "publicKey",
+                },
+              },
+              {
+                name: "shareBps",
+                type: {
+                  vec: "u16",
+                },
+              },
+            ],
+          },
+          {
+            name: "TransferProject",
+            fields: [
+              {
+                name: "newAdmin",
+                type: "publicKey",
+              },
+            ],
+          },
+          {
+            name: "WithdrawFromProject",
+            fields: [
+              {
+                defined: "WithdrawFromProjectParams",
+              },
+            ],
+          },
+          {
+            name: "SetMultisig",
+            fields: [
+              {
+                defined: "SetProjectMultisigParams",
+              },
+            ],
+          },
+          {
+            name: "SetReferralAccountShareMode",
+            fields: [
+              {
+                name: "referralAccount",
+                type: "publicKey",
+              },
+              {
+                name: "inheritDefault",
+                type: "bool",
+              },
+            ],
+          },
+          {
+            name: "SetSwapProgram",
+            fields: [
+              {
+                defined: "SetProjectSwapProgramParams",
+              },
+            ],
+          },
+          {
+            name: "SetMintExtensionPolicy",
+            fields: [
+              {
+                defined: "SetProjectMintExtensionPolicyParams",
+              },
+            ],
+          },
+          {
+            name: "SetMintPolicyMode",
+            fields: [
+              {
+                defined: "SetProjectMintPolicyModeParams",
+              },
+            ],
+          },
+          {
+            name: "AddPolicyMint",
+            fields: [
+              {
+                defined: "ProjectPolicyMintParams",
+              },
+            ],
+          },
+          {
+            name: "RemovePolicyMint",
+            fields: [
+              {
+                defined: "ProjectPolicyMintParams",
+              },
+            ],
+          },
+          {
+            name: "SetMinClaimAmount",
+            fields: [
+              {
+                defined: "SetProjectMinClaimAmountParams",
+              },
+            ],
+          },
+          {
+            name: "SetKeeperFee",
+            fields: [
+              {
+                defined: "SetProjectKeeperFeeParams",
+              },
+            ],
+          },
+          {
+            name: "SetMaxKeeperFee",
+            fields: [
+              {
+                defined: "SetProjectMaxKeeperFeeParams",
+              },
+            ],
+          },
+          {
+            name: "SetSponsorship",
+            fields: [
+              {
+                defined: "SetProjectSponsorshipParams",
+              },
+            ],
+          },
+          {
+            name: "SetAdminTokenAccountPolicy",
+            fields: [
+              {
+                defined: "SetProjectAdminTokenAccountPolicyParams",
+              },
+            ],
+          },
+          {
+            name: "SetMetadata",
+            fields: [
+              {
+                defined: "SetMetadataParams",
+              },
+            ],
+          },
+          {
+            name: "SetAttributionPolicy",
+            fields: [
+              {
+                defined: "SetProjectAttributionPolicyParams",
+              },
+            ],
+          },
+          {
+            name: "SetReferralAccountRebate",
+            fields: [
+              {
+                name: "referralAccount",
+                type: "publicKey",
+              },
+              {
+                name: "rebateBps",
+                type: "u16",
+              },
+            ],
+          },
+          {
+            name: "PublishRebateRoot",
+            fields: [
+              {
+                name: "rebatePool",
+                type: "publicKey",
+              },
+              {
+                name: "merkleRoot",
+                type: {
+                  array: [
+                    "u8",
+                    32,
+                  ],
+                },
+              },
+            ],
+          },
+        ],
+      },
+    },
+  ],
+  events: [
+    {
+      name: "InitializeProjectEvent",
+      fields: [
+        {
+          name: "project",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "admin",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "name",
+          type: "string",
+          index: false,
+        },
+        {
+          name: "defaultShareBps",
+          type: "u16",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "UpdateProjectEvent",
+      fields: [
+        {
+          name: "project",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "previousName",
+          type: "string",
+          index: false,
+        },
+        {
+          name: "name",
+          type: "string",
+          index: false,
+        },
+        {
+          name: "previousDefaultShareBps",
+          type: "u16",
+          index: false,
+        },
+        {
+          name: "defaultShareBps",
+          type: "u16",
+          index: false,
+        },
+        {
+          name: "previousShareDecreaseNoticePeriod",
+          type: "i64",
+          index: false,
+        },
+        {
+          name: "shareDecreaseNoticePeriod",
+          type: "i64",
+          index: false,
+        },
+        {
+          name: "pendingNoticePeriod",
+          type: {
+            option: {
+              defined: "PendingNoticePeriodChange",
+            },
+          },
+          index: false,
+        },
+        {
+          name: "pendingDefaultShare",
+          type: {
+            option: {
+              defined: "PendingShareChange",
+            },
+          },
+          index: false,
+        },
+      ],
     },
     {
-      name: "InitializeReferralAccountWithNameParams";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "name";
-            type: "string";
+      name: "InitializeReferralAccountEvent",
+      fields: [
+        {
+          name: "project",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "partner",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "referralAccount",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "shareBps",
+          type: "u16",
+          index: false,
+        },
+        {
+          name: "name",
+          type: {
+            option: "string",
           },
-        ];
-      };
+          index: false,
+        },
+      ],
     },
     {
-      name: "InitializeReferralAccountParams";
-      type: {
-        kind: "struct";
-        fields: [];
-      };
+      name: "UpdateReferralAccountEvent",
+      fields: [
+        {
+          name: "referralAccount",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "previousShareBps",
+          type: "u16",
+          index: false,
+        },
+        {
+          name: "shareBps",
+          type: "u16",
+          index: false,
+        },
+      ],
     },
     {
-      name: "TransferProjectParams";
-      type: {
-        kind: "struct";
-        fields: [];
-      };
+      name: "UpdateReferralAccountMetadataEvent",
+      fields: [
+        {
+          name: "project",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "referralAccount",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "name",
+          type: {
+            option: "string",
+          },
+          index: false,
+        },
+        {
+          name: "previousDisplayName",
+          type: {
+            option: "string",
+          },
+          index: false,
+        },
+        {
+          name: "displayName",
+          type: {
+            option: "string",
+          },
+          index: false,
+        },
+      ],
     },
     {
-      name: "TransferReferralAccountParams";
-      type: {
-        kind: "struct";
-        fields: [];
-      };
+      name: "QueueReferralAccountShareDecreaseEvent",
+      fields: [
+        {
+          name: "referralAccount",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "shareBps",
+          type: "u16",
+          index: false,
+        },
+        {
+          name: "pendingShareBps",
+          type: "u16",
+          index: false,
+        },
+        {
+          name: "effectiveAt",
+          type: "i64",
+          index: false,
+        },
+      ],
     },
     {
-      name: "UpdateProjectParams";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "name";
-            type: {
-              option: "string";
-            };
-          },
-          {
-            name: "defaultShareBps";
-            type: {
-              option: "u16";
-            };
-          },
-        ];
-      };
+      name: "UpdateReferralAccountShareModeEvent",
+      fields: [
+        {
+          name: "referralAccount",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "inheritDefault",
+          type: "bool",
+          index: false,
+        },
+        {
+          name: "shareBps",
+          type: "u16",
+          index: false,
+        },
+      ],
     },
     {
-      name: "UpdateReferralAccountParams";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "shareBps";
-            type: "u16";
-          },
-        ];
-      };
+      name: "InitializeReferralTokenAccountEvent",
+      fields: [
+        {
+          name: "project",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "referralAccount",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "referralTokenAccount",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "mint",
+          type: "publicKey",
+          index: false,
+        },
+      ],
     },
     {
-      name: "WithdrawFromProjectParams";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "amount";
-            type: "u64";
+      name: "ClaimEvent",
+      fields: [
+        {
+          name: "project",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "projectAdminTokenAccount",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "referralAccount",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "referralTokenAccount",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "partnerTokenAccount",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "mint",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "referralAmount",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "projectAmount",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "shareBps",
+          type: "u16",
+          index: false,
+        },
+        {
+          name: "inheritDefault",
+          type: "bool",
+          index: false,
+        },
+        {
+          name: "referralTransferFee",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "referralNetAmount",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "projectTransferFee",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "projectNetAmount",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "keeperTokenAccount",
+          type: {
+            option: "publicKey",
           },
-        ];
-      };
-    },
-  ];
-  events: [
-    {
-      name: "InitializeProjectEvent";
-      fields: [
+          index: false,
+        },
         {
-          name: "project";
-          type: "publicKey";
-          index: false;
+          name: "keeperAmount",
+          type: "u64",
+          index: false,
         },
         {
-          name: "admin";
-          type: "publicKey";
-          index: false;
+          name: "keeperTransferFee",
+          type: "u64",
+          index: false,
         },
         {
-          name: "name";
-          type: "string";
-          index: false;
+          name: "keeperNetAmount",
+          type: "u64",
+          index: false,
         },
         {
-          name: "defaultShareBps";
-          type: "u16";
-          index: false;
+          name: "rebateTokenAccount",
+          type: {
+            option: "publicKey",
+          },
+          index: false,
         },
-      ];
+        {
+          name: "rebateAmount",
+          type: "u64",
+          index: false,
+        },
+      ],
     },
     {
-      name: "UpdateProjectEvent";
+      name: "ClaimNativeEvent",
       fields: [
         {
-          name: "project";
-          type: "publicKey";
-          index: false;
+          name: "project",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "name";
-          type: "string";
-          index: false;
+          name: "referralAccount",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "defaultShareBps";
-          type: "u16";
-          index: false;
+          name: "referralTokenAccount",
+          type: "publicKey",
+          index: false,
         },
-      ];
-    },
-    {
-      name: "InitializeReferralAccountEvent";
-      fields: [
         {
-          name: "project";
-          type: "publicKey";
-          index: false;
+          name: "mint",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "partner";
-          type: "publicKey";
-          index: false;
+          name: "partner",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "referralAccount";
-          type: "publicKey";
-          index: false;
+          name: "partnerTokenAccount",
+          type: {
+            option: "publicKey",
+          },
+          index: false,
         },
         {
-          name: "shareBps";
-          type: "u16";
-          index: false;
+          name: "admin",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "name";
+          name: "projectAdminTokenAccount",
           type: {
-            option: "string";
-          };
-          index: false;
+            option: "publicKey",
+          },
+          index: false,
         },
-      ];
-    },
-    {
-      name: "UpdateReferralAccountEvent";
-      fields: [
         {
-          name: "referralAccount";
-          type: "publicKey";
-          index: false;
+          name: "referralAmount",
+          type: "u64",
+          index: false,
         },
         {
-          name: "shareBps";
-          type: "u16";
-          index: false;
+          name: "projectAmount",
+          type: "u64",
+          index: false,
         },
-      ];
+        {
+          name: "shareBps",
+          type: "u16",
+          index: false,
+        },
+        {
+          name: "inheritDefault",
+          type: "bool",
+          index: false,
+        },
+        {
+          name: "unwrapReferral",
+          type: "bool",
+          index: false,
+        },
+        {
+          name: "unwrapProject",
+          type: "bool",
+          index: false,
+        },
+        {
+          name: "unwrappedAmount",
+          type: "u64",
+          index: false,
+        },
+      ],
     },
     {
-      name: "InitializeReferralTokenAccountEvent";
+      name: "ClaimAndSwapEvent",
       fields: [
         {
-          name: "project";
-          type: "publicKey";
-          index: false;
+          name: "referralAccount",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "referralAccount";
-          type: "publicKey";
-          index: false;
+          name: "mint",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "referralTokenAccount";
-          type: "publicKey";
-          index: false;
+          name: "inputAmount",
+          type: "u64",
+          index: false,
         },
         {
-          name: "mint";
-          type: "publicKey";
-          index: false;
+          name: "settlementMint",
+          type: "publicKey",
+          index: false,
         },
-      ];
+        {
+          name: "partnerSettlementTokenAccount",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "outputAmount",
+          type: "u64",
+          index: false,
+        },
+      ],
     },
     {
-      name: "ClaimEvent";
+      name: "HarvestReferralTokenAccountFeesEvent",
       fields: [
         {
-          name: "project";
-          type: "publicKey";
-          index: false;
+          name: "referralAccount",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "projectAdminTokenAccount";
-          type: "publicKey";
-          index: false;
+          name: "referralTokenAccount",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "referralAccount";
-          type: "publicKey";
-          index: false;
+          name: "mint",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "referralTokenAccount";
-          type: "publicKey";
-          index: false;
+          name: "withheldAmount",
+          type: "u64",
+          index: false,
         },
+      ],
+    },
+    {
+      name: "SetProjectSwapProgramEvent",
+      fields: [
         {
-          name: "partnerTokenAccount";
-          type: "publicKey";
-          index: false;
+          name: "project",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "mint";
-          type: "publicKey";
-          index: false;
+          name: "swapProgram",
+          type: {
+            option: "publicKey",
+          },
+          index: false,
         },
+      ],
+    },
+    {
+      name: "SetProjectMintExtensionPolicyEvent",
+      fields: [
         {
-          name: "referralAmount";
-          type: "u64";
-          index: false;
+          name: "project",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "projectAmount";
-          type: "u64";
-          index: false;
+          name: "policy",
+          type: {
+            defined: "MintExtensionPolicy",
+          },
+          index: false,
         },
-      ];
+      ],
     },
-  ];
-  errors: [
     {
-      code: 6000;
-      name: "InvalidCalculation";
+      name: "InitializeProjectMintPolicyEvent",
+      fields: [
+        {
+          name: "project",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "mintPolicy",
+          type: "publicKey",
+          index: false,
+        },
+      ],
     },
     {
-      code: 6001;
-      name: "InvalidSharePercentage";
+      name: "SetProjectMintPolicyModeEvent",
+      fields: [
+        {
+          name: "project",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "mode",
+          type: {
+            defined: "MintPolicyMode",
+          },
+          index: false,
+        },
+      ],
     },
     {
-      code: 6002;
-      name: "NameTooLong";
+      name: "AddProjectPolicyMintEvent",
+      fields: [
+        {
+          name: "project",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "mint",
+          type: "publicKey",
+          index: false,
+        },
+      ],
     },
-  ];
-};
-
-export const IDL: Referral = {
-  version: "0.1.0",
-  name: "referral",
-  instructions: [
     {
-      name: "initializeProject",
-      accounts: [
+      name: "RemoveProjectPolicyMintEvent",
+      fields: [
         {
-          name: "payer",
-          isMut: true,
-          isSigner: true,
+          name: "project",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "base",
-          isMut: false,
-          isSigner: true,
+          name: "mint",
+          type: "publicKey",
+          index: false,
         },
+      ],
+    },
+    {
+      name: "InitializeProjectClaimThresholdsEvent",
+      fields: [
         {
-          name: "admin",
-          isMut: false,
-          isSigner: false,
+          name: "project",
+          type: "publicKey",
+          index: false,
         },
+        {
+          name: "claimThresholds",
+          type: "publicKey",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "SetProjectMinClaimAmountEvent",
+      fields: [
         {
           name: "project",
-          isMut: true,
-          isSigner: false,
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
+          name: "mint",
+          type: {
+            option: "publicKey",
+          },
+          index: false,
         },
-      ],
-      args: [
         {
-          name: "params",
+          name: "minAmount",
           type: {
-            defined: "InitializeProjectParams",
+            option: "u64",
           },
+          index: false,
         },
       ],
     },
     {
-      name: "initializeReferralAccount",
-      accounts: [
+      name: "SetProjectKeeperFeeEvent",
+      fields: [
         {
-          name: "payer",
-          isMut: true,
-          isSigner: true,
+          name: "project",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "partner",
-          isMut: false,
-          isSigner: false,
+          name: "keeperFeeBps",
+          type: "u16",
+          index: false,
         },
+      ],
+    },
+    {
+      name: "SetProjectMaxKeeperFeeEvent",
+      fields: [
         {
           name: "project",
-          isMut: false,
-          isSigner: false,
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "referralAccount",
-          isMut: true,
-          isSigner: true,
+          name: "mint",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
+          name: "maxKeeperFee",
+          type: "u64",
+          index: false,
         },
       ],
-      args: [
+    },
+    {
+      name: "SetProjectSponsorshipEvent",
+      fields: [
         {
-          name: "params",
-          type: {
-            defined: "InitializeReferralAccountParams",
-          },
+          name: "project",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "enabled",
+          type: "bool",
+          index: false,
+        },
+        {
+          name: "maxSponsoredLamportsPerPartner",
+          type: "u64",
+          index: false,
         },
       ],
     },
     {
-      name: "initializeReferralAccountWithName",
-      accounts: [
+      name: "FundProjectAuthorityEvent",
+      fields: [
         {
-          name: "payer",
-          isMut: true,
-          isSigner: true,
+          name: "project",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "partner",
-          isMut: false,
-          isSigner: false,
+          name: "projectAuthority",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "funder",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "amount",
+          type: "u64",
+          index: false,
         },
+      ],
+    },
+    {
+      name: "SponsorRentEvent",
+      fields: [
         {
           name: "project",
-          isMut: false,
-          isSigner: false,
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "referralAccount",
-          isMut: true,
-          isSigner: false,
+          name: "partner",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
+          name: "payer",
+          type: "publicKey",
+          index: false,
         },
-      ],
-      args: [
         {
-          name: "params",
-          type: {
-            defined: "InitializeReferralAccountWithNameParams",
-          },
+          name: "lamports",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "sponsoredLamports",
+          type: "u64",
+          index: false,
         },
       ],
     },
     {
-      name: "updateProject",
-      accounts: [
-        {
-          name: "admin",
-          isMut: false,
-          isSigner: true,
-        },
+      name: "SetProjectAdminTokenAccountPolicyEvent",
+      fields: [
         {
           name: "project",
-          isMut: true,
-          isSigner: false,
+          type: "publicKey",
+          index: false,
         },
-      ],
-      args: [
         {
-          name: "params",
+          name: "policy",
           type: {
-            defined: "UpdateProjectParams",
+            defined: "AdminTokenAccountPolicy",
           },
+          index: false,
         },
       ],
     },
     {
-      name: "transferProject",
-      accounts: [
+      name: "CreateAdminTokenAccountEvent",
+      fields: [
+        {
+          name: "project",
+          type: "publicKey",
+          index: false,
+        },
         {
           name: "admin",
-          isMut: false,
-          isSigner: true,
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "newAdmin",
-          isMut: false,
-          isSigner: false,
+          name: "projectAdminTokenAccount",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "project",
-          isMut: true,
-          isSigner: false,
+          name: "mint",
+          type: "publicKey",
+          index: false,
         },
-      ],
-      args: [
         {
-          name: "params",
-          type: {
-            defined: "TransferProjectParams",
-          },
+          name: "lamports",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "epoch",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "epochSpentLamports",
+          type: "u64",
+          index: false,
         },
       ],
     },
     {
-      name: "updateReferralAccount",
-      accounts: [
+      name: "TransferProjectEvent",
+      fields: [
         {
-          name: "admin",
-          isMut: false,
-          isSigner: true,
+          name: "project",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "previousAdmin",
+          type: "publicKey",
+          index: false,
         },
+        {
+          name: "newAdmin",
+          type: "publicKey",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "TransferReferralAccountEvent",
+      fields: [
         {
           name: "project",
-          isMut: false,
-          isSigner: false,
+          type: "publicKey",
+          index: false,
         },
         {
           name: "referralAccount",
-          isMut: true,
-          isSigner: false,
+          type: "publicKey",
+          index: false,
         },
-      ],
-      args: [
         {
-          name: "params",
-          type: {
-            defined: "UpdateReferralAccountParams",
-          },
+          name: "previousPartner",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "newPartner",
+          type: "publicKey",
+          index: false,
         },
       ],
     },
     {
-      name: "transferReferralAccount",
-      accounts: [
+      name: "WithdrawFromProjectEvent",
+      fields: [
         {
-          name: "partner",
-          isMut: false,
-          isSigner: true,
+          name: "project",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "newPartner",
-          isMut: false,
-          isSigner: false,
+          name: "projectAuthority",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "referralAccount",
-          isMut: true,
-          isSigner: false,
+          name: "destination",
+          type: "publicKey",
+          index: false,
         },
-      ],
-      args: [
         {
-          name: "params",
-          type: {
-            defined: "TransferReferralAccountParams",
-          },
+          name: "amount",
+          type: "u64",
+          index: false,
         },
       ],
     },
     {
-      name: "initializeReferralTokenAccount",
-      accounts: [
-        {
-          name: "payer",
-          isMut: true,
-          isSigner: true,
-        },
+      name: "CloseReferralTokenAccountEvent",
+      fields: [
         {
           name: "project",
-          isMut: false,
-          isSigner: false,
+          type: "publicKey",
+          index: false,
         },
         {
           name: "referralAccount",
-          isMut: false,
-          isSigner: false,
+          type: "publicKey",
+          index: false,
         },
         {
           name: "referralTokenAccount",
-          isMut: true,
-          isSigner: false,
+          type: "publicKey",
+          index: false,
         },
         {
           name: "mint",
-          isMut: false,
-          isSigner: false,
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
+          name: "partner",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "tokenProgram",
-          isMut: false,
-          isSigner: false,
+          name: "lamports",
+          type: "u64",
+          index: false,
         },
       ],
-      args: [],
     },
     {
-      name: "claim",
-      accounts: [
-        {
-          name: "payer",
-          isMut: true,
-          isSigner: true,
-        },
+      name: "MigrateProjectEvent",
+      fields: [
         {
           name: "project",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "admin",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "projectAdminTokenAccount",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "referralAccount",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "referralTokenAccount",
-          isMut: true,
-          isSigner: false,
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "partner",
-          isMut: false,
-          isSigner: false,
+          name: "previousVersion",
+          type: "u8",
+          index: false,
         },
         {
-          name: "partnerTokenAccount",
-          isMut: true,
-          isSigner: false,
+          name: "version",
+          type: "u8",
+          index: false,
         },
+      ],
+    },
+    {
+      name: "MigrateReferralAccountEvent",
+      fields: [
         {
-          name: "mint",
-          isMut: false,
-          isSigner: false,
+          name: "project",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "associatedTokenProgram",
-          isMut: false,
-          isSigner: false,
+          name: "referralAccount",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
+          name: "previousVersion",
+          type: "u8",
+          index: false,
         },
         {
-          name: "tokenProgram",
-          isMut: false,
-          isSigner: false,
+          name: "version",
+          type: "u8",
+          index: false,
         },
       ],
-      args: [],
     },
     {
-      name: "createAdminTokenAccount",
-      accounts: [
+      name: "InitializeProjectMetadataEvent",
+      fields: [
         {
           name: "project",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "projectAuthority",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "admin",
-          isMut: false,
-          isSigner: false,
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "projectAdminTokenAccount",
-          isMut: true,
-          isSigner: false,
+          name: "metadata",
+          type: "publicKey",
+          index: false,
         },
+      ],
+    },
+    {
+      name: "SetProjectMetadataEvent",
+      fields: [
         {
-          name: "mint",
-          isMut: false,
-          isSigner: false,
+          name: "project",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
+          name: "metadata",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "tokenProgram",
-          isMut: false,
-          isSigner: false,
+          name: "uri",
+          type: "string",
+          index: false,
         },
         {
-          name: "associatedTokenProgram",
-          isMut: false,
-          isSigner: false,
+          name: "contentHash",
+          type: {
+            array: [
+              "u8",
+              32,
+            ],
+          },
+          index: false,
         },
       ],
-      args: [],
     },
     {
-      name: "withdrawFromProject",
-      accounts: [
+      name: "SetReferralAccountMetadataEvent",
+      fields: [
         {
-          name: "admin",
-          isMut: true,
-          isSigner: true,
+          name: "project",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "project",
-          isMut: false,
-          isSigner: false,
+          name: "referralAccount",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "projectAuthority",
-          isMut: true,
-          isSigner: false,
+          name: "metadata",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
+          name: "uri",
+          type: "string",
+          index: false,
         },
-      ],
-      args: [
         {
-          name: "params",
+          name: "contentHash",
           type: {
-            defined: "WithdrawFromProjectParams",
+            array: [
+              "u8",
+              32,
+            ],
           },
+          index: false,
         },
       ],
     },
     {
-      name: "claimV2",
-      accounts: [
-        {
-          name: "payer",
-          isMut: true,
-          isSigner: true,
-        },
+      name: "CreateReferralAliasEvent",
+      fields: [
         {
           name: "project",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "admin",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "projectAdminTokenAccount",
-          isMut: true,
-          isSigner: false,
+          type: "publicKey",
+          index: false,
         },
         {
           name: "referralAccount",
-          isMut: false,
-          isSigner: false,
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "referralTokenAccount",
-          isMut: true,
-          isSigner: false,
+          name: "aliasAccount",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "partner",
-          isMut: false,
-          isSigner: false,
+          name: "alias",
+          type: "string",
+          index: false,
         },
+      ],
+    },
+    {
+      name: "DeleteReferralAliasEvent",
+      fields: [
         {
-          name: "partnerTokenAccount",
-          isMut: true,
-          isSigner: false,
+          name: "project",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "mint",
-          isMut: false,
-          isSigner: false,
+          name: "referralAccount",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
+          name: "aliasAccount",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "tokenProgram",
-          isMut: false,
-          isSigner: false,
+          name: "alias",
+          type: "string",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "SetProjectAttributionPolicyEvent",
+      fields: [
+        {
+          name: "project",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "associatedTokenProgram",
-          isMut: false,
-          isSigner: false,
+          name: "policy",
+          type: {
+            defined: "AttributionPolicy",
+          },
+          index: false,
         },
       ],
-      args: [],
     },
     {
-      name: "closeReferralTokenAccountV2",
-      accounts: [
+      name: "AttributeUserEvent",
+      fields: [
         {
-          name: "admin",
-          isMut: true,
-          isSigner: true,
+          name: "project",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "project",
-          isMut: false,
-          isSigner: false,
+          name: "user",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "userAttribution",
+          type: "publicKey",
+          index: false,
         },
         {
           name: "referralAccount",
-          isMut: false,
-          isSigner: false,
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "referralTokenAccount",
-          isMut: true,
-          isSigner: false,
+          name: "previousReferralAccount",
+          type: {
+            option: "publicKey",
+          },
+          index: false,
         },
         {
-          name: "partner",
-          isMut: true,
-          isSigner: false,
+          name: "attributedAt",
+          type: "i64",
+          index: false,
         },
+      ],
+    },
+    {
+      name: "SetReferralAccountRebateEvent",
+      fields: [
         {
-          name: "mint",
-          isMut: false,
-          isSigner: false,
+          name: "referralAccount",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "tokenProgram",
-          isMut: false,
-          isSigner: false,
+          name: "previousRebateBps",
+          type: "u16",
+          index: false,
         },
         {
-          name: "associatedTokenProgram",
-          isMut: false,
-          isSigner: false,
+          name: "rebateBps",
+          type: "u16",
+          index: false,
         },
       ],
-      args: [],
-    },
-  ],
-  accounts: [
-    {
-      name: "project",
-      type: {
-        kind: "struct",
-        fields: [
-          {
-            name: "base",
-            type: "publicKey",
-          },
-          {
-            name: "admin",
-            type: "publicKey",
-          },
-          {
-            name: "name",
-            type: "string",
-          },
-          {
-            name: "defaultShareBps",
-            type: "u16",
-          },
-        ],
-      },
-    },
-    {
-      name: "referralAccount",
-      type: {
-        kind: "struct",
-        fields: [
-          {
-            name: "partner",
-            type: "publicKey",
-          },
-          {
-            name: "project",
-            type: "publicKey",
-          },
-          {
-            name: "shareBps",
-            type: "u16",
-          },
-          {
-            name: "name",
-            type: {
-              option: "string",
-            },
-          },
-        ],
-      },
-    },
-  ],
-  types: [
-    {
-      name: "InitializeProjectParams",
-      type: {
-        kind: "struct",
-        fields: [
-          {
-            name: "name",
-            type: "string",
-          },
-          {
-            name: "defaultShareBps",
-            type: "u16",
-          },
-        ],
-      },
-    },
-    {
-      name: "InitializeReferralAccountWithNameParams",
-      type: {
-        kind: "struct",
-        fields: [
-          {
-            name: "name",
-            type: "string",
-          },
-        ],
-      },
-    },
-    {
-      name: "InitializeReferralAccountParams",
-      type: {
-        kind: "struct",
-        fields: [],
-      },
-    },
-    {
-      name: "TransferProjectParams",
-      type: {
-        kind: "struct",
-        fields: [],
-      },
-    },
-    {
-      name: "TransferReferralAccountParams",
-      type: {
-        kind: "struct",
-        fields: [],
-      },
-    },
-    {
-      name: "UpdateProjectParams",
-      type: {
-        kind: "struct",
-        fields: [
-          {
-            name: "name",
-            type: {
-              option: "string",
-            },
-          },
-          {
-            name: "defaultShareBps",
-            type: {
-              option: "u16",
-            },
-          },
-        ],
-      },
-    },
-    {
-      name: "UpdateReferralAccountParams",
-      type: {
-        kind: "struct",
-        fields: [
-          {
-            name: "shareBps",
-            type: "u16",
-          },
-        ],
-      },
     },
     {
-      name: "WithdrawFromProjectParams",
-      type: {
-        kind: "struct",
-        fields: [
-          {
-            name: "amount",
-            type: "u64",
-          },
-        ],
-      },
+      name: "QueueReferralAccountRebateIncreaseEvent",
+      fields: [
+        {
+          name: "referralAccount",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "rebateBps",
+          type: "u16",
+          index: false,
+        },
+        {
+          name: "pendingRebateBps",
+          type: "u16",
+          index: false,
+        },
+        {
+          name: "effectiveAt",
+          type: "i64",
+          index: false,
+        },
+      ],
     },
-  ],
-  events: [
     {
-      name: "InitializeProjectEvent",
+      name: "InitializeRebatePoolEvent",
       fields: [
         {
           name: "project",
@@ -1530,24 +11333,29 @@ export const IDL: Referral = {
           index: false,
         },
         {
-          name: "admin",
+          name: "referralAccount",
           type: "publicKey",
           index: false,
         },
         {
-          name: "name",
-          type: "string",
+          name: "rebatePool",
+          type: "publicKey",
           index: false,
         },
         {
-          name: "defaultShareBps",
-          type: "u16",
+          name: "rebatePoolTokenAccount",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "mint",
+          type: "publicKey",
           index: false,
         },
       ],
     },
     {
-      name: "UpdateProjectEvent",
+      name: "PublishRebateRootEvent",
       fields: [
         {
           name: "project",
@@ -1555,19 +11363,29 @@ export const IDL: Referral = {
           index: false,
         },
         {
-          name: "name",
-          type: "string",
+          name: "rebatePool",
+          type: "publicKey",
           index: false,
         },
         {
-          name: "defaultShareBps",
-          type: "u16",
+          name: "round",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "merkleRoot",
+          type: {
+            array: [
+              "u8",
+              32,
+            ],
+          },
           index: false,
         },
       ],
     },
     {
-      name: "InitializeReferralAccountEvent",
+      name: "ClaimRebateEvent",
       fields: [
         {
           name: "project",
@@ -1575,46 +11393,49 @@ export const IDL: Referral = {
           index: false,
         },
         {
-          name: "partner",
+          name: "referralAccount",
           type: "publicKey",
           index: false,
         },
         {
-          name: "referralAccount",
+          name: "rebatePool",
           type: "publicKey",
           index: false,
         },
         {
-          name: "shareBps",
-          type: "u16",
+          name: "trader",
+          type: "publicKey",
           index: false,
         },
         {
-          name: "name",
-          type: {
-            option: "string",
-          },
+          name: "traderTokenAccount",
+          type: "publicKey",
           index: false,
         },
-      ],
-    },
-    {
-      name: "UpdateReferralAccountEvent",
-      fields: [
         {
-          name: "referralAccount",
+          name: "mint",
           type: "publicKey",
           index: false,
         },
         {
-          name: "shareBps",
-          type: "u16",
+          name: "round",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "index",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "amount",
+          type: "u64",
           index: false,
         },
       ],
     },
     {
-      name: "InitializeReferralTokenAccountEvent",
+      name: "FeeCollectedEvent",
       fields: [
         {
           name: "project",
@@ -1636,10 +11457,45 @@ export const IDL: Referral = {
           type: "publicKey",
           index: false,
         },
+        {
+          name: "sourceTokenAccount",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "feeStats",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "sourceTag",
+          type: "string",
+          index: false,
+        },
+        {
+          name: "grossVolume",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "feeAmount",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "transferFee",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "netAmount",
+          type: "u64",
+          index: false,
+        },
       ],
     },
     {
-      name: "ClaimEvent",
+      name: "SetProjectMultisigEvent",
       fields: [
         {
           name: "project",
@@ -1647,38 +11503,92 @@ export const IDL: Referral = {
           index: false,
         },
         {
-          name: "projectAdminTokenAccount",
+          name: "signers",
+          type: {
+            vec: "publicKey",
+          },
+          index: false,
+        },
+        {
+          name: "threshold",
+          type: "u8",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "CreateProjectProposalEvent",
+      fields: [
+        {
+          name: "project",
           type: "publicKey",
           index: false,
         },
         {
-          name: "referralAccount",
+          name: "proposal",
           type: "publicKey",
           index: false,
         },
         {
-          name: "referralTokenAccount",
+          name: "proposer",
           type: "publicKey",
           index: false,
         },
         {
-          name: "partnerTokenAccount",
+          name: "id",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "action",
+          type: {
+            defined: "ProjectAction",
+          },
+          index: false,
+        },
+        {
+          name: "expiresAt",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "ApproveProjectProposalEvent",
+      fields: [
+        {
+          name: "project",
           type: "publicKey",
           index: false,
         },
         {
-          name: "mint",
+          name: "proposal",
           type: "publicKey",
           index: false,
         },
         {
-          name: "referralAmount",
-          type: "u64",
+          name: "signer",
+          type: "publicKey",
           index: false,
         },
+      ],
+    },
+    {
+      name: "ExecuteProjectProposalEvent",
+      fields: [
         {
-          name: "projectAmount",
-          type: "u64",
+          name: "project",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "proposal",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "executor",
+          type: "publicKey",
           index: false,
         },
       ],
@@ -1697,5 +11607,209 @@ export const IDL: Referral = {
       code: 6002,
       name: "NameTooLong",
     },
+    {
+      code: 6003,
+      name: "MultisigRequired",
+    },
+    {
+      code: 6004,
+      name: "InvalidMultisigConfig",
+    },
+    {
+      code: 6005,
+      name: "NotMultisigSigner",
+    },
+    {
+      code: 6006,
+      name: "InvalidProposalExpiry",
+    },
+    {
+      code: 6007,
+      name: "ProposalExpired",
+    },
+    {
+      code: 6008,
+      name: "ProposalAlreadyExecuted",
+    },
+    {
+      code: 6009,
+      name: "ProposalAlreadyApproved",
+    },
+    {
+      code: 6010,
+      name: "ProposalThresholdNotMet",
+    },
+    {
+      code: 6011,
+      name: "ProposalNotClosable",
+    },
+    {
+      code: 6012,
+      name: "MissingProposalAccount",
+    },
+    {
+      code: 6013,
+      name: "InvalidNoticePeriod",
+    },
+    {
+      code: 6014,
+      name: "ShareModeChangeDecreasesShare",
+    },
+    {
+      code: 6015,
+      name: "InvalidReferralAccountsUpdate",
+    },
+    {
+      code: 6016,
+      name: "ClaimAmountExceedsBalance",
+    },
+    {
+      code: 6017,
+      name: "InvalidClaimManyAccounts",
+    },
+    {
+      code: 6018,
+      name: "MissingPayoutTokenAccount",
+    },
+    {
+      code: 6019,
+      name: "SwapProgramNotAllowed",
+    },
+    {
+      code: 6020,
+      name: "SwapInputNotConsumed",
+    },
+    {
+      code: 6021,
+      name: "SwapOutputTooLow",
+    },
+    {
+      code: 6022,
+      name: "MintHasPermanentDelegate",
+    },
+    {
+      code: 6023,
+      name: "MintDefaultFrozen",
+    },
+    {
+      code: 6024,
+      name: "MintNonTransferable",
+    },
+    {
+      code: 6025,
+      name: "MintPausable",
+    },
+    {
+      code: 6026,
+      name: "MissingMintPolicyAccount",
+    },
+    {
+      code: 6027,
+      name: "MintNotAllowed",
+    },
+    {
+      code: 6028,
+      name: "MintPolicyFull",
+    },
+    {
+      code: 6029,
+      name: "MintAlreadyInPolicy",
+    },
+    {
+      code: 6030,
+      name: "MintNotInPolicy",
+    },
+    {
+      code: 6031,
+      name: "MissingClaimThresholdsAccount",
+    },
+    {
+      code: 6032,
+      name: "ClaimAmountBelowMinimum",
+    },
+    {
+      code: 6033,
+      name: "ClaimThresholdsFull",
+    },
+    {
+      code: 6034,
+      name: "SponsorshipDisabled",
+    },
+    {
+      code: 6035,
+      name: "SponsorshipCapExceeded",
+    },
+    {
+      code: 6036,
+      name: "MissingReferralTokenAccount",
+    },
+    {
+      code: 6037,
+      name: "ReferralBalanceTooLow",
+    },
+    {
+      code: 6038,
+      name: "EpochBudgetExceeded",
+    },
+    {
+      code: 6039,
+      name: "MetadataUriTooLong",
+    },
+    {
+      code: 6040,
+      name: "InvalidAlias",
+    },
+    {
+      code: 6041,
+      name: "InvalidAttributionExpiry",
+    },
+    {
+      code: 6042,
+      name: "UserAlreadyAttributed",
+    },
+    {
+      code: 6043,
+      name: "RebateRequiresClaimV2",
+    },
+    {
+      code: 6044,
+      name: "MissingRebatePoolAccount",
+    },
+    {
+      code: 6045,
+      name: "InvalidRebatePoolAccount",
+    },
+    {
+      code: 6046,
+      name: "InvalidMerkleProof",
+    },
+    {
+      code: 6047,
+      name: "RebateAlreadyClaimed",
+    },
+    {
+      code: 6048,
+      name: "InvalidSourceTag",
+    },
+    {
+      code: 6049,
+      name: "MintAllowlistRequired",
+    },
+    {
+      code: 6050,
+      name: "InvalidReferralTokenAccount",
+    },
+    {
+      code: 6051,
+      name: "InvalidFeeAmount",
+    },
+    {
+      code: 6052,
+      name: "InvalidKeeperFee",
+    },
+    {
+      code: 6053,
+      name: "ReferralAccountNotMigrated",
+    },
   ],
 };
@@ -3,3 +3,4 @@ export * from "./idl";
 export * from "./filter";
 export * from "./constant";
 export * from "./types";
+export * from "./transferHook";
@@ -25,6 +25,7 @@ import { chunkedGetMultipleAccountInfos } from "./chunks";
 import { PROGRAM_ID } from "./constant";
 import { feeService } from "./FeeService";
 import { IDL, Referral } from "./idl";
+import { resolveClaimV2TransferHookAccounts } from "./transferHook";
 import { getOrCreateATAInstruction } from "./utils";
 
 export interface InitializeProjectVariable {
@@ -119,6 +120,17 @@ export class ReferralProvider {
     this.program = new Program(IDL, PROGRAM_ID, provider);
   }
 
+  // Accounts of the event self-CPI, which every instruction emitting events takes last.
+  private get eventCpiAccounts() {
+    return {
+      eventAuthority: PublicKey.findProgramAddressSync(
+        [Buffer.from("__event_authority")],
+        this.program.programId,
+      )[0],
+      program: this.program.programId,
+    };
+  }
+
   public async getProjects(filters: GetProgramAccountsFilter[] = []) {
     return await this.program.account.project.all(filters);
   }
@@ -146,6 +158,88 @@ export class ReferralProvider {
     return projectAuthority;
   }
 
+  // The mint policy account that token account creation and claims must pass, once the project
+  // has one.
+  public getMintPolicyPubKey(
+    projectPubKey: PublicKey,
+    project: IdlAccounts<Referral>["project"],
+  ): PublicKey | null {
+    if (!project.hasMintPolicy) return null;
+
+    const [mintPolicy] = PublicKey.findProgramAddressSync(
+      [Buffer.from("mint_policy"), projectPubKey.toBuffer()],
+      this.program.programId,
+    );
+
+    return mintPolicy;
+  }
+
+  // The payer's token account that claim_v2 pays the keeper fee to, when the project charges one.
+  // The fee is capped per mint by the claim thresholds, which the claim then has to be given.
+  public getKeeperTokenAccountPubKey(
+    project: IdlAccounts<Referral>["project"],
+    payerPubKey: PublicKey,
+    mint: PublicKey,
+    tokenProgramId: PublicKey,
+  ): PublicKey | null {
+    if (project.keeperFeeBps === 0 || !project.hasClaimThresholds) return null;
+
+    return getAssociatedTokenAddressSync(
+      mint,
+      payerPubKey,
+      true,
+      tokenProgramId,
+    );
+  }
+
+  // The claim thresholds account that claims must pass, once the project has one.
+  public getClaimThresholdsPubKey(
+    projectPubKey: PublicKey,
+    project: IdlAccounts<Referral>["project"],
+  ): PublicKey | null {
+    if (!project.hasClaimThresholds) return null;
+
+    const [claimThresholds] = PublicKey.findProgramAddressSync(
+      [Buffer.from("claim_thresholds"), projectPubKey.toBuffer()],
+      this.program.programId,
+    );
+
+    return claimThresholds;
+  }
+
+  // The token account of the rebate pool that claim_v2 pays the traders' part of the referral
+  // share into, while the referral account has a rebate in effect.
+  public getRebatePoolTokenAccountPubKey(
+    referralAccountPubKey: PublicKey,
+    referralAccount: IdlAccounts<Referral>["referralAccount"],
+    mint: PublicKey,
+    tokenProgramId: PublicKey,
+  ): PublicKey | null {
+    const now = Date.now() / 1000;
+    const pendingRebate = referralAccount.pendingRebate;
+    const rebateBps =
+      pendingRebate && pendingRebate.effectiveAt.toNumber() <= now
+        ? pendingRebate.shareBps
+        : referralAccount.rebateBps;
+    if (rebateBps === 0) return null;
+
+    const [rebatePool] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("rebate_pool"),
+        referralAccountPubKey.toBuffer(),
+        mint.toBuffer(),
+      ],
+      this.program.programId,
+    );
+
+    return getAssociatedTokenAddressSync(
+      mint,
+      rebatePool,
+      true,
+      tokenProgramId,
+    );
+  }
+
   public getReferralAccountWithNamePubKey({
     projectPubKey,
     name,
@@ -456,6 +550,7 @@ export class ReferralProvider {
         admin: adminPubKey,
         project: projectPubKey,
         base: basePubKey,
+        ...this.eventCpiAccounts,
       })
       .transaction();
   }
@@ -472,6 +567,7 @@ export class ReferralProvider {
         admin: project.admin,
         project: projectPubKey,
         newAdmin: newAdminPubKey,
+        ...this.eventCpiAccounts,
       })
       .transaction();
   }
@@ -489,6 +585,7 @@ export class ReferralProvider {
         partner: partnerPubKey,
         referralAccount: referralAccountPubKey,
         payer: payerPubKey,
+        ...this.eventCpiAccounts,
       })
       .transaction();
   }
@@ -514,6 +611,7 @@ export class ReferralProvider {
         partner: partnerPubKey,
         referralAccount: referralAccountPubKey,
         payer: payerPubKey,
+        ...this.eventCpiAccounts,
       })
       .transaction();
 
@@ -534,6 +632,7 @@ export class ReferralProvider {
         partner: referralAccount.partner,
         newPartner: newPartnerPubKey,
         referralAccount: referralAccountPubKey,
+        ...this.eventCpiAccounts,
       })
       .transaction();
   }
@@ -559,6 +658,13 @@ export class ReferralProvider {
     const referralAccount = await this.program.account.referralAccount.fetch(
       referralAccountPubKey,
     );
+    const project = await this.program.account.project.fetch(
+      referralAccount.project,
+    );
+    const mintPolicy = this.getMintPolicyPubKey(
+      referralAccount.project,
+      project,
+    );
 
     const referralTokenAccountPubKey = this.getReferralTokenAccountPubKey({
       referralAccountPubKey,
@@ -574,6 +680,8 @@ export class ReferralProvider {
         referralTokenAccount: referralTokenAccountPubKey,
         mint,
         tokenProgram: mintAccount.owner,
+        mintPolicy,
+        ...this.eventCpiAccounts,
       })
       .transaction();
 
@@ -598,22 +706,40 @@ export class ReferralProvider {
     )
       throw new Error("Invalid mint");
 
-    const tokenAccount = getAssociatedTokenAddressSync(
-      mint,
+    const referralAccount = await this.program.account.referralAccount.fetch(
       referralAccountPubKey,
-      true,
-      mintAccount.owner,
+    );
+    const project = await this.program.account.project.fetch(
+      referralAccount.project,
     );
 
-    const ix = createAssociatedTokenAccountIdempotentInstruction(
-      payerPubKey,
-      tokenAccount,
-      referralAccountPubKey,
+    const tokenAccount = getAssociatedTokenAddressSync(
       mint,
+      referralAccountPubKey,
+      true,
       mintAccount.owner,
     );
 
-    const tx = new Transaction().add(ix);
+    // Opened through the program rather than the associated token program, so that the mint
+    // passes the project's mint and extension policies.
+    const tx = await this.program.methods
+      .initializeReferralTokenAccountV2()
+      .accountsStrict({
+        payer: payerPubKey,
+        project: referralAccount.project,
+        referralAccount: referralAccountPubKey,
+        referralTokenAccount: tokenAccount,
+        mint,
+        systemProgram: SystemProgram.programId,
+        tokenProgram: mintAccount.owner,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        mintPolicy: this.getMintPolicyPubKey(
+          referralAccount.project,
+          project,
+        ),
+        ...this.eventCpiAccounts,
+      })
+      .transaction();
 
     return { tx, tokenAccount };
   }
@@ -639,6 +765,14 @@ export class ReferralProvider {
     const project = await this.program.account.project.fetch(
       referralAccount.project,
     );
+    const mintPolicy = this.getMintPolicyPubKey(
+      referralAccount.project,
+      project,
+    );
+    const claimThresholds = this.getClaimThresholdsPubKey(
+      referralAccount.project,
+      project,
+    );
 
     const [
       referralTokenAccountPubKey,
@@ -681,6 +815,10 @@ export class ReferralProvider {
           projectAdminTokenAccount: projectAdminTokenAccount,
           mint,
           tokenProgram: mintAccount.owner,
+          mintPolicy,
+          referralAccount: referralAccountPubKey,
+          referralTokenAccount: referralTokenAccountPubKey,
+          ...this.eventCpiAccounts,
         })
         .instruction();
 
@@ -700,6 +838,9 @@ export class ReferralProvider {
         partnerTokenAccount: partnerTokenAccount,
         mint,
         tokenProgram: mintAccount.owner,
+        mintPolicy,
+        claimThresholds,
+        ...this.eventCpiAccounts,
       })
       .preInstructions(preInstructions)
       .transaction();
@@ -757,6 +898,14 @@ export class ReferralProvider {
     const project = await this.program.account.project.fetch(
       referralAccount.project,
     );
+    const mintPolicy = this.getMintPolicyPubKey(
+      referralAccount.project,
+      project,
+    );
+    const claimThresholds = this.getClaimThresholdsPubKey(
+      referralAccount.project,
+      project,
+    );
 
     const [
       referralTokenAccountPubKey,
@@ -786,9 +935,32 @@ export class ReferralProvider {
       ),
     ]);
 
+    const keeperTokenAccount = this.getKeeperTokenAccountPubKey(
+      project,
+      payerPubKey,
+      mint,
+      mintAccount.owner,
+    );
+    const rebatePoolTokenAccount = this.getRebatePoolTokenAccountPubKey(
+      referralAccountPubKey,
+      referralAccount,
+      mint,
+      mintAccount.owner,
+    );
+
     let preInstructions: TransactionInstruction[] = [];
     if (createPartnerTokenAccountIx)
       preInstructions.push(createPartnerTokenAccountIx);
+    if (keeperTokenAccount)
+      preInstructions.push(
+        createAssociatedTokenAccountIdempotentInstruction(
+          payerPubKey,
+          keeperTokenAccount,
+          payerPubKey,
+          mint,
+          mintAccount.owner,
+        ),
+      );
     if (createProjectAdminTokenAccountIx) {
       const projectAuthority = this.getProjectAuthorityPubKey(project);
       const ix = await this.program.methods
@@ -800,12 +972,30 @@ export class ReferralProvider {
           projectAdminTokenAccount: projectAdminTokenAccount,
           mint,
           tokenProgram: mintAccount.owner,
+          mintPolicy,
+          referralAccount: referralAccountPubKey,
+          referralTokenAccount: referralTokenAccountPubKey,
+          ...this.eventCpiAccounts,
         })
         .instruction();
 
       preInstructions.push(ix);
     }
 
+    const transferHookAccounts = await resolveClaimV2TransferHookAccounts({
+      connection: this.connection,
+      tokenProgramId: mintAccount.owner,
+      referralAccount: referralAccountPubKey,
+      referralTokenAccount: referralTokenAccountPubKey,
+      mint,
+      destinations: [
+        partnerTokenAccount,
+        projectAdminTokenAccount,
+        keeperTokenAccount,
+        rebatePoolTokenAccount,
+      ],
+    });
+
     const transaction = await this.program.methods
       .claimV2()
       .accountsStrict({
@@ -821,7 +1011,13 @@ export class ReferralProvider {
         tokenProgram: mintAccount.owner,
         systemProgram: SystemProgram.programId,
         associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        mintPolicy,
+        claimThresholds,
+        keeperTokenAccount,
+        rebatePoolTokenAccount,
+        ...this.eventCpiAccounts,
       })
+      .remainingAccounts(transferHookAccounts)
       .preInstructions(preInstructions)
       .transaction();
     const instructions = transaction.instructions;
@@ -875,6 +1071,14 @@ export class ReferralProvider {
     const project = await this.program.account.project.fetch(
       referralAccount.project,
     );
+    const mintPolicy = this.getMintPolicyPubKey(
+      referralAccount.project,
+      project,
+    );
+    const claimThresholds = this.getClaimThresholdsPubKey(
+      referralAccount.project,
+      project,
+    );
     const projectAuthority = this.getProjectAuthorityPubKey(project);
 
     const { tokenAccounts, token2022Accounts } = strategy
@@ -933,6 +1137,10 @@ export class ReferralProvider {
                 projectAdminTokenAccount,
                 mint: token.account.mint,
                 tokenProgram: tokenProgramId,
+                mintPolicy,
+                referralAccount: referralAccountPubKey,
+                referralTokenAccount: referralTokenAccountPubKey,
+                ...this.eventCpiAccounts,
               })
               .instruction();
 
@@ -971,6 +1179,9 @@ export class ReferralProvider {
               partnerTokenAccount: partnerTokenAccount,
               mint,
               tokenProgram: tokenProgramId,
+              mintPolicy,
+              claimThresholds,
+              ...this.eventCpiAccounts,
             })
             .preInstructions(preInstructions)
             .transaction();
@@ -1031,6 +1242,14 @@ export class ReferralProvider {
     const project = await this.program.account.project.fetch(
       referralAccount.project,
     );
+    const mintPolicy = this.getMintPolicyPubKey(
+      referralAccount.project,
+      project,
+    );
+    const claimThresholds = this.getClaimThresholdsPubKey(
+      referralAccount.project,
+      project,
+    );
     const projectAuthority = this.getProjectAuthorityPubKey(project);
 
     const { tokenAccounts, token2022Accounts } =
@@ -1070,6 +1289,30 @@ export class ReferralProvider {
               true,
               tokenProgramId,
             );
+
+            const keeperTokenAccount = this.getKeeperTokenAccountPubKey(
+              project,
+              payerPubKey,
+              token.account.mint,
+              tokenProgramId,
+            );
+            const rebatePoolTokenAccount =
+              this.getRebatePoolTokenAccountPubKey(
+                referralAccountPubKey,
+                referralAccount,
+                token.account.mint,
+                tokenProgramId,
+              );
+            if (keeperTokenAccount)
+              preInstructions.push(
+                createAssociatedTokenAccountIdempotentInstruction(
+                  payerPubKey,
+                  keeperTokenAccount,
+                  payerPubKey,
+                  token.account.mint,
+                  tokenProgramId,
+                ),
+              );
             const ix = await this.program.methods
               .createAdminTokenAccount()
               .accounts({
@@ -1079,15 +1322,37 @@ export class ReferralProvider {
                 projectAdminTokenAccount,
                 mint: token.account.mint,
                 tokenProgram: tokenProgramId,
+                mintPolicy,
+                referralAccount: referralAccountPubKey,
+                referralTokenAccount: token.pubkey,
+                ...this.eventCpiAccounts,
               })
               .instruction();
 
             preInstructions.push(ix);
 
+            const transferHookAccounts =
+              await resolveClaimV2TransferHookAccounts({
+                connection: this.connection,
+                tokenProgramId,
+                referralAccount: referralAccountPubKey,
+                referralTokenAccount: token.pubkey,
+                mint: token.account.mint,
+                destinations: [
+                  partnerTokenAccount,
+                  projectAdminTokenAccount,
+                  keeperTokenAccount,
+                  rebatePoolTokenAccount,
+                ],
+              });
+
             return {
               projectAdminTokenAccount,
               partnerTokenAccount,
+              keeperTokenAccount,
+              rebatePoolTokenAccount,
               preInstructions,
+              transferHookAccounts,
               mint: token.account.mint,
               referralTokenAccount: token.pubkey, // Use ATA directly
             };
@@ -1100,8 +1365,11 @@ export class ReferralProvider {
         for (const {
           projectAdminTokenAccount,
           partnerTokenAccount,
+          keeperTokenAccount,
+          rebatePoolTokenAccount,
           mint,
           preInstructions,
+          transferHookAccounts,
           referralTokenAccount,
         } of claimParams) {
           const tx = await this.program.methods
@@ -1119,7 +1387,13 @@ export class ReferralProvider {
               tokenProgram: tokenProgramId,
               systemProgram: SystemProgram.programId,
               associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+              mintPolicy,
+              claimThresholds,
+              keeperTokenAccount,
+              rebatePoolTokenAccount,
+              ...this.eventCpiAccounts,
             })
+            .remainingAccounts(transferHookAccounts)
             .preInstructions(preInstructions)
             .transaction();
           instructions.push(...tx.instructions);
@@ -1190,6 +1464,14 @@ export class ReferralProvider {
     const project = await this.program.account.project.fetch(
       referralAccount.project,
     );
+    const mintPolicy = this.getMintPolicyPubKey(
+      referralAccount.project,
+      project,
+    );
+    const claimThresholds = this.getClaimThresholdsPubKey(
+      referralAccount.project,
+      project,
+    );
     const projectAuthority = this.getProjectAuthorityPubKey(project);
 
     const result = await this.connection.getMultipleAccountsInfo(
@@ -1242,6 +1524,10 @@ export class ReferralProvider {
             projectAdminTokenAccount,
             mint: tokenAccountData.mint,
             tokenProgram: tokenProgramId,
+            mintPolicy,
+            referralAccount: referralAccountPubKey,
+            referralTokenAccount: referralTokenAccountPubKey,
+            ...this.eventCpiAccounts,
           })
           .instruction();
 
@@ -1287,6 +1573,9 @@ export class ReferralProvider {
                   partnerTokenAccount: partnerTokenAccount,
                   mint,
                   tokenProgram: tokenProgramId,
+                  mintPolicy,
+                  claimThresholds,
+                  ...this.eventCpiAccounts,
                 })
                 .preInstructions(preInstructions)
                 .transaction();
@@ -0,0 +1,172 @@
+import { TOKEN_2022_PROGRAM_ID } from "@solana/spl-token";
+import { AccountMeta, Connection, PublicKey } from "@solana/web3.js";
+
+// Token-2022 mints keep their extensions as TLV entries after the account type byte.
+const MINT_EXTENSIONS_OFFSET = 165 + 1;
+const TRANSFER_HOOK_EXTENSION_TYPE = 14;
+// An ExtraAccountMetaList is a TLV entry, whose value is a u32 count followed by the metas.
+const EXTRA_ACCOUNT_METAS_OFFSET = 8 + 4 + 4;
+const EXTRA_ACCOUNT_META_LEN = 1 + 32 + 1 + 1;
+
+const getTransferHookProgramId = (mintData: Buffer): PublicKey | null => {
+  let offset = MINT_EXTENSIONS_OFFSET;
+  while (offset + 4 <= mintData.length) {
+    const extensionType = mintData.readUInt16LE(offset);
+    const length = mintData.readUInt16LE(offset + 2);
+    if (extensionType === TRANSFER_HOOK_EXTENSION_TYPE) {
+      // The hook program follows the authority, zeroed when the hook is unset.
+      const programId = new PublicKey(
+        mintData.subarray(offset + 4 + 32, offset + 4 + 64),
+      );
+      return programId.equals(PublicKey.default) ? null : programId;
+    }
+    offset += 4 + length;
+  }
+
+  return null;
+};
+
+// Resolves the seeds packed in the address config of an extra account meta, against the accounts
+// of the hook's execute instruction.
+const resolveSeeds = async (
+  connection: Connection,
+  addressConfig: Buffer,
+  accounts: AccountMeta[],
+): Promise<Buffer[]> => {
+  const seeds: Buffer[] = [];
+  let offset = 0;
+  while (offset < addressConfig.length && addressConfig[offset] !== 0) {
+    switch (addressConfig[offset]) {
+      case 1: {
+        const length = addressConfig[offset + 1];
+        seeds.push(addressConfig.subarray(offset + 2, offset + 2 + length));
+        offset += 2 + length;
+        break;
+      }
+      case 3: {
+        seeds.push(accounts[addressConfig[offset + 1]].pubkey.toBuffer());
+        offset += 2;
+        break;
+      }
+      case 4: {
+        const [accountIndex, dataIndex, length] = addressConfig.subarray(
+          offset + 1,
+          offset + 4,
+        );
+        const account = await connection.getAccountInfo(
+          accounts[accountIndex].pubkey,
+        );
+        if (!account) throw new Error("Missing transfer hook seed account");
+        seeds.push(account.data.subarray(dataIndex, dataIndex + length));
+        offset += 4;
+        break;
+      }
+      default:
+        // Seeds from the instruction data depend on the transferred amount.
+        throw new Error("Unsupported transfer hook seed");
+    }
+  }
+
+  return seeds;
+};
+
+// Adds each account once, with the strongest privileges any of the legs requires, since the
+// program looks them up by address.
+const mergeAccountMetas = (
+  accountMetas: AccountMeta[],
+  newAccountMetas: AccountMeta[],
+) => {
+  for (const newAccountMeta of newAccountMetas) {
+    const accountMeta = accountMetas.find((item) =>
+      item.pubkey.equals(newAccountMeta.pubkey),
+    );
+    if (accountMeta) {
+      accountMeta.isSigner ||= newAccountMeta.isSigner;
+      accountMeta.isWritable ||= newAccountMeta.isWritable;
+    } else {
+      accountMetas.push({ ...newAccountMeta });
+    }
+  }
+};
+
+// Resolves the transfer hook accounts to pass as remaining accounts of claim_v2, covering every
+// leg of the claim: the partner, the project admin and, when passed to the claim, the keeper and
+// the rebate pool token accounts. Mirrors resolve_claim_v2_transfer_hook_accounts of the program
+// crate, returns no accounts for mints without a transfer hook.
+export const resolveClaimV2TransferHookAccounts = async ({
+  connection,
+  tokenProgramId,
+  referralAccount,
+  referralTokenAccount,
+  mint,
+  destinations,
+}: {
+  connection: Connection;
+  tokenProgramId: PublicKey;
+  referralAccount: PublicKey;
+  referralTokenAccount: PublicKey;
+  mint: PublicKey;
+  destinations: (PublicKey | null)[];
+}): Promise<AccountMeta[]> => {
+  if (!tokenProgramId.equals(TOKEN_2022_PROGRAM_ID)) return [];
+
+  const mintAccount = await connection.getAccountInfo(mint);
+  if (!mintAccount) throw new Error("Invalid mint");
+  const hookProgramId = getTransferHookProgramId(mintAccount.data);
+  if (!hookProgramId) return [];
+
+  const [extraAccountMetasAddress] = PublicKey.findProgramAddressSync(
+    [Buffer.from("extra-account-metas"), mint.toBuffer()],
+    hookProgramId,
+  );
+  const extraAccountMetasAccount = await connection.getAccountInfo(
+    extraAccountMetasAddress,
+  );
+  if (!extraAccountMetasAccount) return [];
+  const data = extraAccountMetasAccount.data;
+  const count = data.readUInt32LE(EXTRA_ACCOUNT_METAS_OFFSET - 4);
+
+  const accountMetas: AccountMeta[] = [];
+  for (const destination of destinations) {
+    if (!destination) continue;
+
+    // The accounts of the hook's execute instruction, which the extra accounts are appended to.
+    const accounts: AccountMeta[] = [
+      { pubkey: referralTokenAccount, isSigner: false, isWritable: false },
+      { pubkey: mint, isSigner: false, isWritable: false },
+      { pubkey: destination, isSigner: false, isWritable: false },
+      { pubkey: referralAccount, isSigner: false, isWritable: false },
+      { pubkey: extraAccountMetasAddress, isSigner: false, isWritable: false },
+    ];
+    for (let index = 0; index < count; index++) {
+      const offset =
+        EXTRA_ACCOUNT_METAS_OFFSET + index * EXTRA_ACCOUNT_META_LEN;
+      const discriminator = data[offset];
+      const addressConfig = data.subarray(offset + 1, offset + 33);
+      const isSigner = data[offset + 33] === 1;
+      const isWritable = data[offset + 34] === 1;
+
+      let pubkey: PublicKey;
+      if (discriminator === 0) {
+        pubkey = new PublicKey(addressConfig);
+      } else {
+        // Seeds derive from the hook program, or from the account at the index in the top bits.
+        const programId =
+          discriminator === 1
+            ? hookProgramId
+            : accounts[discriminator - 128].pubkey;
+        const seeds = await resolveSeeds(connection, addressConfig, accounts);
+        [pubkey] = PublicKey.findProgramAddressSync(seeds, programId);
+      }
+      accounts.push({ pubkey, isSigner, isWritable });
+    }
+
+    mergeAccountMetas(accountMetas, [
+      ...accounts.slice(5),
+      { pubkey: hookProgramId, isSigner: false, isWritable: false },
+      { pubkey: extraAccountMetasAddress, isSigner: false, isWritable: false },
+    ]);
+  }
+
+  return accountMetas;
+};
@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    mint_to, transfer_checked, Mint, MintTo, TokenAccount, TokenInterface, TransferChecked,
+};
+
+declare_id!("AAUNjHqSPCwDb8dNZXrK4WC5rXzWeHHkZFPBe3CU5PWR");
+
+pub const MINT_AUTHORITY_SEED: &[u8] = b"mint_authority";
+
+// Takes the whole source balance into the vault and mints a fixed amount of the output mint,
+// standing in for a real swap program in the claim_and_swap tests.
+#[program]
+pub mod mock_swap {
+    use super::*;
+
+    pub fn swap(ctx: Context<Swap>, amount_out: u64) -> Result<()> {
+        let accounts = &ctx.accounts;
+
+        transfer_checked(
+            CpiContext::new(
+                accounts.input_token_program.to_account_info(),
+                TransferChecked {
+                    from: accounts.source.to_account_info(),
+                    mint: accounts.input_mint.to_account_info(),
+                    to: accounts.vault.to_account_info(),
+                    authority: accounts.authority.to_account_info(),
+                },
+            ),
+            accounts.source.amount,
+            accounts.input_mint.decimals,
+        )?;
+
+        let signer_seeds: &[&[&[u8]]] = &[&[MINT_AUTHORITY_SEED, &[ctx.bumps.mint_authority]]];
+        mint_to(
+            CpiContext::new_with_signer(
+                accounts.output_token_program.to_account_info(),
+                MintTo {
+                    mint: accounts.output_mint.to_account_info(),
+                    to: accounts.destination.to_account_info(),
+                    authority: accounts.mint_authority.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            amount_out,
+        )?;
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct Swap<'info> {
+    authority: Signer<'info>,
+    #[account(mut, token::mint = input_mint)]
+    source: InterfaceAccount<'info, TokenAccount>,
+    #[account(mut, token::mint = input_mint)]
+    vault: InterfaceAccount<'info, TokenAccount>,
+    input_mint: InterfaceAccount<'info, Mint>,
+    #[account(mut, token::mint = output_mint)]
+    destination: InterfaceAccount<'info, TokenAccount>,
+    #[account(mut)]
+    output_mint: InterfaceAccount<'info, Mint>,
+    /// CHECK: PDA minting the output mint
+    #[account(seeds = [MINT_AUTHORITY_SEED], bump)]
+    mint_authority: UncheckedAccount<'info>,
+    input_token_program: Interface<'info, TokenInterface>,
+    output_token_program: Interface<'info, TokenInterface>,
+}
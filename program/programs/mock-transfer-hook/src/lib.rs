@@ -0,0 +1,103 @@
+use anchor_lang::{prelude::*, solana_program::program_error::ProgramError};
+use spl_tlv_account_resolution::{account::ExtraAccountMeta, state::ExtraAccountMetaList};
+use spl_transfer_hook_interface::instruction::{ExecuteInstruction, TransferHookInstruction};
+
+declare_id!("CUsR3U9FDQdfeANYSNSXuo1tQewJL5GkpRycLYCV3tZB");
+
+pub const EXTRA_ACCOUNT_METAS_SEED: &[u8] = b"extra-account-metas";
+pub const COUNTER_SEED: &[u8] = b"counter";
+
+// The single extra account of every transfer: a counter of the mint, so that a transfer only
+// goes through when the hook accounts were forwarded. It is listed by address, the pinned
+// spl-token-2022 derives seed-based extra accounts from the token program instead of the hook.
+pub fn extra_account_metas(mint: &Pubkey) -> Vec<ExtraAccountMeta> {
+    let (counter, _) = Pubkey::find_program_address(&[COUNTER_SEED, mint.as_ref()], &ID);
+    vec![ExtraAccountMeta::new_with_pubkey(&counter, false, true).unwrap()]
+}
+
+// Counts the transfers of a mint, standing in for a real transfer hook program in the claim_v2
+// tests.
+#[program]
+pub mod mock_transfer_hook {
+    use super::*;
+
+    pub fn initialize_extra_account_meta_list(
+        ctx: Context<InitializeExtraAccountMetaList>,
+    ) -> Result<()> {
+        ExtraAccountMetaList::init::<ExecuteInstruction>(
+            &mut ctx.accounts.extra_account_meta_list.try_borrow_mut_data()?,
+            &extra_account_metas(ctx.accounts.mint.key),
+        )?;
+
+        Ok(())
+    }
+
+    pub fn transfer_hook(ctx: Context<TransferHook>, _amount: u64) -> Result<()> {
+        ctx.accounts.counter.transfers += 1;
+
+        Ok(())
+    }
+
+    // Token-2022 invokes the hook with the interface discriminator instead of Anchor's.
+    pub fn fallback<'info>(
+        program_id: &Pubkey,
+        accounts: &'info [AccountInfo<'info>],
+        data: &[u8],
+    ) -> Result<()> {
+        match TransferHookInstruction::unpack(data)? {
+            TransferHookInstruction::Execute { amount } => {
+                __private::__global::transfer_hook(program_id, accounts, &amount.to_le_bytes())
+            }
+            _ => Err(ProgramError::InvalidInstructionData.into()),
+        }
+    }
+}
+
+#[account]
+#[derive(Default)]
+pub struct Counter {
+    pub transfers: u64,
+}
+
+#[derive(Accounts)]
+pub struct InitializeExtraAccountMetaList<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+    /// CHECK: Written as an extra account meta list
+    #[account(
+        init,
+        payer = payer,
+        space = ExtraAccountMetaList::size_of(1).unwrap(),
+        seeds = [EXTRA_ACCOUNT_METAS_SEED, mint.key().as_ref()],
+        bump
+    )]
+    extra_account_meta_list: UncheckedAccount<'info>,
+    /// CHECK: Only used as a seed
+    mint: UncheckedAccount<'info>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + std::mem::size_of::<Counter>(),
+        seeds = [COUNTER_SEED, mint.key().as_ref()],
+        bump
+    )]
+    counter: Account<'info, Counter>,
+    system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct TransferHook<'info> {
+    /// CHECK: Checked by the token program
+    source: UncheckedAccount<'info>,
+    /// CHECK: Checked by the token program
+    mint: UncheckedAccount<'info>,
+    /// CHECK: Checked by the token program
+    destination: UncheckedAccount<'info>,
+    /// CHECK: Checked by the token program
+    owner: UncheckedAccount<'info>,
+    /// CHECK: Checked by the token program
+    #[account(seeds = [EXTRA_ACCOUNT_METAS_SEED, mint.key().as_ref()], bump)]
+    extra_account_meta_list: UncheckedAccount<'info>,
+    #[account(mut, seeds = [COUNTER_SEED, mint.key().as_ref()], bump)]
+    counter: Account<'info, Counter>,
+}
@@ -0,0 +1,366 @@
+//! Off-chain helpers for integrators building referral instructions.
+
+use std::future::Future;
+
+use anchor_lang::{
+    prelude::*,
+    solana_program::instruction::{AccountMeta, Instruction},
+    InstructionData,
+};
+use anchor_spl::token_2022::spl_token_2022::{
+    self,
+    offchain::{resolve_extra_transfer_account_metas, AccountDataResult, AccountFetchError},
+};
+
+/// Resolves the transfer hook accounts to pass as remaining accounts of `claim_v2` and
+/// `claim_amount_v2`, covering every leg of the claim: the partner, the project admin and, when
+/// passed to the claim, the keeper and the rebate pool token accounts. Returns no accounts for
+/// mints without a transfer hook. See [`claim_v2_instruction`] for where they go.
+///
+/// `fetch_account_data_fn` returns the data of an account, e.g. through an RPC client:
+///
+/// ```rust,ignore
+/// let remaining_accounts = resolve_claim_v2_transfer_hook_accounts(
+///     |address| rpc_client.get_account(&address).map_ok(|account| Some(account.data)),
+///     &token_program,
+///     &referral_account,
+///     &referral_token_account,
+///     &mint,
+///     &partner_token_account,
+///     &project_admin_token_account,
+///     keeper_token_account.as_ref(),
+///     rebate_pool_token_account.as_ref(),
+/// )
+/// .await?;
+/// ```
+#[allow(clippy::too_many_arguments)]
+pub async fn resolve_claim_v2_transfer_hook_accounts<F, Fut>(
+    fetch_account_data_fn: F,
+    token_program: &Pubkey,
+    referral_account: &Pubkey,
+    referral_token_account: &Pubkey,
+    mint: &Pubkey,
+    partner_token_account: &Pubkey,
+    project_admin_token_account: &Pubkey,
+    keeper_token_account: Option<&Pubkey>,
+    rebate_pool_token_account: Option<&Pubkey>,
+) -> std::result::Result<Vec<AccountMeta>, AccountFetchError>
+where
+    F: Fn(Pubkey) -> Fut,
+    Fut: Future<Output = AccountDataResult>,
+{
+    let mut extra_account_metas: Vec<AccountMeta> = vec![];
+
+    let destinations = [
+        Some(partner_token_account),
+        Some(project_admin_token_account),
+        keeper_token_account,
+        rebate_pool_token_account,
+    ];
+    for destination in destinations.into_iter().flatten() {
+        // The amount does not take part in the resolution.
+        let mut transfer_instruction = spl_token_2022::instruction::transfer_checked(
+            token_program,
+            referral_token_account,
+            mint,
+            destination,
+            referral_account,
+            &[],
+            0,
+            0,
+        )?;
+        let transfer_accounts_len = transfer_instruction.accounts.len();
+        resolve_extra_transfer_account_metas(
+            &mut transfer_instruction,
+            &fetch_account_data_fn,
+            mint,
+        )
+        .await?;
+
+        merge_account_metas(
+            &mut extra_account_metas,
+            transfer_instruction.accounts.drain(transfer_accounts_len..),
+        );
+    }
+
+    Ok(extra_account_metas)
+}
+
+/// Builds a `claim_v2` instruction. Its accounts are, in order:
+///
+/// 1. the accounts of [`crate::accounts::ClaimV2`] up to `associated_token_program`,
+/// 2. `mint_policy`, `claim_thresholds`, `keeper_token_account` and `rebate_pool_token_account`,
+///    each passed as the program ID when `None`,
+/// 3. `event_authority` and `program`,
+/// 4. the transfer hook accounts from [`resolve_claim_v2_transfer_hook_accounts`].
+///
+/// Leaving out an optional account instead of passing the program ID would shift the accounts
+/// after it into its slot.
+pub fn claim_v2_instruction(
+    accounts: crate::accounts::ClaimV2,
+    transfer_hook_accounts: Vec<AccountMeta>,
+) -> Instruction {
+    let mut account_metas = accounts.to_account_metas(None);
+    account_metas.extend(transfer_hook_accounts);
+
+    Instruction {
+        program_id: crate::ID,
+        accounts: account_metas,
+        data: crate::instruction::ClaimV2 {}.data(),
+    }
+}
+
+/// The accounts `claim_many_v2` takes for each mint it claims.
+pub struct ClaimManyV2Mint {
+    pub mint: Pubkey,
+    pub referral_token_account: Pubkey,
+    pub partner_token_account: Pubkey,
+    pub project_admin_token_account: Pubkey,
+    pub token_program: Pubkey,
+}
+
+/// Builds a `claim_many_v2` instruction. Its accounts are, in order:
+///
+/// 1. the accounts of [`crate::accounts::ClaimManyV2`] up to `associated_token_program`,
+/// 2. the optional accounts, each passed as the program ID when `None`,
+/// 3. `event_authority` and `program`,
+/// 4. the accounts of each mint, in the order of [`ClaimManyV2Mint`].
+pub fn claim_many_v2_instruction(
+    accounts: crate::accounts::ClaimManyV2,
+    mints: &[ClaimManyV2Mint],
+) -> Instruction {
+    let mut account_metas = accounts.to_account_metas(None);
+    for mint in mints {
+        account_metas.extend([
+            AccountMeta::new_readonly(mint.mint, false),
+            AccountMeta::new(mint.referral_token_account, false),
+            AccountMeta::new(mint.partner_token_account, false),
+            AccountMeta::new(mint.project_admin_token_account, false),
+            AccountMeta::new_readonly(mint.token_program, false),
+        ]);
+    }
+
+    Instruction {
+        program_id: crate::ID,
+        accounts: account_metas,
+        data: crate::instruction::ClaimManyV2 {}.data(),
+    }
+}
+
+// The on-chain side looks the accounts up by address, so each account only needs to be passed once
+// with the strongest privileges any of the legs requires.
+fn merge_account_metas(
+    account_metas: &mut Vec<AccountMeta>,
+    new_account_metas: impl Iterator<Item = AccountMeta>,
+) {
+    for new_account_meta in new_account_metas {
+        match account_metas
+            .iter_mut()
+            .find(|account_meta| account_meta.pubkey == new_account_meta.pubkey)
+        {
+            Some(account_meta) => {
+                account_meta.is_signer |= new_account_meta.is_signer;
+                account_meta.is_writable |= new_account_meta.is_writable;
+            }
+            None => account_metas.push(new_account_meta),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::HashMap,
+        future::{ready, Future},
+        pin::pin,
+        task::{Context, Poll, Waker},
+    };
+
+    use anchor_spl::token_2022::spl_token_2022::{
+        extension::{transfer_hook::TransferHook, ExtensionType, StateWithExtensionsMut},
+        state::Mint,
+    };
+    use spl_tlv_account_resolution::{
+        account::ExtraAccountMeta, seeds::Seed, state::ExtraAccountMetaList,
+    };
+    use spl_transfer_hook_interface::{
+        get_extra_account_metas_address, instruction::ExecuteInstruction,
+    };
+
+    use super::*;
+
+    // The fetch function below resolves immediately, so a single poll drives the helper.
+    fn block_on<F: Future>(future: F) -> F::Output {
+        match pin!(future).poll(&mut Context::from_waker(Waker::noop())) {
+            Poll::Ready(output) => output,
+            Poll::Pending => panic!("the accounts are fetched synchronously"),
+        }
+    }
+
+    fn mint_data(transfer_hook_program_id: Option<Pubkey>) -> Vec<u8> {
+        let mut data =
+            vec![
+                0;
+                ExtensionType::try_calculate_account_len::<Mint>(&[ExtensionType::TransferHook])
+                    .unwrap()
+            ];
+        let mut mint = StateWithExtensionsMut::<Mint>::unpack_uninitialized(&mut data).unwrap();
+        mint.init_extension::<TransferHook>(true)
+            .unwrap()
+            .program_id = transfer_hook_program_id.try_into().unwrap();
+        mint.base.is_initialized = true;
+        mint.pack_base();
+        mint.init_account_type().unwrap();
+        data
+    }
+
+    fn extra_account_meta_list(extra_account_metas: &[ExtraAccountMeta]) -> Vec<u8> {
+        let mut data = vec![0; ExtraAccountMetaList::size_of(extra_account_metas.len()).unwrap()];
+        ExtraAccountMetaList::init::<ExecuteInstruction>(&mut data, extra_account_metas).unwrap();
+        data
+    }
+
+    fn resolve(
+        accounts: HashMap<Pubkey, Vec<u8>>,
+        mint: &Pubkey,
+        keeper_token_account: Option<&Pubkey>,
+        rebate_pool_token_account: Option<&Pubkey>,
+    ) -> Vec<AccountMeta> {
+        block_on(resolve_claim_v2_transfer_hook_accounts(
+            |address| ready(Ok(accounts.get(&address).cloned())),
+            &spl_token_2022::ID,
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            mint,
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            keeper_token_account,
+            rebate_pool_token_account,
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn resolves_hook_accounts_once_for_both_legs() {
+        let mint = Pubkey::new_unique();
+        let hook_program_id = mock_transfer_hook::ID;
+        let extra_account_metas_address = get_extra_account_metas_address(&mint, &hook_program_id);
+
+        let account_metas = resolve(
+            HashMap::from([
+                (mint, mint_data(Some(hook_program_id))),
+                (
+                    extra_account_metas_address,
+                    extra_account_meta_list(&mock_transfer_hook::extra_account_metas(&mint)),
+                ),
+            ]),
+            &mint,
+            None,
+            None,
+        );
+
+        let (counter, _) = Pubkey::find_program_address(
+            &[mock_transfer_hook::COUNTER_SEED, mint.as_ref()],
+            &hook_program_id,
+        );
+        assert_eq!(
+            account_metas,
+            vec![
+                AccountMeta::new(counter, false),
+                AccountMeta::new_readonly(hook_program_id, false),
+                AccountMeta::new_readonly(extra_account_metas_address, false),
+            ]
+        );
+    }
+
+    #[test]
+    fn resolves_hook_accounts_of_every_leg() {
+        let mint = Pubkey::new_unique();
+        let hook_program_id = mock_transfer_hook::ID;
+        let extra_account_metas_address = get_extra_account_metas_address(&mint, &hook_program_id);
+        // An account derived from the destination, which differs between the legs.
+        let extra_account_metas =
+            [
+                ExtraAccountMeta::new_with_seeds(&[Seed::AccountKey { index: 2 }], false, true)
+                    .unwrap(),
+            ];
+        let accounts = HashMap::from([
+            (mint, mint_data(Some(hook_program_id))),
+            (
+                extra_account_metas_address,
+                extra_account_meta_list(&extra_account_metas),
+            ),
+        ]);
+
+        // The hook program and the extra account metas account, and one account per leg.
+        assert_eq!(resolve(accounts.clone(), &mint, None, None).len(), 2 + 2);
+        assert_eq!(
+            resolve(
+                accounts,
+                &mint,
+                Some(&Pubkey::new_unique()),
+                Some(&Pubkey::new_unique()),
+            )
+            .len(),
+            2 + 4
+        );
+    }
+
+    #[test]
+    fn resolves_no_accounts_without_a_hook_program() {
+        let mint = Pubkey::new_unique();
+
+        let account_metas = resolve(HashMap::from([(mint, mint_data(None))]), &mint, None, None);
+
+        assert!(account_metas.is_empty());
+    }
+
+    #[test]
+    fn claim_v2_instruction_keeps_the_optional_account_slots() {
+        let keeper_token_account = Pubkey::new_unique();
+        let event_authority = Pubkey::new_unique();
+        let transfer_hook_account = AccountMeta::new(Pubkey::new_unique(), false);
+
+        let instruction = claim_v2_instruction(
+            crate::accounts::ClaimV2 {
+                payer: Pubkey::new_unique(),
+                project: Pubkey::new_unique(),
+                admin: Pubkey::new_unique(),
+                project_admin_token_account: Pubkey::new_unique(),
+                referral_account: Pubkey::new_unique(),
+                referral_token_account: Pubkey::new_unique(),
+                partner: Pubkey::new_unique(),
+                partner_token_account: Pubkey::new_unique(),
+                mint: Pubkey::new_unique(),
+                system_program: Pubkey::new_unique(),
+                token_program: Pubkey::new_unique(),
+                associated_token_program: Pubkey::new_unique(),
+                mint_policy: None,
+                claim_thresholds: None,
+                keeper_token_account: Some(keeper_token_account),
+                rebate_pool_token_account: None,
+                event_authority,
+                program: crate::ID,
+            },
+            vec![transfer_hook_account.clone()],
+        );
+
+        let account_keys: Vec<Pubkey> = instruction.accounts[12..]
+            .iter()
+            .map(|account_meta| account_meta.pubkey)
+            .collect();
+        assert_eq!(
+            account_keys,
+            vec![
+                crate::ID,
+                crate::ID,
+                keeper_token_account,
+                crate::ID,
+                event_authority,
+                crate::ID,
+                transfer_hook_account.pubkey,
+            ]
+        );
+        assert_eq!(instruction.accounts.last(), Some(&transfer_hook_account));
+    }
+}
@@ -1,5 +1,50 @@
 use anchor_lang::prelude::*;
 
+use crate::{
+    AdminTokenAccountPolicy, AttributionPolicy, MintExtensionPolicy, MintPolicyMode,
+    PendingNoticePeriodChange, PendingShareChange, ProjectAction,
+};
+
+// Emits to the program logs and through a self-CPI to the event authority. RPC nodes truncate
+// logs on busy transactions but not inner instructions, the log copy stays while indexers move
+// over. `$ctx` is the instruction `Context` or an `EventCpi`.
+macro_rules! emit_event {
+    ($ctx:expr, $event:expr) => {{
+        let ctx = $ctx;
+        let event = $event;
+        anchor_lang::prelude::emit!(event);
+        anchor_lang::prelude::emit_cpi!(event);
+    }};
+}
+
+// The parts of a `Context` that `emit_cpi!` reads, for helpers shared with
+// `execute_project_proposal` that have no `Context` of their own.
+pub(crate) struct EventCpi<'info> {
+    pub accounts: EventCpiAccounts<'info>,
+    pub bumps: EventCpiBumps,
+}
+
+pub(crate) struct EventCpiAccounts<'info> {
+    pub event_authority: AccountInfo<'info>,
+}
+
+pub(crate) struct EventCpiBumps {
+    pub event_authority: u8,
+}
+
+impl<'info> EventCpi<'info> {
+    pub(crate) fn new(event_authority: &AccountInfo<'info>, event_authority_bump: u8) -> Self {
+        Self {
+            accounts: EventCpiAccounts {
+                event_authority: event_authority.clone(),
+            },
+            bumps: EventCpiBumps {
+                event_authority: event_authority_bump,
+            },
+        }
+    }
+}
+
 #[event]
 pub struct InitializeProjectEvent {
     pub project: Pubkey,
@@ -11,8 +56,14 @@ pub struct InitializeProjectEvent {
 #[event]
 pub struct UpdateProjectEvent {
     pub project: Pubkey,
+    pub previous_name: String,
     pub name: String,
+    pub previous_default_share_bps: u16,
     pub default_share_bps: u16,
+    pub previous_share_decrease_notice_period: i64,
+    pub share_decrease_notice_period: i64,
+    pub pending_notice_period: Option<PendingNoticePeriodChange>,
+    pub pending_default_share: Option<PendingShareChange>,
 }
 
 #[event]
@@ -27,6 +78,31 @@ pub struct InitializeReferralAccountEvent {
 #[event]
 pub struct UpdateReferralAccountEvent {
     pub referral_account: Pubkey,
+    pub previous_share_bps: u16,
+    pub share_bps: u16,
+}
+
+#[event]
+pub struct UpdateReferralAccountMetadataEvent {
+    pub project: Pubkey,
+    pub referral_account: Pubkey,
+    pub name: Option<String>,
+    pub previous_display_name: Option<String>,
+    pub display_name: Option<String>,
+}
+
+#[event]
+pub struct QueueReferralAccountShareDecreaseEvent {
+    pub referral_account: Pubkey,
+    pub share_bps: u16,
+    pub pending_share_bps: u16,
+    pub effective_at: i64,
+}
+
+#[event]
+pub struct UpdateReferralAccountShareModeEvent {
+    pub referral_account: Pubkey,
+    pub inherit_default: bool,
     pub share_bps: u16,
 }
 
@@ -48,4 +124,362 @@ pub struct ClaimEvent {
     pub mint: Pubkey,
     pub referral_amount: u64,
     pub project_amount: u64,
+    pub share_bps: u16,
+    pub inherit_default: bool,
+    // Token-2022 transfer fees withheld from each leg, the net amounts are what was received. The
+    // split is on the gross amount and each leg pays its own fee, so the net amounts only keep the
+    // share_bps ratio up to fee rounding, and not once a leg's fee hits the mint's maximum fee.
+    pub referral_transfer_fee: u64,
+    pub referral_net_amount: u64,
+    pub project_transfer_fee: u64,
+    pub project_net_amount: u64,
+    // Paid to the claim payer out of the project amount, claim_v2 only.
+    pub keeper_token_account: Option<Pubkey>,
+    pub keeper_amount: u64,
+    pub keeper_transfer_fee: u64,
+    pub keeper_net_amount: u64,
+    // Set aside for traders out of the referral amount, claim_v2 only.
+    pub rebate_token_account: Option<Pubkey>,
+    pub rebate_amount: u64,
+}
+
+#[event]
+pub struct ClaimNativeEvent {
+    pub project: Pubkey,
+    pub referral_account: Pubkey,
+    pub referral_token_account: Pubkey,
+    pub mint: Pubkey,
+    // Unwrapped legs are paid to the wallet and have no token account.
+    pub partner: Pubkey,
+    pub partner_token_account: Option<Pubkey>,
+    pub admin: Pubkey,
+    pub project_admin_token_account: Option<Pubkey>,
+    pub referral_amount: u64,
+    pub project_amount: u64,
+    pub share_bps: u16,
+    pub inherit_default: bool,
+    // Whether each leg was paid as native SOL, which may differ from the request.
+    pub unwrap_referral: bool,
+    pub unwrap_project: bool,
+    pub unwrapped_amount: u64,
+}
+
+#[event]
+pub struct ClaimAndSwapEvent {
+    pub referral_account: Pubkey,
+    pub mint: Pubkey,
+    pub input_amount: u64,
+    pub settlement_mint: Pubkey,
+    pub partner_settlement_token_account: Pubkey,
+    pub output_amount: u64,
+}
+
+#[event]
+pub struct HarvestReferralTokenAccountFeesEvent {
+    pub referral_account: Pubkey,
+    pub referral_token_account: Pubkey,
+    pub mint: Pubkey,
+    pub withheld_amount: u64,
+}
+
+#[event]
+pub struct SetProjectSwapProgramEvent {
+    pub project: Pubkey,
+    pub swap_program: Option<Pubkey>,
+}
+
+#[event]
+pub struct SetProjectMintExtensionPolicyEvent {
+    pub project: Pubkey,
+    pub policy: MintExtensionPolicy,
+}
+
+#[event]
+pub struct InitializeProjectMintPolicyEvent {
+    pub project: Pubkey,
+    pub mint_policy: Pubkey,
+}
+
+#[event]
+pub struct SetProjectMintPolicyModeEvent {
+    pub project: Pubkey,
+    pub mode: MintPolicyMode,
+}
+
+#[event]
+pub struct AddProjectPolicyMintEvent {
+    pub project: Pubkey,
+    pub mint: Pubkey,
+}
+
+#[event]
+pub struct RemoveProjectPolicyMintEvent {
+    pub project: Pubkey,
+    pub mint: Pubkey,
+}
+
+#[event]
+pub struct InitializeProjectClaimThresholdsEvent {
+    pub project: Pubkey,
+    pub claim_thresholds: Pubkey,
+}
+
+#[event]
+pub struct SetProjectMinClaimAmountEvent {
+    pub project: Pubkey,
+    // None for the default minimum.
+    pub mint: Option<Pubkey>,
+    // None when the mint falls back to the default minimum.
+    pub min_amount: Option<u64>,
+}
+
+#[event]
+pub struct SetProjectKeeperFeeEvent {
+    pub project: Pubkey,
+    pub keeper_fee_bps: u16,
+}
+
+#[event]
+pub struct SetProjectMaxKeeperFeeEvent {
+    pub project: Pubkey,
+    pub mint: Pubkey,
+    pub max_keeper_fee: u64,
+}
+
+#[event]
+pub struct SetProjectSponsorshipEvent {
+    pub project: Pubkey,
+    pub enabled: bool,
+    pub max_sponsored_lamports_per_partner: u64,
+}
+
+#[event]
+pub struct FundProjectAuthorityEvent {
+    pub project: Pubkey,
+    pub project_authority: Pubkey,
+    pub funder: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct SponsorRentEvent {
+    pub project: Pubkey,
+    pub partner: Pubkey,
+    pub payer: Pubkey,
+    pub lamports: u64,
+    pub sponsored_lamports: u64,
+}
+
+#[event]
+pub struct SetProjectAdminTokenAccountPolicyEvent {
+    pub project: Pubkey,
+    pub policy: AdminTokenAccountPolicy,
+}
+
+#[event]
+pub struct CreateAdminTokenAccountEvent {
+    pub project: Pubkey,
+    pub admin: Pubkey,
+    pub project_admin_token_account: Pubkey,
+    pub mint: Pubkey,
+    // Rent paid by the project authority, zero when the account already existed.
+    pub lamports: u64,
+    pub epoch: u64,
+    pub epoch_spent_lamports: u64,
+}
+
+#[event]
+pub struct TransferProjectEvent {
+    pub project: Pubkey,
+    pub previous_admin: Pubkey,
+    pub new_admin: Pubkey,
+}
+
+#[event]
+pub struct TransferReferralAccountEvent {
+    pub project: Pubkey,
+    pub referral_account: Pubkey,
+    pub previous_partner: Pubkey,
+    pub new_partner: Pubkey,
+}
+
+#[event]
+pub struct WithdrawFromProjectEvent {
+    pub project: Pubkey,
+    pub project_authority: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct CloseReferralTokenAccountEvent {
+    pub project: Pubkey,
+    pub referral_account: Pubkey,
+    pub referral_token_account: Pubkey,
+    pub mint: Pubkey,
+    pub partner: Pubkey,
+    // Rent returned to the partner.
+    pub lamports: u64,
+}
+
+#[event]
+pub struct MigrateProjectEvent {
+    pub project: Pubkey,
+    pub previous_version: u8,
+    pub version: u8,
+}
+
+#[event]
+pub struct MigrateReferralAccountEvent {
+    pub project: Pubkey,
+    pub referral_account: Pubkey,
+    pub previous_version: u8,
+    pub version: u8,
+}
+
+#[event]
+pub struct InitializeProjectMetadataEvent {
+    pub project: Pubkey,
+    pub metadata: Pubkey,
+}
+
+#[event]
+pub struct SetProjectMetadataEvent {
+    pub project: Pubkey,
+    pub metadata: Pubkey,
+    pub uri: String,
+    pub content_hash: [u8; 32],
+}
+
+#[event]
+pub struct SetReferralAccountMetadataEvent {
+    pub project: Pubkey,
+    pub referral_account: Pubkey,
+    pub metadata: Pubkey,
+    pub uri: String,
+    pub content_hash: [u8; 32],
+}
+
+#[event]
+pub struct CreateReferralAliasEvent {
+    pub project: Pubkey,
+    pub referral_account: Pubkey,
+    pub alias_account: Pubkey,
+    pub alias: String,
+}
+
+#[event]
+pub struct DeleteReferralAliasEvent {
+    pub project: Pubkey,
+    pub referral_account: Pubkey,
+    pub alias_account: Pubkey,
+    pub alias: String,
+}
+
+#[event]
+pub struct SetProjectAttributionPolicyEvent {
+    pub project: Pubkey,
+    pub policy: AttributionPolicy,
+}
+
+#[event]
+pub struct AttributeUserEvent {
+    pub project: Pubkey,
+    pub user: Pubkey,
+    pub user_attribution: Pubkey,
+    pub referral_account: Pubkey,
+    pub previous_referral_account: Option<Pubkey>,
+    pub attributed_at: i64,
+}
+
+#[event]
+pub struct SetReferralAccountRebateEvent {
+    pub referral_account: Pubkey,
+    pub previous_rebate_bps: u16,
+    pub rebate_bps: u16,
+}
+
+#[event]
+pub struct QueueReferralAccountRebateIncreaseEvent {
+    pub referral_account: Pubkey,
+    pub rebate_bps: u16,
+    pub pending_rebate_bps: u16,
+    pub effective_at: i64,
+}
+
+#[event]
+pub struct InitializeRebatePoolEvent {
+    pub project: Pubkey,
+    pub referral_account: Pubkey,
+    pub rebate_pool: Pubkey,
+    pub rebate_pool_token_account: Pubkey,
+    pub mint: Pubkey,
+}
+
+#[event]
+pub struct PublishRebateRootEvent {
+    pub project: Pubkey,
+    pub rebate_pool: Pubkey,
+    pub round: u64,
+    pub merkle_root: [u8; 32],
+}
+
+#[event]
+pub struct ClaimRebateEvent {
+    pub project: Pubkey,
+    pub referral_account: Pubkey,
+    pub rebate_pool: Pubkey,
+    pub trader: Pubkey,
+    pub trader_token_account: Pubkey,
+    pub mint: Pubkey,
+    pub round: u64,
+    pub index: u64,
+    pub amount: u64,
+}
+
+#[event]
+pub struct FeeCollectedEvent {
+    pub project: Pubkey,
+    pub referral_account: Pubkey,
+    pub referral_token_account: Pubkey,
+    pub mint: Pubkey,
+    pub source_token_account: Pubkey,
+    pub fee_stats: Pubkey,
+    pub source_tag: String,
+    pub gross_volume: u64,
+    pub fee_amount: u64,
+    // Token-2022 transfer fee withheld from the fee, the net amount is what the referral received.
+    pub transfer_fee: u64,
+    pub net_amount: u64,
+}
+
+#[event]
+pub struct SetProjectMultisigEvent {
+    pub project: Pubkey,
+    pub signers: Vec<Pubkey>,
+    pub threshold: u8,
+}
+
+#[event]
+pub struct CreateProjectProposalEvent {
+    pub project: Pubkey,
+    pub proposal: Pubkey,
+    pub proposer: Pubkey,
+    pub id: u64,
+    pub action: ProjectAction,
+    pub expires_at: i64,
+}
+
+#[event]
+pub struct ApproveProjectProposalEvent {
+    pub project: Pubkey,
+    pub proposal: Pubkey,
+    pub signer: Pubkey,
+}
+
+#[event]
+pub struct ExecuteProjectProposalEvent {
+    pub project: Pubkey,
+    pub proposal: Pubkey,
+    pub executor: Pubkey,
 }
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::{AddProjectPolicyMintEvent, EventCpi},
+    ProgramErrorCode, Project, ProjectMintPolicy, MAX_MINT_POLICY_MINTS,
+};
+
+use super::UpdateProjectMintPolicy;
+
+pub fn add_project_policy_mint(
+    ctx: Context<UpdateProjectMintPolicy>,
+    params: ProjectPolicyMintParams,
+) -> Result<()> {
+    let event_cpi = EventCpi::new(&ctx.accounts.event_authority, ctx.bumps.event_authority);
+
+    apply_add_project_policy_mint(
+        &ctx.accounts.project,
+        &mut ctx.accounts.mint_policy,
+        params,
+        &event_cpi,
+    )
+}
+
+pub(crate) fn apply_add_project_policy_mint(
+    project: &Account<Project>,
+    mint_policy: &mut Account<ProjectMintPolicy>,
+    params: ProjectPolicyMintParams,
+    event_cpi: &EventCpi,
+) -> Result<()> {
+    require!(
+        !mint_policy.mints.contains(&params.mint),
+        ProgramErrorCode::MintAlreadyInPolicy
+    );
+    require!(
+        mint_policy.mints.len() < MAX_MINT_POLICY_MINTS,
+        ProgramErrorCode::MintPolicyFull
+    );
+
+    mint_policy.mints.push(params.mint);
+
+    emit_event!(
+        event_cpi,
+        AddProjectPolicyMintEvent {
+            project: project.key(),
+            mint: params.mint,
+        }
+    );
+
+    Ok(())
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Debug, Clone, Default)]
+pub struct ProjectPolicyMintParams {
+    pub mint: Pubkey,
+}
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::{ApproveProjectProposalEvent, EventCpi},
+    ProgramErrorCode, Project, ProjectProposal,
+};
+
+pub fn approve_project_proposal(ctx: Context<ApproveProjectProposal>) -> Result<()> {
+    let event_cpi = EventCpi::new(&ctx.accounts.event_authority, ctx.bumps.event_authority);
+
+    let signer = ctx.accounts.signer.key();
+    let project = &ctx.accounts.project;
+    let proposal = &mut ctx.accounts.proposal;
+
+    require!(
+        !proposal.executed,
+        ProgramErrorCode::ProposalAlreadyExecuted
+    );
+    require!(
+        proposal.expires_at > Clock::get()?.unix_timestamp,
+        ProgramErrorCode::ProposalExpired
+    );
+    require!(
+        !proposal.approvals.contains(&signer),
+        ProgramErrorCode::ProposalAlreadyApproved
+    );
+
+    // Approvals of signers rotated out since no longer count, dropping them also keeps the list
+    // within the space of the proposal.
+    proposal
+        .approvals
+        .retain(|approval| project.is_multisig_signer(approval));
+    proposal.approvals.push(signer);
+
+    emit_event!(
+        &event_cpi,
+        ApproveProjectProposalEvent {
+            project: project.key(),
+            proposal: proposal.key(),
+            signer,
+        }
+    );
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ApproveProjectProposal<'info> {
+    signer: Signer<'info>,
+    #[account(
+        constraint = project.is_multisig_signer(&signer.key()) @ ProgramErrorCode::NotMultisigSigner,
+    )]
+    project: Account<'info, Project>,
+    #[account(
+        mut,
+        has_one = project,
+    )]
+    proposal: Account<'info, ProjectProposal>,
+}
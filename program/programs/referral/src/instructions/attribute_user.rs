@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::AttributeUserEvent, ProgramErrorCode, Project, ReferralAccount, UserAttribution,
+    USER_ATTRIBUTION_SEED,
+};
+
+// Records the referral account the user came through. An active attribution only moves to another
+// referral account when the project allows overrides, re-attributing to the same one is a no-op.
+pub fn attribute_user(ctx: Context<AttributeUser>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let policy = ctx.accounts.project.attribution_policy;
+    let referral_account = ctx.accounts.referral_account.key();
+    let user_attribution = &mut ctx.accounts.user_attribution;
+
+    // Freshly created attributions have no project yet.
+    let previous_referral_account = (user_attribution.project != Pubkey::default())
+        .then_some(user_attribution.referral_account);
+
+    if previous_referral_account.is_some() && user_attribution.is_active(&policy, now) {
+        if previous_referral_account == Some(referral_account) {
+            return Ok(());
+        }
+
+        require!(
+            policy.allow_override,
+            ProgramErrorCode::UserAlreadyAttributed
+        );
+    }
+
+    user_attribution.set_inner(UserAttribution {
+        project: ctx.accounts.project.key(),
+        user: ctx.accounts.user.key(),
+        referral_account,
+        attributed_at: now,
+    });
+
+    emit_event!(
+        &ctx,
+        AttributeUserEvent {
+            project: ctx.accounts.project.key(),
+            user: ctx.accounts.user.key(),
+            user_attribution: ctx.accounts.user_attribution.key(),
+            referral_account,
+            previous_referral_account,
+            attributed_at: now,
+        }
+    );
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AttributeUser<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+    user: Signer<'info>,
+    project: Account<'info, Project>,
+    #[account(has_one = project)]
+    referral_account: Account<'info, ReferralAccount>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        seeds = [USER_ATTRIBUTION_SEED, project.key().as_ref(), user.key().as_ref()],
+        bump,
+        space = UserAttribution::LEN
+    )]
+    user_attribution: Account<'info, UserAttribution>,
+    system_program: Program<'info, System>,
+}
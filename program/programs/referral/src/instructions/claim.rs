@@ -1,5 +1,11 @@
 use crate::{
-    events::ClaimEvent, ProgramErrorCode, Project, ReferralAccount, PROJECT_SEED, REFERRAL_ATA_SEED,
+    events::ClaimEvent,
+    utils::{
+        calculate_referral_amount, calculate_transfer_fee, check_min_claim_amount,
+        check_mint_policy,
+    },
+    ProgramErrorCode, Project, ProjectClaimThresholds, ProjectMintPolicy, ReferralAccount,
+    CLAIM_THRESHOLDS_SEED, MINT_POLICY_SEED, PROJECT_SEED, REFERRAL_ATA_SEED,
 };
 use anchor_lang::prelude::*;
 use anchor_spl::{
@@ -7,21 +13,38 @@ use anchor_spl::{
     token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked},
 };
 
-const DENOMINATOR: u128 = 10_000;
-
 pub fn claim(ctx: Context<Claim>) -> Result<()> {
+    let token_account_balance = ctx.accounts.referral_token_account.amount;
+    process_claim(ctx, token_account_balance)
+}
+
+pub(crate) fn process_claim(ctx: Context<Claim>, amount: u64) -> Result<()> {
     let accounts = &ctx.accounts;
 
-    let token_account_balance = accounts.referral_token_account.amount;
-    let referral_amount: u64 = u128::from(token_account_balance)
-        .checked_mul(u128::from(accounts.referral_account.share_bps))
-        .ok_or(ProgramErrorCode::InvalidCalculation)?
-        .checked_div(DENOMINATOR)
-        .ok_or(ProgramErrorCode::InvalidCalculation)?
-        .try_into()
-        .unwrap();
+    require!(
+        amount <= accounts.referral_token_account.amount,
+        ProgramErrorCode::ClaimAmountExceedsBalance
+    );
+
+    check_mint_policy(
+        &accounts.project,
+        accounts.mint_policy.as_deref(),
+        &accounts.mint.key(),
+    )?;
+    check_min_claim_amount(
+        &accounts.project,
+        accounts.claim_thresholds.as_deref(),
+        &accounts.mint.key(),
+        amount,
+    )?;
 
-    let project_amount = token_account_balance.checked_sub(referral_amount).unwrap();
+    let share_bps = accounts
+        .referral_account
+        .share_bps_at(&accounts.project, Clock::get()?.unix_timestamp);
+
+    let referral_amount = calculate_referral_amount(amount, share_bps)?;
+
+    let project_amount = amount.checked_sub(referral_amount).unwrap();
     let mint = &ctx.accounts.mint;
 
     let bump = ctx.bumps.project;
@@ -61,20 +84,41 @@ pub fn claim(ctx: Context<Claim>) -> Result<()> {
         )?;
     }
 
-    emit!(ClaimEvent {
-        project: ctx.accounts.project.key(),
-        project_admin_token_account: ctx.accounts.project_admin_token_account.key(),
-        referral_account: ctx.accounts.referral_account.key(),
-        referral_token_account: ctx.accounts.referral_token_account.key(),
-        partner_token_account: ctx.accounts.partner_token_account.key(),
-        mint: ctx.accounts.mint.key(),
-        referral_amount: referral_amount,
-        project_amount: project_amount
-    });
+    let referral_transfer_fee =
+        calculate_transfer_fee(&ctx.accounts.mint.to_account_info(), referral_amount)?;
+    let project_transfer_fee =
+        calculate_transfer_fee(&ctx.accounts.mint.to_account_info(), project_amount)?;
+
+    emit_event!(
+        &ctx,
+        ClaimEvent {
+            project: ctx.accounts.project.key(),
+            project_admin_token_account: ctx.accounts.project_admin_token_account.key(),
+            referral_account: ctx.accounts.referral_account.key(),
+            referral_token_account: ctx.accounts.referral_token_account.key(),
+            partner_token_account: ctx.accounts.partner_token_account.key(),
+            mint: ctx.accounts.mint.key(),
+            referral_amount: referral_amount,
+            project_amount: project_amount,
+            share_bps,
+            inherit_default: ctx.accounts.referral_account.inherit_default,
+            referral_transfer_fee,
+            referral_net_amount: referral_amount - referral_transfer_fee,
+            project_transfer_fee,
+            project_net_amount: project_amount - project_transfer_fee,
+            keeper_token_account: None,
+            keeper_amount: 0,
+            keeper_transfer_fee: 0,
+            keeper_net_amount: 0,
+            rebate_token_account: None,
+            rebate_amount: 0,
+        }
+    );
 
     Ok(())
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct Claim<'info> {
     #[account(mut)]
@@ -95,7 +139,8 @@ pub struct Claim<'info> {
     project_admin_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
     #[account(
         has_one = project,
-        has_one = partner
+        has_one = partner,
+        constraint = !referral_account.has_rebate() @ ProgramErrorCode::RebateRequiresClaimV2,
     )]
     referral_account: Account<'info, ReferralAccount>,
     #[account(
@@ -118,4 +163,14 @@ pub struct Claim<'info> {
     associated_token_program: Program<'info, AssociatedToken>,
     system_program: Program<'info, System>,
     token_program: Interface<'info, TokenInterface>,
+    #[account(
+        seeds = [MINT_POLICY_SEED, project.key().as_ref()],
+        bump,
+    )]
+    mint_policy: Option<Account<'info, ProjectMintPolicy>>,
+    #[account(
+        seeds = [CLAIM_THRESHOLDS_SEED, project.key().as_ref()],
+        bump,
+    )]
+    claim_thresholds: Option<Account<'info, ProjectClaimThresholds>>,
 }
@@ -0,0 +1,13 @@
+use anchor_lang::prelude::*;
+
+use super::{process_claim, Claim};
+
+// Claims part of the referral token account balance, the split is computed on the requested amount.
+pub fn claim_amount(ctx: Context<Claim>, params: ClaimAmountParams) -> Result<()> {
+    process_claim(ctx, params.amount)
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Debug, Clone, Default)]
+pub struct ClaimAmountParams {
+    pub amount: u64,
+}
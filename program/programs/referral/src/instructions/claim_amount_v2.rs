@@ -0,0 +1,11 @@
+use anchor_lang::prelude::*;
+
+use super::{process_claim_v2, ClaimAmountParams, ClaimV2};
+
+// Claims part of the referral token account balance, the split is computed on the requested amount.
+pub fn claim_amount_v2<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, ClaimV2<'info>>,
+    params: ClaimAmountParams,
+) -> Result<()> {
+    process_claim_v2(ctx, params.amount)
+}
@@ -0,0 +1,288 @@
+use crate::{
+    events::{ClaimAndSwapEvent, ClaimEvent, EventCpi},
+    utils::{
+        calculate_referral_amount, calculate_transfer_fee, check_min_claim_amount,
+        check_mint_extensions, check_mint_policy,
+    },
+    ProgramErrorCode, Project, ProjectClaimThresholds, ProjectMintPolicy, ReferralAccount,
+    CLAIM_THRESHOLDS_SEED, MINT_POLICY_SEED, PROJECT_SEED, REFERRAL_SEED, SWAP_AUTHORITY_SEED,
+    SWAP_TOKEN_ACCOUNT_SEED,
+};
+use anchor_lang::{
+    prelude::*,
+    solana_program::{instruction::Instruction, program::invoke_signed},
+};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{
+        close_account, transfer_checked, CloseAccount, Mint, TokenAccount, TokenInterface,
+        TransferChecked,
+    },
+};
+
+// Claims the referral portion into a temporary token account owned by the swap authority, then
+// CPIs into the project's swap program with the caller supplied instruction data and
+// remaining_accounts. The swap authority only ever controls the temporary token account, so the
+// swap program cannot reach other referral balances. The swap data and minimum output decide where
+// the referral portion ends up, so only the partner may supply them.
+pub fn claim_and_swap<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, ClaimAndSwap<'info>>,
+    params: ClaimAndSwapParams,
+) -> Result<()> {
+    let event_cpi = EventCpi::new(&ctx.accounts.event_authority, ctx.bumps.event_authority);
+
+    let accounts = &ctx.accounts;
+    check_mint_policy(
+        &accounts.project,
+        accounts.mint_policy.as_deref(),
+        &accounts.mint.key(),
+    )?;
+    check_mint_extensions(
+        &accounts.mint.to_account_info(),
+        &accounts.project.mint_extension_policy,
+    )?;
+
+    let amount = accounts.referral_token_account.amount;
+    check_min_claim_amount(
+        &accounts.project,
+        accounts.claim_thresholds.as_deref(),
+        &accounts.mint.key(),
+        amount,
+    )?;
+    let share_bps = accounts
+        .referral_account
+        .share_bps_at(&accounts.project, Clock::get()?.unix_timestamp);
+
+    let referral_amount = calculate_referral_amount(amount, share_bps)?;
+    let project_amount = amount.checked_sub(referral_amount).unwrap();
+
+    let bump = ctx.bumps.referral_account;
+    let project_key = accounts.project.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        REFERRAL_SEED,
+        project_key.as_ref(),
+        accounts.referral_account.name.as_ref().unwrap().as_bytes(),
+        &[bump],
+    ]];
+
+    for (to, leg_amount) in [
+        (
+            accounts.swap_token_account.to_account_info(),
+            referral_amount,
+        ),
+        (
+            accounts.project_admin_token_account.to_account_info(),
+            project_amount,
+        ),
+    ] {
+        if leg_amount > 0 {
+            transfer_checked(
+                CpiContext::new_with_signer(
+                    accounts.token_program.to_account_info(),
+                    TransferChecked {
+                        from: accounts.referral_token_account.to_account_info(),
+                        mint: accounts.mint.to_account_info(),
+                        to,
+                        authority: accounts.referral_account.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                leg_amount,
+                accounts.mint.decimals,
+            )?;
+        }
+    }
+
+    let output_balance_before = accounts.partner_settlement_token_account.amount;
+    let referral_account_key = accounts.referral_account.key();
+    let swap_authority_signer_seeds: &[&[&[u8]]] = &[&[
+        SWAP_AUTHORITY_SEED,
+        referral_account_key.as_ref(),
+        &[ctx.bumps.swap_authority],
+    ]];
+
+    let swap_authority_key = accounts.swap_authority.key();
+    let mut swap_account_infos = ctx.remaining_accounts.to_vec();
+    swap_account_infos.push(accounts.swap_authority.to_account_info());
+    swap_account_infos.push(accounts.swap_program.to_account_info());
+    let swap_instruction = Instruction {
+        program_id: accounts.swap_program.key(),
+        accounts: ctx
+            .remaining_accounts
+            .iter()
+            .map(|account| AccountMeta {
+                pubkey: account.key(),
+                is_signer: account.is_signer || account.key() == swap_authority_key,
+                is_writable: account.is_writable,
+            })
+            .collect(),
+        data: params.swap_data,
+    };
+    invoke_signed(
+        &swap_instruction,
+        &swap_account_infos,
+        swap_authority_signer_seeds,
+    )?;
+
+    let accounts = ctx.accounts;
+    accounts.swap_token_account.reload()?;
+    require!(
+        accounts.swap_token_account.amount == 0,
+        ProgramErrorCode::SwapInputNotConsumed
+    );
+
+    close_account(CpiContext::new_with_signer(
+        accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: accounts.swap_token_account.to_account_info(),
+            destination: accounts.payer.to_account_info(),
+            authority: accounts.swap_authority.to_account_info(),
+        },
+        swap_authority_signer_seeds,
+    ))?;
+
+    accounts.partner_settlement_token_account.reload()?;
+    let output_amount = accounts
+        .partner_settlement_token_account
+        .amount
+        .checked_sub(output_balance_before)
+        .ok_or(ProgramErrorCode::SwapOutputTooLow)?;
+    require!(
+        output_amount >= params.minimum_out,
+        ProgramErrorCode::SwapOutputTooLow
+    );
+
+    let referral_transfer_fee =
+        calculate_transfer_fee(&accounts.mint.to_account_info(), referral_amount)?;
+    let project_transfer_fee =
+        calculate_transfer_fee(&accounts.mint.to_account_info(), project_amount)?;
+
+    emit_event!(
+        &event_cpi,
+        ClaimEvent {
+            project: project_key,
+            project_admin_token_account: accounts.project_admin_token_account.key(),
+            referral_account: referral_account_key,
+            referral_token_account: accounts.referral_token_account.key(),
+            partner_token_account: accounts.partner_settlement_token_account.key(),
+            mint: accounts.mint.key(),
+            referral_amount,
+            project_amount,
+            share_bps,
+            inherit_default: accounts.referral_account.inherit_default,
+            referral_transfer_fee,
+            referral_net_amount: referral_amount - referral_transfer_fee,
+            project_transfer_fee,
+            project_net_amount: project_amount - project_transfer_fee,
+            keeper_token_account: None,
+            keeper_amount: 0,
+            keeper_transfer_fee: 0,
+            keeper_net_amount: 0,
+            rebate_token_account: None,
+            rebate_amount: 0,
+        }
+    );
+
+    emit_event!(
+        &event_cpi,
+        ClaimAndSwapEvent {
+            referral_account: referral_account_key,
+            mint: accounts.mint.key(),
+            input_amount: referral_amount,
+            settlement_mint: accounts.settlement_mint.key(),
+            partner_settlement_token_account: accounts.partner_settlement_token_account.key(),
+            output_amount,
+        }
+    );
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimAndSwap<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+    #[account(
+        has_one = admin,
+        seeds = [PROJECT_SEED, project.base.key().as_ref()],
+        bump,
+        constraint = project.swap_program == Some(swap_program.key()) @ ProgramErrorCode::SwapProgramNotAllowed,
+    )]
+    project: Account<'info, Project>,
+    admin: SystemAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::authority = admin,
+        associated_token::mint = mint,
+        associated_token::token_program = token_program,
+    )]
+    project_admin_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        has_one = project,
+        has_one = partner,
+        constraint = !referral_account.has_rebate() @ ProgramErrorCode::RebateRequiresClaimV2,
+        constraint = referral_account.name.is_some(),
+        seeds = [REFERRAL_SEED, project.key().as_ref(), referral_account.name.as_ref().unwrap().as_bytes()],
+        bump
+    )]
+    referral_account: Account<'info, ReferralAccount>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = referral_account,
+        associated_token::token_program = token_program,
+    )]
+    referral_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    partner: Signer<'info>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::authority = partner,
+        associated_token::mint = settlement_mint,
+        associated_token::token_program = settlement_token_program,
+    )]
+    partner_settlement_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: PDA signing for the swap, it only has authority over the swap token account
+    #[account(
+        seeds = [SWAP_AUTHORITY_SEED, referral_account.key().as_ref()],
+        bump
+    )]
+    swap_authority: UncheckedAccount<'info>,
+    #[account(
+        init,
+        payer = payer,
+        seeds = [SWAP_TOKEN_ACCOUNT_SEED, referral_account.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = swap_authority,
+        token::token_program = token_program,
+    )]
+    swap_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    mint: Box<InterfaceAccount<'info, Mint>>,
+    settlement_mint: Box<InterfaceAccount<'info, Mint>>,
+    /// CHECK: Whitelisted on the project
+    #[account(executable)]
+    swap_program: UncheckedAccount<'info>,
+    system_program: Program<'info, System>,
+    token_program: Interface<'info, TokenInterface>,
+    settlement_token_program: Interface<'info, TokenInterface>,
+    associated_token_program: Program<'info, AssociatedToken>,
+    #[account(
+        seeds = [MINT_POLICY_SEED, project.key().as_ref()],
+        bump,
+    )]
+    mint_policy: Option<Account<'info, ProjectMintPolicy>>,
+    #[account(
+        seeds = [CLAIM_THRESHOLDS_SEED, project.key().as_ref()],
+        bump,
+    )]
+    claim_thresholds: Option<Account<'info, ProjectClaimThresholds>>,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Debug, Clone, Default)]
+pub struct ClaimAndSwapParams {
+    pub swap_data: Vec<u8>,
+    pub minimum_out: u64,
+}
@@ -0,0 +1,200 @@
+use crate::{
+    events::ClaimEvent,
+    utils::{
+        calculate_referral_amount, calculate_transfer_fee, check_min_claim_amount,
+        check_mint_extensions, check_mint_policy,
+    },
+    ProgramErrorCode, Project, ProjectClaimThresholds, ProjectMintPolicy, ReferralAccount,
+    CLAIM_THRESHOLDS_SEED, MINT_POLICY_SEED, PROJECT_SEED, REFERRAL_SEED,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::{self, AssociatedToken},
+    token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+
+// Mint, referral token account, partner token account, project admin token account and token program.
+const CLAIM_ACCOUNTS_LEN: usize = 5;
+
+// Claims every mint passed in remaining_accounts, grouped as
+// [mint, referral_token_account, partner_token_account, project_admin_token_account, token_program].
+// They come after the optional accounts and the event CPI accounts, an absent optional account is
+// passed as the program ID so that the groups keep their position.
+// usize::is_multiple_of is newer than the SBF toolchain.
+#[allow(unknown_lints, clippy::manual_is_multiple_of)]
+pub fn claim_many_v2<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, ClaimManyV2<'info>>,
+) -> Result<()> {
+    require!(
+        !ctx.remaining_accounts.is_empty()
+            && ctx.remaining_accounts.len() % CLAIM_ACCOUNTS_LEN == 0,
+        ProgramErrorCode::InvalidClaimManyAccounts
+    );
+
+    let accounts = &ctx.accounts;
+    let referral_account = &accounts.referral_account;
+    let share_bps = referral_account.share_bps_at(&accounts.project, Clock::get()?.unix_timestamp);
+
+    let bump = ctx.bumps.referral_account;
+    let project_key = accounts.project.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        REFERRAL_SEED,
+        project_key.as_ref(),
+        referral_account.name.as_ref().unwrap().as_bytes(),
+        &[bump],
+    ]];
+
+    for claim_accounts in ctx.remaining_accounts.chunks(CLAIM_ACCOUNTS_LEN) {
+        let mint_info = &claim_accounts[0];
+        let referral_token_account_info = &claim_accounts[1];
+        let partner_token_account_info = &claim_accounts[2];
+        let project_admin_token_account_info = &claim_accounts[3];
+        let token_program_info = &claim_accounts[4];
+
+        let token_program = Interface::<TokenInterface>::try_from(token_program_info)?;
+        let mint = InterfaceAccount::<Mint>::try_from(mint_info)?;
+        require_keys_eq!(
+            *mint_info.owner,
+            token_program.key(),
+            ProgramErrorCode::InvalidClaimManyAccounts
+        );
+
+        check_mint_policy(
+            &accounts.project,
+            accounts.mint_policy.as_deref(),
+            &mint.key(),
+        )?;
+        check_mint_extensions(mint_info, &accounts.project.mint_extension_policy)?;
+
+        let referral_token_account =
+            InterfaceAccount::<TokenAccount>::try_from(referral_token_account_info)?;
+        require_keys_eq!(
+            referral_token_account.owner,
+            referral_account.key(),
+            ProgramErrorCode::InvalidClaimManyAccounts
+        );
+        require_keys_eq!(
+            referral_token_account.mint,
+            mint.key(),
+            ProgramErrorCode::InvalidClaimManyAccounts
+        );
+
+        check_min_claim_amount(
+            &accounts.project,
+            accounts.claim_thresholds.as_deref(),
+            &mint.key(),
+            referral_token_account.amount,
+        )?;
+
+        // Creating the associated token accounts also verifies their addresses.
+        for (token_account_info, authority) in [
+            (partner_token_account_info, &accounts.partner),
+            (project_admin_token_account_info, &accounts.admin),
+        ] {
+            associated_token::create_idempotent(CpiContext::new(
+                accounts.associated_token_program.to_account_info(),
+                associated_token::Create {
+                    payer: accounts.payer.to_account_info(),
+                    associated_token: token_account_info.to_account_info(),
+                    authority: authority.to_account_info(),
+                    mint: mint_info.to_account_info(),
+                    system_program: accounts.system_program.to_account_info(),
+                    token_program: token_program_info.to_account_info(),
+                },
+            ))?;
+        }
+
+        let amount = referral_token_account.amount;
+        let referral_amount = calculate_referral_amount(amount, share_bps)?;
+        let project_amount = amount.checked_sub(referral_amount).unwrap();
+
+        for (to, leg_amount) in [
+            (partner_token_account_info, referral_amount),
+            (project_admin_token_account_info, project_amount),
+        ] {
+            if leg_amount > 0 {
+                transfer_checked(
+                    CpiContext::new_with_signer(
+                        token_program_info.to_account_info(),
+                        TransferChecked {
+                            from: referral_token_account_info.to_account_info(),
+                            mint: mint_info.to_account_info(),
+                            to: to.to_account_info(),
+                            authority: referral_account.to_account_info(),
+                        },
+                        signer_seeds,
+                    ),
+                    leg_amount,
+                    mint.decimals,
+                )?;
+            }
+        }
+
+        let referral_transfer_fee = calculate_transfer_fee(mint_info, referral_amount)?;
+        let project_transfer_fee = calculate_transfer_fee(mint_info, project_amount)?;
+
+        emit_event!(
+            &ctx,
+            ClaimEvent {
+                project: project_key,
+                project_admin_token_account: project_admin_token_account_info.key(),
+                referral_account: referral_account.key(),
+                referral_token_account: referral_token_account_info.key(),
+                partner_token_account: partner_token_account_info.key(),
+                mint: mint.key(),
+                referral_amount,
+                project_amount,
+                share_bps,
+                inherit_default: referral_account.inherit_default,
+                referral_transfer_fee,
+                referral_net_amount: referral_amount - referral_transfer_fee,
+                project_transfer_fee,
+                project_net_amount: project_amount - project_transfer_fee,
+                keeper_token_account: None,
+                keeper_amount: 0,
+                keeper_transfer_fee: 0,
+                keeper_net_amount: 0,
+                rebate_token_account: None,
+                rebate_amount: 0,
+            }
+        );
+    }
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimManyV2<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+    #[account(
+        has_one = admin,
+        seeds = [PROJECT_SEED, project.base.key().as_ref()],
+        bump,
+    )]
+    project: Account<'info, Project>,
+    admin: SystemAccount<'info>,
+    #[account(
+        has_one = project,
+        has_one = partner,
+        constraint = !referral_account.has_rebate() @ ProgramErrorCode::RebateRequiresClaimV2,
+        constraint = referral_account.name.is_some(),
+        seeds = [REFERRAL_SEED, project.key().as_ref(), referral_account.name.as_ref().unwrap().as_bytes()],
+        bump
+    )]
+    referral_account: Account<'info, ReferralAccount>,
+    partner: SystemAccount<'info>,
+    system_program: Program<'info, System>,
+    associated_token_program: Program<'info, AssociatedToken>,
+    #[account(
+        seeds = [MINT_POLICY_SEED, project.key().as_ref()],
+        bump,
+    )]
+    mint_policy: Option<Account<'info, ProjectMintPolicy>>,
+    #[account(
+        seeds = [CLAIM_THRESHOLDS_SEED, project.key().as_ref()],
+        bump,
+    )]
+    claim_thresholds: Option<Account<'info, ProjectClaimThresholds>>,
+}
@@ -0,0 +1,244 @@
+use crate::{
+    events::ClaimNativeEvent,
+    utils::{calculate_referral_amount, check_min_claim_amount, check_mint_policy},
+    ProgramErrorCode, Project, ProjectClaimThresholds, ProjectMintPolicy, ReferralAccount,
+    CLAIM_THRESHOLDS_SEED, MINT_POLICY_SEED, PROJECT_SEED, REFERRAL_SEED, TEMPORARY_WSOL_SEED,
+};
+use anchor_lang::{prelude::*, system_program};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{spl_token::native_mint, Token},
+    token_interface::{
+        close_account, transfer_checked, CloseAccount, Mint, TokenAccount, TransferChecked,
+    },
+};
+
+// Claims a wrapped SOL referral token account, paying the unwrapped portions out as native SOL.
+// Unwrapped portions go through a temporary token account that is closed to the payer, who then
+// forwards the lamports, so the payer only fronts the temporary account rent within the instruction.
+// A wallet can't be funded below the rent exemption, so a leg too small for that is paid wrapped to
+// the wallet's token account instead, which must then be passed.
+pub fn claim_native_v2(ctx: Context<ClaimNativeV2>, params: ClaimNativeV2Params) -> Result<()> {
+    let accounts = &ctx.accounts;
+    check_mint_policy(
+        &accounts.project,
+        accounts.mint_policy.as_deref(),
+        &accounts.mint.key(),
+    )?;
+
+    let amount = accounts.referral_token_account.amount;
+    check_min_claim_amount(
+        &accounts.project,
+        accounts.claim_thresholds.as_deref(),
+        &accounts.mint.key(),
+        amount,
+    )?;
+    let share_bps = accounts
+        .referral_account
+        .share_bps_at(&accounts.project, Clock::get()?.unix_timestamp);
+
+    let referral_amount = calculate_referral_amount(amount, share_bps)?;
+    let project_amount = amount.checked_sub(referral_amount).unwrap();
+
+    let bump = ctx.bumps.referral_account;
+    let project_key = accounts.project.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        REFERRAL_SEED,
+        project_key.as_ref(),
+        accounts.referral_account.name.as_ref().unwrap().as_bytes(),
+        &[bump],
+    ]];
+
+    let minimum_balance = Rent::get()?.minimum_balance(0);
+    let can_unwrap = |wallet: &AccountInfo, leg_amount: u64| {
+        leg_amount == 0 || wallet.lamports().saturating_add(leg_amount) >= minimum_balance
+    };
+
+    let partner = accounts.partner.to_account_info();
+    let admin = accounts.admin.to_account_info();
+    let unwrap_referral = params.unwrap_referral && can_unwrap(&partner, referral_amount);
+    let unwrap_project = params.unwrap_project && can_unwrap(&admin, project_amount);
+
+    let legs = [
+        (
+            unwrap_referral,
+            referral_amount,
+            partner,
+            accounts.partner_token_account.as_ref(),
+        ),
+        (
+            unwrap_project,
+            project_amount,
+            admin,
+            accounts.project_admin_token_account.as_ref(),
+        ),
+    ];
+
+    let mut unwrap_amount: u64 = 0;
+
+    for (unwrap, leg_amount, _, token_account) in legs.iter() {
+        let to = if *unwrap {
+            unwrap_amount = unwrap_amount
+                .checked_add(*leg_amount)
+                .ok_or(ProgramErrorCode::InvalidCalculation)?;
+            accounts.temporary_token_account.to_account_info()
+        } else {
+            token_account
+                .ok_or(ProgramErrorCode::MissingPayoutTokenAccount)?
+                .to_account_info()
+        };
+
+        if *leg_amount > 0 {
+            transfer_checked(
+                CpiContext::new_with_signer(
+                    accounts.token_program.to_account_info(),
+                    TransferChecked {
+                        from: accounts.referral_token_account.to_account_info(),
+                        mint: accounts.mint.to_account_info(),
+                        to,
+                        authority: accounts.referral_account.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                *leg_amount,
+                accounts.mint.decimals,
+            )?;
+        }
+    }
+
+    close_account(CpiContext::new_with_signer(
+        accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: accounts.temporary_token_account.to_account_info(),
+            destination: accounts.payer.to_account_info(),
+            authority: accounts.referral_account.to_account_info(),
+        },
+        signer_seeds,
+    ))?;
+
+    for (unwrap, leg_amount, wallet, _) in legs.iter() {
+        if *unwrap && *leg_amount > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    accounts.system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: accounts.payer.to_account_info(),
+                        to: wallet.clone(),
+                    },
+                ),
+                *leg_amount,
+            )?;
+        }
+    }
+
+    emit_event!(
+        &ctx,
+        ClaimNativeEvent {
+            project: project_key,
+            referral_account: accounts.referral_account.key(),
+            referral_token_account: accounts.referral_token_account.key(),
+            mint: accounts.mint.key(),
+            partner: accounts.partner.key(),
+            partner_token_account: accounts
+                .partner_token_account
+                .as_ref()
+                .filter(|_| !unwrap_referral)
+                .map(|partner_token_account| partner_token_account.key()),
+            admin: accounts.admin.key(),
+            project_admin_token_account: accounts
+                .project_admin_token_account
+                .as_ref()
+                .filter(|_| !unwrap_project)
+                .map(|project_admin_token_account| project_admin_token_account.key()),
+            referral_amount,
+            project_amount,
+            share_bps,
+            inherit_default: accounts.referral_account.inherit_default,
+            unwrap_referral,
+            unwrap_project,
+            unwrapped_amount: unwrap_amount,
+        }
+    );
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimNativeV2<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+    #[account(
+        has_one = admin,
+        seeds = [PROJECT_SEED, project.base.key().as_ref()],
+        bump,
+    )]
+    project: Account<'info, Project>,
+    #[account(mut)]
+    admin: SystemAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::authority = admin,
+        associated_token::mint = mint,
+        associated_token::token_program = token_program,
+    )]
+    project_admin_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    #[account(
+        has_one = project,
+        has_one = partner,
+        constraint = !referral_account.has_rebate() @ ProgramErrorCode::RebateRequiresClaimV2,
+        constraint = referral_account.name.is_some(),
+        seeds = [REFERRAL_SEED, project.key().as_ref(), referral_account.name.as_ref().unwrap().as_bytes()],
+        bump
+    )]
+    referral_account: Account<'info, ReferralAccount>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = referral_account,
+        associated_token::token_program = token_program,
+    )]
+    referral_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut)]
+    partner: SystemAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::authority = partner,
+        associated_token::mint = mint,
+        associated_token::token_program = token_program,
+    )]
+    partner_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    #[account(
+        init,
+        payer = payer,
+        seeds = [TEMPORARY_WSOL_SEED, referral_account.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = referral_account,
+        token::token_program = token_program,
+    )]
+    temporary_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(address = native_mint::ID)]
+    mint: Box<InterfaceAccount<'info, Mint>>,
+    system_program: Program<'info, System>,
+    token_program: Program<'info, Token>,
+    associated_token_program: Program<'info, AssociatedToken>,
+    #[account(
+        seeds = [MINT_POLICY_SEED, project.key().as_ref()],
+        bump,
+    )]
+    mint_policy: Option<Account<'info, ProjectMintPolicy>>,
+    #[account(
+        seeds = [CLAIM_THRESHOLDS_SEED, project.key().as_ref()],
+        bump,
+    )]
+    claim_thresholds: Option<Account<'info, ProjectClaimThresholds>>,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Debug, Clone, Default)]
+pub struct ClaimNativeV2Params {
+    pub unwrap_referral: bool,
+    pub unwrap_project: bool,
+}
@@ -0,0 +1,137 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{
+    events::{ClaimRebateEvent, EventCpi},
+    utils::{rebate_leaf, transfer_checked_with_hook, verify_merkle_proof},
+    ProgramErrorCode, RebateBitmap, RebatePool, REBATE_BITMAP_SEED, REBATE_POOL_SEED,
+};
+
+// Pays a trader the amount of their leaf in the current round of the pool. Transfer hook accounts
+// of the mint are passed in remaining_accounts.
+pub fn claim_rebate<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, ClaimRebate<'info>>,
+    params: ClaimRebateParams,
+) -> Result<()> {
+    let event_cpi = EventCpi::new(&ctx.accounts.event_authority, ctx.bumps.event_authority);
+
+    let accounts = ctx.accounts;
+    let rebate_pool = &accounts.rebate_pool;
+
+    let leaf = rebate_leaf(
+        rebate_pool.round,
+        params.index,
+        &accounts.trader.key(),
+        params.amount,
+    );
+    require!(
+        verify_merkle_proof(&params.proof, &rebate_pool.merkle_root, leaf),
+        ProgramErrorCode::InvalidMerkleProof
+    );
+
+    let rebate_bitmap = &mut accounts.rebate_bitmap;
+    // Freshly created bitmaps have no pool yet.
+    if rebate_bitmap.rebate_pool == Pubkey::default() {
+        rebate_bitmap.rebate_pool = rebate_pool.key();
+        rebate_bitmap.round = rebate_pool.round;
+        rebate_bitmap.chunk = RebateBitmap::chunk(params.index);
+    }
+    require!(
+        rebate_bitmap.claim(params.index),
+        ProgramErrorCode::RebateAlreadyClaimed
+    );
+
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        REBATE_POOL_SEED,
+        rebate_pool.referral_account.as_ref(),
+        rebate_pool.mint.as_ref(),
+        &[ctx.bumps.rebate_pool],
+    ]];
+
+    if params.amount > 0 {
+        transfer_checked_with_hook(
+            accounts.token_program.to_account_info(),
+            accounts.rebate_pool_token_account.to_account_info(),
+            accounts.mint.to_account_info(),
+            accounts.trader_token_account.to_account_info(),
+            rebate_pool.to_account_info(),
+            ctx.remaining_accounts,
+            params.amount,
+            accounts.mint.decimals,
+            signer_seeds,
+        )?;
+    }
+
+    emit_event!(
+        &event_cpi,
+        ClaimRebateEvent {
+            project: rebate_pool.project,
+            referral_account: rebate_pool.referral_account,
+            rebate_pool: rebate_pool.key(),
+            trader: accounts.trader.key(),
+            trader_token_account: accounts.trader_token_account.key(),
+            mint: rebate_pool.mint,
+            round: rebate_pool.round,
+            index: params.index,
+            amount: params.amount,
+        }
+    );
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(params: ClaimRebateParams)]
+pub struct ClaimRebate<'info> {
+    #[account(mut)]
+    trader: Signer<'info>,
+    #[account(
+        has_one = mint,
+        seeds = [REBATE_POOL_SEED, rebate_pool.referral_account.as_ref(), mint.key().as_ref()],
+        bump,
+    )]
+    rebate_pool: Account<'info, RebatePool>,
+    #[account(
+        mut,
+        associated_token::authority = rebate_pool,
+        associated_token::mint = mint,
+        associated_token::token_program = token_program,
+    )]
+    rebate_pool_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        init_if_needed,
+        payer = trader,
+        associated_token::authority = trader,
+        associated_token::mint = mint,
+        associated_token::token_program = token_program,
+    )]
+    trader_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        init_if_needed,
+        payer = trader,
+        seeds = [
+            REBATE_BITMAP_SEED,
+            rebate_pool.key().as_ref(),
+            rebate_pool.round.to_le_bytes().as_ref(),
+            RebateBitmap::chunk(params.index).to_le_bytes().as_ref(),
+        ],
+        bump,
+        space = RebateBitmap::LEN
+    )]
+    rebate_bitmap: Box<Account<'info, RebateBitmap>>,
+    mint: Box<InterfaceAccount<'info, Mint>>,
+    system_program: Program<'info, System>,
+    token_program: Interface<'info, TokenInterface>,
+    associated_token_program: Program<'info, AssociatedToken>,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Debug, Clone, Default)]
+pub struct ClaimRebateParams {
+    pub index: u64,
+    pub amount: u64,
+    pub proof: Vec<[u8; 32]>,
+}
@@ -1,31 +1,121 @@
 use crate::{
-    events::ClaimEvent, ProgramErrorCode, Project, ReferralAccount, PROJECT_SEED, REFERRAL_SEED,
+    events::ClaimEvent,
+    utils::{
+        calculate_referral_amount, calculate_transfer_fee, check_min_claim_amount,
+        check_mint_extensions, check_mint_policy, transfer_checked_with_hook,
+    },
+    ProgramErrorCode, Project, ProjectClaimThresholds, ProjectMintPolicy, ReferralAccount,
+    CLAIM_THRESHOLDS_SEED, MINT_POLICY_SEED, PROJECT_SEED, REBATE_POOL_SEED, REFERRAL_SEED,
 };
 use anchor_lang::prelude::*;
 use anchor_spl::{
-    associated_token::AssociatedToken,
-    token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked},
+    associated_token::{self, AssociatedToken},
+    token_interface::{Mint, TokenAccount, TokenInterface},
 };
 
-const DENOMINATOR: u128 = 10_000;
+// Transfer hook accounts of the mint are passed in remaining_accounts and forwarded to every leg.
+// They come after the optional accounts and the event CPI accounts, an absent optional account is
+// passed as the program ID so that the accounts after it keep their position.
+pub fn claim_v2<'c: 'info, 'info>(ctx: Context<'_, '_, 'c, 'info, ClaimV2<'info>>) -> Result<()> {
+    let token_account_balance = ctx.accounts.referral_token_account.amount;
+    process_claim_v2(ctx, token_account_balance)
+}
 
-pub fn claim_v2(ctx: Context<ClaimV2>) -> Result<()> {
+pub(crate) fn process_claim_v2<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, ClaimV2<'info>>,
+    amount: u64,
+) -> Result<()> {
     let accounts = &ctx.accounts;
-    let token_account_balance = accounts.referral_token_account.amount;
+    require!(
+        amount <= accounts.referral_token_account.amount,
+        ProgramErrorCode::ClaimAmountExceedsBalance
+    );
+
+    check_mint_policy(
+        &accounts.project,
+        accounts.mint_policy.as_deref(),
+        &accounts.mint.key(),
+    )?;
+    // The referral associated token account can be opened without the program, so the mint is
+    // screened again here.
+    check_mint_extensions(
+        &accounts.mint.to_account_info(),
+        &accounts.project.mint_extension_policy,
+    )?;
+    check_min_claim_amount(
+        &accounts.project,
+        accounts.claim_thresholds.as_deref(),
+        &accounts.mint.key(),
+        amount,
+    )?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let share_bps = accounts
+        .referral_account
+        .share_bps_at(&accounts.project, now);
 
     // Dependent on the project share bps, calculate the referral amount.
-    let referral_amount: u64 = u128::from(token_account_balance)
-        .checked_mul(u128::from(accounts.referral_account.share_bps))
-        .ok_or(ProgramErrorCode::InvalidCalculation)?
-        .checked_div(DENOMINATOR)
-        .ok_or(ProgramErrorCode::InvalidCalculation)?
-        .try_into()
-        .unwrap();
+    let referral_amount = calculate_referral_amount(amount, share_bps)?;
 
     // Calculate the project amount.
-    let project_amount = token_account_balance.checked_sub(referral_amount).unwrap();
+    let project_amount = amount.checked_sub(referral_amount).unwrap();
+
+    // The keeper is only paid when the payer passes a token account to receive the fee, and only
+    // for claiming the whole balance, so that splitting it into partial claims doesn't multiply
+    // the capped fee. It comes out of the project amount, leaving the referral amount as set by
+    // the share bps.
+    let keeper_amount = match accounts.keeper_token_account {
+        Some(_) if amount == accounts.referral_token_account.amount => {
+            let claim_thresholds = accounts
+                .claim_thresholds
+                .as_deref()
+                .ok_or(ProgramErrorCode::MissingClaimThresholdsAccount)?;
+            accounts
+                .project
+                .keeper_fee(amount, claim_thresholds, &accounts.mint.key())?
+                .min(project_amount)
+        }
+        _ => 0,
+    };
+    let project_amount = project_amount.checked_sub(keeper_amount).unwrap();
     let mint = &ctx.accounts.mint;
 
+    // The rebate comes out of the referral amount and goes to the pool of the referral account
+    // and mint, which traders claim from.
+    let rebate_bps = accounts.referral_account.rebate_bps_at(now);
+    let (rebate_token_account, rebate_amount) = if rebate_bps > 0 {
+        let rebate_pool_token_account = accounts
+            .rebate_pool_token_account
+            .as_ref()
+            .ok_or(ProgramErrorCode::MissingRebatePoolAccount)?;
+        let (rebate_pool, _) = Pubkey::find_program_address(
+            &[
+                REBATE_POOL_SEED,
+                accounts.referral_account.key().as_ref(),
+                mint.key().as_ref(),
+            ],
+            &crate::ID,
+        );
+        // The pool only pays out of its associated token account, see claim_rebate.
+        require_keys_eq!(
+            rebate_pool_token_account.key(),
+            associated_token::get_associated_token_address_with_program_id(
+                &rebate_pool,
+                &mint.key(),
+                accounts.token_program.key,
+            ),
+            ProgramErrorCode::InvalidRebatePoolAccount
+        );
+
+        (
+            Some(rebate_pool_token_account),
+            calculate_referral_amount(referral_amount, rebate_bps)?,
+        )
+    } else {
+        (None, 0)
+    };
+    let referral_amount = referral_amount.checked_sub(rebate_amount).unwrap();
+
     let bump = ctx.bumps.referral_account;
     let project_key = accounts.project.key();
     let signer_seeds: &[&[&[u8]]] = &[&[
@@ -35,54 +125,108 @@ pub fn claim_v2(ctx: Context<ClaimV2>) -> Result<()> {
         &[bump],
     ]];
 
-    if referral_amount > 0 {
-        transfer_checked(
-            CpiContext::new_with_signer(
+    if let Some(keeper_token_account) = &accounts.keeper_token_account {
+        if keeper_amount > 0 {
+            transfer_checked_with_hook(
                 accounts.token_program.to_account_info(),
-                TransferChecked {
-                    from: accounts.referral_token_account.to_account_info(),
-                    mint: accounts.mint.to_account_info(),
-                    to: accounts.partner_token_account.to_account_info(),
-                    authority: accounts.referral_account.to_account_info(),
-                },
+                accounts.referral_token_account.to_account_info(),
+                accounts.mint.to_account_info(),
+                keeper_token_account.to_account_info(),
+                accounts.referral_account.to_account_info(),
+                ctx.remaining_accounts,
+                keeper_amount,
+                mint.decimals,
                 signer_seeds,
-            ),
+            )?;
+        }
+    }
+
+    if let Some(rebate_token_account) = rebate_token_account {
+        if rebate_amount > 0 {
+            transfer_checked_with_hook(
+                accounts.token_program.to_account_info(),
+                accounts.referral_token_account.to_account_info(),
+                accounts.mint.to_account_info(),
+                rebate_token_account.to_account_info(),
+                accounts.referral_account.to_account_info(),
+                ctx.remaining_accounts,
+                rebate_amount,
+                mint.decimals,
+                signer_seeds,
+            )?;
+        }
+    }
+
+    if referral_amount > 0 {
+        transfer_checked_with_hook(
+            accounts.token_program.to_account_info(),
+            accounts.referral_token_account.to_account_info(),
+            accounts.mint.to_account_info(),
+            accounts.partner_token_account.to_account_info(),
+            accounts.referral_account.to_account_info(),
+            ctx.remaining_accounts,
             referral_amount,
             mint.decimals,
+            signer_seeds,
         )?;
     }
 
     if project_amount > 0 {
-        transfer_checked(
-            CpiContext::new_with_signer(
-                accounts.token_program.to_account_info(),
-                TransferChecked {
-                    from: accounts.referral_token_account.to_account_info(),
-                    mint: accounts.mint.to_account_info(),
-                    to: accounts.project_admin_token_account.to_account_info(),
-                    authority: accounts.referral_account.to_account_info(),
-                },
-                signer_seeds,
-            ),
+        transfer_checked_with_hook(
+            accounts.token_program.to_account_info(),
+            accounts.referral_token_account.to_account_info(),
+            accounts.mint.to_account_info(),
+            accounts.project_admin_token_account.to_account_info(),
+            accounts.referral_account.to_account_info(),
+            ctx.remaining_accounts,
             project_amount,
             mint.decimals,
+            signer_seeds,
         )?;
     }
 
-    emit!(ClaimEvent {
-        project: ctx.accounts.project.key(),
-        project_admin_token_account: ctx.accounts.project_admin_token_account.key(),
-        referral_account: ctx.accounts.referral_account.key(),
-        referral_token_account: ctx.accounts.referral_token_account.key(),
-        partner_token_account: ctx.accounts.partner_token_account.key(),
-        mint: ctx.accounts.mint.key(),
-        referral_amount: referral_amount,
-        project_amount: project_amount
-    });
+    let referral_transfer_fee =
+        calculate_transfer_fee(&ctx.accounts.mint.to_account_info(), referral_amount)?;
+    let project_transfer_fee =
+        calculate_transfer_fee(&ctx.accounts.mint.to_account_info(), project_amount)?;
+    let keeper_transfer_fee =
+        calculate_transfer_fee(&ctx.accounts.mint.to_account_info(), keeper_amount)?;
+
+    emit_event!(
+        &ctx,
+        ClaimEvent {
+            project: ctx.accounts.project.key(),
+            project_admin_token_account: ctx.accounts.project_admin_token_account.key(),
+            referral_account: ctx.accounts.referral_account.key(),
+            referral_token_account: ctx.accounts.referral_token_account.key(),
+            partner_token_account: ctx.accounts.partner_token_account.key(),
+            mint: ctx.accounts.mint.key(),
+            referral_amount: referral_amount,
+            project_amount: project_amount,
+            share_bps,
+            inherit_default: ctx.accounts.referral_account.inherit_default,
+            referral_transfer_fee,
+            referral_net_amount: referral_amount - referral_transfer_fee,
+            project_transfer_fee,
+            project_net_amount: project_amount - project_transfer_fee,
+            keeper_token_account: ctx
+                .accounts
+                .keeper_token_account
+                .as_ref()
+                .map(|keeper_token_account| keeper_token_account.key()),
+            keeper_amount,
+            keeper_transfer_fee,
+            keeper_net_amount: keeper_amount - keeper_transfer_fee,
+            rebate_token_account: rebate_token_account
+                .map(|rebate_token_account| rebate_token_account.key()),
+            rebate_amount,
+        }
+    );
 
     Ok(())
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct ClaimV2<'info> {
     #[account(mut)]
@@ -130,4 +274,28 @@ pub struct ClaimV2<'info> {
     system_program: Program<'info, System>,
     token_program: Interface<'info, TokenInterface>,
     associated_token_program: Program<'info, AssociatedToken>,
+    #[account(
+        seeds = [MINT_POLICY_SEED, project.key().as_ref()],
+        bump,
+    )]
+    mint_policy: Option<Account<'info, ProjectMintPolicy>>,
+    #[account(
+        seeds = [CLAIM_THRESHOLDS_SEED, project.key().as_ref()],
+        bump,
+    )]
+    claim_thresholds: Option<Account<'info, ProjectClaimThresholds>>,
+    #[account(
+        mut,
+        token::authority = payer,
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    keeper_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    // Required when the referral account has a rebate, checked against the pool address in the handler.
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    rebate_pool_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
 }
@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+use crate::{ProgramErrorCode, ProjectProposal};
+
+pub fn close_project_proposal(_ctx: Context<CloseProjectProposal>) -> Result<()> {
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CloseProjectProposal<'info> {
+    #[account(mut)]
+    proposer: Signer<'info>,
+    #[account(
+        mut,
+        has_one = proposer,
+        constraint = proposal.executed
+            || proposal.expires_at <= Clock::get()?.unix_timestamp @ ProgramErrorCode::ProposalNotClosable,
+        close = proposer,
+    )]
+    proposal: Account<'info, ProjectProposal>,
+}
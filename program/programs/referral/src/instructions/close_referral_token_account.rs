@@ -3,12 +3,21 @@ use anchor_spl::token_interface::{
     close_account, CloseAccount, Mint, TokenAccount, TokenInterface,
 };
 
-use crate::{Project, ReferralAccount, PROJECT_SEED, REFERRAL_ATA_SEED};
+use crate::{
+    events::CloseReferralTokenAccountEvent, Project, ReferralAccount, PROJECT_SEED,
+    REFERRAL_ATA_SEED,
+};
 
 pub fn close_referral_token_account(ctx: Context<CloseReferralTokenAccount>) -> Result<()> {
     let bump = ctx.bumps.project;
     let signer_seeds: &[&[&[u8]]] = &[&[PROJECT_SEED, ctx.accounts.project.base.as_ref(), &[bump]]];
 
+    let lamports = ctx
+        .accounts
+        .referral_token_account
+        .to_account_info()
+        .lamports();
+
     close_account(CpiContext::new_with_signer(
         ctx.accounts.token_program.to_account_info(),
         CloseAccount {
@@ -19,9 +28,22 @@ pub fn close_referral_token_account(ctx: Context<CloseReferralTokenAccount>) ->
         signer_seeds,
     ))?;
 
+    emit_event!(
+        &ctx,
+        CloseReferralTokenAccountEvent {
+            project: ctx.accounts.project.key(),
+            referral_account: ctx.accounts.referral_account.key(),
+            referral_token_account: ctx.accounts.referral_token_account.key(),
+            mint: ctx.accounts.mint.key(),
+            partner: ctx.accounts.partner.key(),
+            lamports,
+        }
+    );
+
     Ok(())
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct CloseReferralTokenAccount<'info> {
     #[account(mut)]
@@ -4,7 +4,9 @@ use anchor_spl::{
     token_interface::{close_account, CloseAccount, Mint, TokenAccount, TokenInterface},
 };
 
-use crate::{Project, ReferralAccount, PROJECT_SEED, REFERRAL_SEED};
+use crate::{
+    events::CloseReferralTokenAccountEvent, Project, ReferralAccount, PROJECT_SEED, REFERRAL_SEED,
+};
 
 pub fn close_referral_token_account_v2(ctx: Context<CloseReferralTokenAccountV2>) -> Result<()> {
     let bump = ctx.bumps.referral_account;
@@ -21,6 +23,12 @@ pub fn close_referral_token_account_v2(ctx: Context<CloseReferralTokenAccountV2>
         &[bump],
     ]];
 
+    let lamports = ctx
+        .accounts
+        .referral_token_account
+        .to_account_info()
+        .lamports();
+
     close_account(CpiContext::new_with_signer(
         ctx.accounts.token_program.to_account_info(),
         CloseAccount {
@@ -31,9 +39,22 @@ pub fn close_referral_token_account_v2(ctx: Context<CloseReferralTokenAccountV2>
         signer_seeds,
     ))?;
 
+    emit_event!(
+        &ctx,
+        CloseReferralTokenAccountEvent {
+            project: ctx.accounts.project.key(),
+            referral_account: ctx.accounts.referral_account.key(),
+            referral_token_account: ctx.accounts.referral_token_account.key(),
+            mint: ctx.accounts.mint.key(),
+            partner: ctx.accounts.partner.key(),
+            lamports,
+        }
+    );
+
     Ok(())
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct CloseReferralTokenAccountV2<'info> {
     #[account(mut)]
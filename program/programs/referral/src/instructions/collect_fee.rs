@@ -0,0 +1,127 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    events::FeeCollectedEvent,
+    utils::{calculate_transfer_fee, transfer_checked_with_hook},
+    FeeStats, ProgramErrorCode, Project, ReferralAccount, FEE_STATS_SEED, MAX_SOURCE_TAG_LENGTH,
+};
+
+// Entrypoint for programs that charge a referral fee, they CPI in instead of transferring to the
+// referral token account directly so the fee is attributed to its volume and source. The authority
+// of the source token account signs, transfer hook accounts of the mint are passed in
+// remaining_accounts.
+pub fn collect_fee<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, CollectFee<'info>>,
+    params: CollectFeeParams,
+) -> Result<()> {
+    require!(params.fee_amount > 0, ProgramErrorCode::InvalidFeeAmount);
+
+    let accounts = &ctx.accounts;
+    transfer_checked_with_hook(
+        accounts.token_program.to_account_info(),
+        accounts.source_token_account.to_account_info(),
+        accounts.mint.to_account_info(),
+        accounts.referral_token_account.to_account_info(),
+        accounts.authority.to_account_info(),
+        ctx.remaining_accounts,
+        params.fee_amount,
+        accounts.mint.decimals,
+        &[],
+    )?;
+
+    let fee_stats = &mut ctx.accounts.fee_stats;
+    // Freshly created stats have no referral account yet.
+    if fee_stats.referral_account == Pubkey::default() {
+        fee_stats.referral_account = ctx.accounts.referral_account.key();
+        fee_stats.mint = ctx.accounts.mint.key();
+        fee_stats.authority = ctx.accounts.authority.key();
+        fee_stats.source_tag = params.source_tag.clone();
+    }
+    fee_stats.gross_volume = fee_stats.gross_volume.saturating_add(params.gross_volume);
+    fee_stats.fee_amount = fee_stats.fee_amount.saturating_add(params.fee_amount);
+    fee_stats.collections = fee_stats.collections.saturating_add(1);
+
+    let transfer_fee =
+        calculate_transfer_fee(&ctx.accounts.mint.to_account_info(), params.fee_amount)?;
+    let net_amount = params
+        .fee_amount
+        .checked_sub(transfer_fee)
+        .ok_or(ProgramErrorCode::InvalidCalculation)?;
+
+    emit_event!(
+        &ctx,
+        FeeCollectedEvent {
+            project: ctx.accounts.project.key(),
+            referral_account: ctx.accounts.referral_account.key(),
+            referral_token_account: ctx.accounts.referral_token_account.key(),
+            mint: ctx.accounts.mint.key(),
+            source_token_account: ctx.accounts.source_token_account.key(),
+            fee_stats: ctx.accounts.fee_stats.key(),
+            source_tag: params.source_tag,
+            gross_volume: params.gross_volume,
+            fee_amount: params.fee_amount,
+            transfer_fee,
+            net_amount,
+        }
+    );
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(params: CollectFeeParams)]
+pub struct CollectFee<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+    authority: Signer<'info>,
+    project: Account<'info, Project>,
+    // The source tag is checked here, ahead of the fee stats account, since an over-long seed would
+    // abort its derivation without an error code.
+    #[account(
+        has_one = project,
+        constraint = !params.source_tag.is_empty()
+            && params.source_tag.len() <= MAX_SOURCE_TAG_LENGTH @ ProgramErrorCode::InvalidSourceTag,
+    )]
+    referral_account: Account<'info, ReferralAccount>,
+    #[account(
+        mut,
+        associated_token::authority = referral_account,
+        associated_token::mint = mint,
+        associated_token::token_program = token_program,
+    )]
+    referral_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    source_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        seeds = [
+            FEE_STATS_SEED,
+            referral_account.key().as_ref(),
+            mint.key().as_ref(),
+            authority.key().as_ref(),
+            params.source_tag.as_bytes(),
+        ],
+        bump,
+        space = FeeStats::LEN
+    )]
+    fee_stats: Account<'info, FeeStats>,
+    mint: Box<InterfaceAccount<'info, Mint>>,
+    system_program: Program<'info, System>,
+    token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Debug, Clone, Default)]
+pub struct CollectFeeParams {
+    pub fee_amount: u64,
+    // Volume the fee was charged on, recorded as reported.
+    pub gross_volume: u64,
+    // Identifies the integration the fee came from, such as a program or product name.
+    pub source_tag: String,
+}
@@ -1,35 +1,150 @@
-use crate::{Project, PROJECT_AUTHORITY_SEED};
+use crate::{
+    events::{CreateAdminTokenAccountEvent, EventCpi},
+    utils::check_mint_policy,
+    AdminTokenAccountPolicy, MintPolicyMode, ProgramErrorCode, Project, ProjectMintPolicy,
+    ReferralAccount, MINT_POLICY_SEED, PROJECT_AUTHORITY_SEED, REFERRAL_ATA_SEED,
+};
 use anchor_lang::prelude::*;
 use anchor_spl::associated_token;
 use anchor_spl::associated_token::AssociatedToken;
-use anchor_spl::token_interface::{Mint, TokenInterface};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 // A convenient method that uses the project authority's SOL to create an associated token account for the admin.
 pub fn create_admin_token_account(ctx: Context<CreateAdminTokenAccount>) -> Result<()> {
-    let project = &ctx.accounts.project;
+    let event_cpi = EventCpi::new(&ctx.accounts.event_authority, ctx.bumps.event_authority);
+
+    let accounts = ctx.accounts;
+    // Projects that weren't migrated yet read the bytes past their legacy layout as the policy, so
+    // they keep the default one until then.
+    let policy = if accounts.project.version == 0 {
+        AdminTokenAccountPolicy::default()
+    } else {
+        accounts.project.admin_token_account_policy
+    };
+
+    if policy.require_allowed_mint || policy.min_referral_balance > 0 {
+        // A project without a mint policy has no allowlist to pass.
+        require!(
+            accounts.project.has_mint_policy,
+            ProgramErrorCode::MissingMintPolicyAccount
+        );
+        check_mint_policy(
+            &accounts.project,
+            accounts.mint_policy.as_deref(),
+            &accounts.mint.key(),
+        )?;
+    }
+
+    if policy.min_referral_balance > 0 {
+        // Anyone can fund a referral token account with a mint of their own, so the balance only
+        // counts for mints the project allowlists.
+        let mint_policy = accounts
+            .mint_policy
+            .as_ref()
+            .ok_or(ProgramErrorCode::MissingMintPolicyAccount)?;
+        require!(
+            mint_policy.mode == MintPolicyMode::Allow,
+            ProgramErrorCode::MintAllowlistRequired
+        );
+
+        let referral_account = accounts
+            .referral_account
+            .as_ref()
+            .ok_or(ProgramErrorCode::MissingReferralTokenAccount)?;
+        let referral_token_account = accounts
+            .referral_token_account
+            .as_ref()
+            .ok_or(ProgramErrorCode::MissingReferralTokenAccount)?;
+
+        // Referral token accounts are either the project owned PDA of the referral account, or the
+        // associated token account of a named referral account.
+        let mint_info = accounts.mint.to_account_info();
+        let (referral_ata, _) = Pubkey::find_program_address(
+            &[
+                REFERRAL_ATA_SEED,
+                referral_account.key().as_ref(),
+                mint_info.key.as_ref(),
+            ],
+            &crate::ID,
+        );
+        let associated_token_account =
+            associated_token::get_associated_token_address_with_program_id(
+                &referral_account.key(),
+                mint_info.key,
+                mint_info.owner,
+            );
+        require!(
+            referral_token_account.key() == referral_ata
+                || referral_token_account.key() == associated_token_account,
+            ProgramErrorCode::InvalidReferralTokenAccount
+        );
+
+        require!(
+            referral_token_account.amount >= policy.min_referral_balance,
+            ProgramErrorCode::ReferralBalanceTooLow
+        );
+    }
 
     let bump = ctx.bumps.project_authority;
-    let signer_seeds: &[&[&[u8]]] = &[&[PROJECT_AUTHORITY_SEED, project.base.as_ref(), &[bump]]];
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        PROJECT_AUTHORITY_SEED,
+        accounts.project.base.as_ref(),
+        &[bump],
+    ]];
 
+    let lamports_before = accounts.project_authority.lamports();
     associated_token::create_idempotent(CpiContext::new_with_signer(
-        ctx.accounts.associated_token_program.to_account_info(),
+        accounts.associated_token_program.to_account_info(),
         associated_token::Create {
-            associated_token: ctx.accounts.project_admin_token_account.to_account_info(),
-            authority: ctx.accounts.admin.to_account_info(),
-            mint: ctx.accounts.mint.to_account_info(),
-            payer: ctx.accounts.project_authority.to_account_info(),
-            system_program: ctx.accounts.system_program.to_account_info(),
-            token_program: ctx.accounts.token_program.to_account_info(),
+            associated_token: accounts.project_admin_token_account.to_account_info(),
+            authority: accounts.admin.to_account_info(),
+            mint: accounts.mint.to_account_info(),
+            payer: accounts.project_authority.to_account_info(),
+            system_program: accounts.system_program.to_account_info(),
+            token_program: accounts.token_program.to_account_info(),
         },
         signer_seeds,
     ))?;
+    let lamports = lamports_before
+        .checked_sub(accounts.project_authority.lamports())
+        .ok_or(ProgramErrorCode::InvalidCalculation)?;
+
+    let project = &mut accounts.project;
+    let epoch = Clock::get()?.epoch;
+    if project.admin_token_account_spend_epoch != epoch {
+        project.admin_token_account_spend_epoch = epoch;
+        project.admin_token_account_spent_lamports = 0;
+    }
+    project.admin_token_account_spent_lamports = project
+        .admin_token_account_spent_lamports
+        .checked_add(lamports)
+        .ok_or(ProgramErrorCode::InvalidCalculation)?;
+    require!(
+        project.admin_token_account_spent_lamports <= policy.epoch_budget_lamports,
+        ProgramErrorCode::EpochBudgetExceeded
+    );
+
+    emit_event!(
+        &event_cpi,
+        CreateAdminTokenAccountEvent {
+            project: project.key(),
+            admin: accounts.admin.key(),
+            project_admin_token_account: accounts.project_admin_token_account.key(),
+            mint: accounts.mint.key(),
+            lamports,
+            epoch,
+            epoch_spent_lamports: project.admin_token_account_spent_lamports,
+        }
+    );
 
     Ok(())
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct CreateAdminTokenAccount<'info> {
     #[account(
+        mut,
         has_one = admin,
     )]
     project: Account<'info, Project>,
@@ -47,4 +162,14 @@ pub struct CreateAdminTokenAccount<'info> {
     system_program: Program<'info, System>,
     token_program: Interface<'info, TokenInterface>,
     associated_token_program: Program<'info, AssociatedToken>,
+    #[account(
+        seeds = [MINT_POLICY_SEED, project.key().as_ref()],
+        bump,
+    )]
+    mint_policy: Option<Account<'info, ProjectMintPolicy>>,
+    #[account(
+        has_one = project,
+    )]
+    referral_account: Option<Account<'info, ReferralAccount>>,
+    referral_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
 }
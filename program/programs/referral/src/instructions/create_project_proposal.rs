@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::CreateProjectProposalEvent, ProgramErrorCode, Project, ProjectAction, ProjectProposal,
+    MAX_PROPOSAL_REFERRAL_ACCOUNT_UPDATES, PROPOSAL_SEED,
+};
+
+pub fn create_project_proposal(
+    ctx: Context<CreateProjectProposal>,
+    params: CreateProjectProposalParams,
+) -> Result<()> {
+    require!(
+        params.expires_at > Clock::get()?.unix_timestamp,
+        ProgramErrorCode::InvalidProposalExpiry
+    );
+    // The other actions have a fixed size, this one has to fit in the proposal account.
+    if let ProjectAction::UpdateReferralAccounts {
+        referral_accounts,
+        share_bps,
+    } = &params.action
+    {
+        require!(
+            !share_bps.is_empty()
+                && share_bps.len() == referral_accounts.len()
+                && share_bps.len() <= MAX_PROPOSAL_REFERRAL_ACCOUNT_UPDATES,
+            ProgramErrorCode::InvalidReferralAccountsUpdate
+        );
+    }
+
+    let proposer = ctx.accounts.proposer.key();
+
+    // The proposer implicitly approves their own proposal.
+    ctx.accounts.proposal.set_inner(ProjectProposal {
+        project: ctx.accounts.project.key(),
+        proposer,
+        id: params.id,
+        action: params.action.clone(),
+        approvals: vec![proposer],
+        expires_at: params.expires_at,
+        executed: false,
+    });
+
+    emit_event!(
+        &ctx,
+        CreateProjectProposalEvent {
+            project: ctx.accounts.project.key(),
+            proposal: ctx.accounts.proposal.key(),
+            proposer,
+            id: params.id,
+            action: params.action,
+            expires_at: params.expires_at,
+        }
+    );
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(params: CreateProjectProposalParams)]
+pub struct CreateProjectProposal<'info> {
+    #[account(mut)]
+    proposer: Signer<'info>,
+    #[account(
+        constraint = project.has_multisig() @ ProgramErrorCode::InvalidMultisigConfig,
+        constraint = project.is_multisig_signer(&proposer.key()) @ ProgramErrorCode::NotMultisigSigner,
+    )]
+    project: Account<'info, Project>,
+    #[account(
+        init,
+        payer = proposer,
+        seeds = [PROPOSAL_SEED, project.key().as_ref(), params.id.to_le_bytes().as_ref()],
+        bump,
+        space = ProjectProposal::LEN
+    )]
+    proposal: Account<'info, ProjectProposal>,
+    system_program: Program<'info, System>,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Debug, Clone)]
+pub struct CreateProjectProposalParams {
+    pub id: u64,
+    pub action: ProjectAction,
+    pub expires_at: i64,
+}
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::CreateReferralAliasEvent, Alias, ProgramErrorCode, Project, ReferralAccount,
+    ALIAS_SEED, MAX_ALIAS_LENGTH,
+};
+
+pub fn create_referral_alias(
+    ctx: Context<CreateReferralAlias>,
+    params: CreateReferralAliasParams,
+) -> Result<()> {
+    ctx.accounts.alias.set_inner(Alias {
+        project: ctx.accounts.project.key(),
+        referral_account: ctx.accounts.referral_account.key(),
+        alias: params.alias.clone(),
+    });
+
+    emit_event!(
+        &ctx,
+        CreateReferralAliasEvent {
+            project: ctx.accounts.project.key(),
+            referral_account: ctx.accounts.referral_account.key(),
+            alias_account: ctx.accounts.alias.key(),
+            alias: params.alias,
+        }
+    );
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(params: CreateReferralAliasParams)]
+pub struct CreateReferralAlias<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+    partner: Signer<'info>,
+    project: Account<'info, Project>,
+    // The alias is checked here, ahead of the alias account, since an over-long seed would abort
+    // its derivation without an error code.
+    #[account(
+        has_one = project,
+        has_one = partner,
+        constraint = !params.alias.is_empty()
+            && params.alias.len() <= MAX_ALIAS_LENGTH @ ProgramErrorCode::InvalidAlias,
+    )]
+    referral_account: Account<'info, ReferralAccount>,
+    #[account(
+        init,
+        payer = payer,
+        seeds = [ALIAS_SEED, project.key().as_ref(), params.alias.as_bytes()],
+        bump,
+        space = Alias::LEN
+    )]
+    alias: Account<'info, Alias>,
+    system_program: Program<'info, System>,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Debug, Clone, Default)]
+pub struct CreateReferralAliasParams {
+    pub alias: String,
+}
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::{events::DeleteReferralAliasEvent, Alias, ReferralAccount};
+
+pub fn delete_referral_alias(ctx: Context<DeleteReferralAlias>) -> Result<()> {
+    emit_event!(
+        &ctx,
+        DeleteReferralAliasEvent {
+            project: ctx.accounts.alias.project,
+            referral_account: ctx.accounts.referral_account.key(),
+            alias_account: ctx.accounts.alias.key(),
+            alias: ctx.accounts.alias.alias.clone(),
+        }
+    );
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct DeleteReferralAlias<'info> {
+    #[account(mut)]
+    partner: Signer<'info>,
+    #[account(has_one = partner)]
+    referral_account: Account<'info, ReferralAccount>,
+    #[account(
+        mut,
+        has_one = referral_account,
+        close = partner,
+    )]
+    alias: Account<'info, Alias>,
+}
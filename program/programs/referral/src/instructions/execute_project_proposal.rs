@@ -0,0 +1,317 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::{EventCpi, ExecuteProjectProposalEvent},
+    Metadata, ProgramErrorCode, Project, ProjectAction, ProjectClaimThresholds, ProjectMintPolicy,
+    ProjectProposal, RebatePool, ReferralAccount, CLAIM_THRESHOLDS_SEED, METADATA_SEED,
+    MINT_POLICY_SEED, PROJECT_AUTHORITY_SEED,
+};
+
+use super::{
+    apply_add_project_policy_mint, apply_publish_rebate_root, apply_remove_project_policy_mint,
+    apply_set_project_admin_token_account_policy, apply_set_project_attribution_policy,
+    apply_set_project_keeper_fee, apply_set_project_max_keeper_fee, apply_set_project_metadata,
+    apply_set_project_min_claim_amount, apply_set_project_mint_extension_policy,
+    apply_set_project_mint_policy_mode, apply_set_project_multisig, apply_set_project_sponsorship,
+    apply_set_project_swap_program, apply_set_referral_account_rebate,
+    apply_set_referral_account_share_mode, apply_transfer_project, apply_update_project,
+    apply_update_referral_account, apply_update_referral_accounts, apply_withdraw_from_project,
+};
+
+pub fn execute_project_proposal<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, ExecuteProjectProposal<'info>>,
+) -> Result<()> {
+    let event_cpi = EventCpi::new(&ctx.accounts.event_authority, ctx.bumps.event_authority);
+
+    let accounts = ctx.accounts;
+    let proposal = &mut accounts.proposal;
+
+    require!(
+        !proposal.executed,
+        ProgramErrorCode::ProposalAlreadyExecuted
+    );
+    require!(
+        proposal.expires_at > Clock::get()?.unix_timestamp,
+        ProgramErrorCode::ProposalExpired
+    );
+
+    // Only approvals from the current signer set count, the set may have changed since they were given.
+    let approvals = proposal
+        .approvals
+        .iter()
+        .filter(|approval| accounts.project.is_multisig_signer(approval))
+        .count();
+    require!(
+        approvals >= usize::from(accounts.project.multisig_threshold),
+        ProgramErrorCode::ProposalThresholdNotMet
+    );
+
+    proposal.executed = true;
+
+    match proposal.action.clone() {
+        ProjectAction::UpdateProject(params) => {
+            apply_update_project(&mut accounts.project, params, &event_cpi)?;
+        }
+        ProjectAction::UpdateReferralAccount {
+            referral_account,
+            share_bps,
+        } => {
+            let account = accounts
+                .referral_account
+                .as_mut()
+                .ok_or(ProgramErrorCode::MissingProposalAccount)?;
+            require_keys_eq!(
+                account.key(),
+                referral_account,
+                ProgramErrorCode::MissingProposalAccount
+            );
+
+            apply_update_referral_account(&accounts.project, account, share_bps, &event_cpi)?;
+        }
+        ProjectAction::UpdateReferralAccounts {
+            referral_accounts,
+            share_bps,
+        } => {
+            require!(
+                ctx.remaining_accounts
+                    .iter()
+                    .map(|account_info| account_info.key())
+                    .eq(referral_accounts),
+                ProgramErrorCode::MissingProposalAccount
+            );
+
+            apply_update_referral_accounts(
+                &accounts.project,
+                ctx.remaining_accounts,
+                share_bps,
+                &event_cpi,
+            )?;
+        }
+        ProjectAction::TransferProject { new_admin } => {
+            apply_transfer_project(&mut accounts.project, new_admin, &event_cpi)?;
+        }
+        ProjectAction::WithdrawFromProject(params) => {
+            let project_authority = accounts
+                .project_authority
+                .as_ref()
+                .ok_or(ProgramErrorCode::MissingProposalAccount)?;
+            let admin = accounts
+                .admin
+                .as_ref()
+                .ok_or(ProgramErrorCode::MissingProposalAccount)?;
+
+            apply_withdraw_from_project(
+                &accounts.project,
+                ctx.bumps.project_authority,
+                project_authority.to_account_info(),
+                admin.to_account_info(),
+                accounts.system_program.to_account_info(),
+                params.amount,
+                &event_cpi,
+            )?;
+        }
+        ProjectAction::SetMultisig(params) => {
+            apply_set_project_multisig(&mut accounts.project, params, &event_cpi)?;
+        }
+        ProjectAction::SetReferralAccountShareMode {
+            referral_account,
+            inherit_default,
+        } => {
+            let account = accounts
+                .referral_account
+                .as_mut()
+                .ok_or(ProgramErrorCode::MissingProposalAccount)?;
+            require_keys_eq!(
+                account.key(),
+                referral_account,
+                ProgramErrorCode::MissingProposalAccount
+            );
+
+            apply_set_referral_account_share_mode(
+                &accounts.project,
+                account,
+                inherit_default,
+                &event_cpi,
+            )?;
+        }
+        ProjectAction::SetSwapProgram(params) => {
+            apply_set_project_swap_program(&mut accounts.project, params, &event_cpi)?;
+        }
+        ProjectAction::SetMintExtensionPolicy(params) => {
+            apply_set_project_mint_extension_policy(&mut accounts.project, params, &event_cpi)?;
+        }
+        ProjectAction::SetMintPolicyMode(params) => {
+            let mint_policy = accounts
+                .mint_policy
+                .as_mut()
+                .ok_or(ProgramErrorCode::MissingProposalAccount)?;
+
+            apply_set_project_mint_policy_mode(&accounts.project, mint_policy, params, &event_cpi)?;
+        }
+        ProjectAction::AddPolicyMint(params) => {
+            let mint_policy = accounts
+                .mint_policy
+                .as_mut()
+                .ok_or(ProgramErrorCode::MissingProposalAccount)?;
+
+            apply_add_project_policy_mint(&accounts.project, mint_policy, params, &event_cpi)?;
+        }
+        ProjectAction::RemovePolicyMint(params) => {
+            let mint_policy = accounts
+                .mint_policy
+                .as_mut()
+                .ok_or(ProgramErrorCode::MissingProposalAccount)?;
+
+            apply_remove_project_policy_mint(&accounts.project, mint_policy, params, &event_cpi)?;
+        }
+        ProjectAction::SetMinClaimAmount(params) => {
+            let claim_thresholds = accounts
+                .claim_thresholds
+                .as_mut()
+                .ok_or(ProgramErrorCode::MissingProposalAccount)?;
+
+            apply_set_project_min_claim_amount(
+                &accounts.project,
+                claim_thresholds,
+                params,
+                &event_cpi,
+            )?;
+        }
+        ProjectAction::SetKeeperFee(params) => {
+            apply_set_project_keeper_fee(&mut accounts.project, params, &event_cpi)?;
+        }
+        ProjectAction::SetMaxKeeperFee(params) => {
+            let claim_thresholds = accounts
+                .claim_thresholds
+                .as_mut()
+                .ok_or(ProgramErrorCode::MissingProposalAccount)?;
+
+            apply_set_project_max_keeper_fee(
+                &accounts.project,
+                claim_thresholds,
+                params,
+                &event_cpi,
+            )?;
+        }
+        ProjectAction::SetSponsorship(params) => {
+            apply_set_project_sponsorship(&mut accounts.project, params, &event_cpi)?;
+        }
+        ProjectAction::SetAdminTokenAccountPolicy(params) => {
+            apply_set_project_admin_token_account_policy(
+                &mut accounts.project,
+                params,
+                &event_cpi,
+            )?;
+        }
+        ProjectAction::SetMetadata(params) => {
+            let metadata = accounts
+                .metadata
+                .as_mut()
+                .ok_or(ProgramErrorCode::MissingProposalAccount)?;
+
+            apply_set_project_metadata(&accounts.project, metadata, params, &event_cpi)?;
+        }
+        ProjectAction::SetAttributionPolicy(params) => {
+            apply_set_project_attribution_policy(&mut accounts.project, params, &event_cpi)?;
+        }
+        ProjectAction::SetReferralAccountRebate {
+            referral_account,
+            rebate_bps,
+        } => {
+            let account = accounts
+                .referral_account
+                .as_mut()
+                .ok_or(ProgramErrorCode::MissingProposalAccount)?;
+            require_keys_eq!(
+                account.key(),
+                referral_account,
+                ProgramErrorCode::MissingProposalAccount
+            );
+
+            apply_set_referral_account_rebate(&accounts.project, account, rebate_bps, &event_cpi)?;
+        }
+        ProjectAction::PublishRebateRoot {
+            rebate_pool,
+            merkle_root,
+        } => {
+            let pool = accounts
+                .rebate_pool
+                .as_mut()
+                .ok_or(ProgramErrorCode::MissingProposalAccount)?;
+            require_keys_eq!(
+                pool.key(),
+                rebate_pool,
+                ProgramErrorCode::MissingProposalAccount
+            );
+
+            apply_publish_rebate_root(pool, merkle_root, &event_cpi)?;
+        }
+    }
+
+    emit_event!(
+        &event_cpi,
+        ExecuteProjectProposalEvent {
+            project: accounts.project.key(),
+            proposal: proposal.key(),
+            executor: accounts.executor.key(),
+        }
+    );
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ExecuteProjectProposal<'info> {
+    executor: Signer<'info>,
+    #[account(
+        mut,
+        constraint = project.is_multisig_signer(&executor.key()) @ ProgramErrorCode::NotMultisigSigner,
+    )]
+    project: Account<'info, Project>,
+    #[account(
+        mut,
+        has_one = project,
+    )]
+    proposal: Account<'info, ProjectProposal>,
+    #[account(
+        mut,
+        has_one = project,
+    )]
+    referral_account: Option<Account<'info, ReferralAccount>>,
+    #[account(
+        mut,
+        seeds = [PROJECT_AUTHORITY_SEED, project.base.key().as_ref()],
+        bump
+    )]
+    project_authority: Option<SystemAccount<'info>>,
+    #[account(
+        mut,
+        address = project.admin,
+    )]
+    admin: Option<SystemAccount<'info>>,
+    system_program: Program<'info, System>,
+    #[account(
+        mut,
+        seeds = [MINT_POLICY_SEED, project.key().as_ref()],
+        bump,
+    )]
+    mint_policy: Option<Account<'info, ProjectMintPolicy>>,
+    #[account(
+        mut,
+        seeds = [CLAIM_THRESHOLDS_SEED, project.key().as_ref()],
+        bump,
+    )]
+    claim_thresholds: Option<Account<'info, ProjectClaimThresholds>>,
+    #[account(
+        mut,
+        seeds = [METADATA_SEED, project.key().as_ref()],
+        bump,
+    )]
+    metadata: Option<Account<'info, Metadata>>,
+    #[account(
+        mut,
+        has_one = project,
+    )]
+    rebate_pool: Option<Account<'info, RebatePool>>,
+}
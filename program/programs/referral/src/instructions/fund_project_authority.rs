@@ -0,0 +1,52 @@
+use anchor_lang::{prelude::*, system_program};
+
+use crate::{events::FundProjectAuthorityEvent, Project, PROJECT_AUTHORITY_SEED};
+
+// Deposits SOL that the project authority spends on admin token accounts and sponsored onboarding.
+pub fn fund_project_authority(
+    ctx: Context<FundProjectAuthority>,
+    params: FundProjectAuthorityParams,
+) -> Result<()> {
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.funder.to_account_info(),
+                to: ctx.accounts.project_authority.to_account_info(),
+            },
+        ),
+        params.amount,
+    )?;
+
+    emit_event!(
+        &ctx,
+        FundProjectAuthorityEvent {
+            project: ctx.accounts.project.key(),
+            project_authority: ctx.accounts.project_authority.key(),
+            funder: ctx.accounts.funder.key(),
+            amount: params.amount,
+        }
+    );
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct FundProjectAuthority<'info> {
+    #[account(mut)]
+    funder: Signer<'info>,
+    project: Account<'info, Project>,
+    #[account(
+        mut,
+        seeds = [PROJECT_AUTHORITY_SEED, project.base.key().as_ref()],
+        bump
+    )]
+    project_authority: SystemAccount<'info>,
+    system_program: Program<'info, System>,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Debug, Clone, Default)]
+pub struct FundProjectAuthorityParams {
+    pub amount: u64,
+}
@@ -0,0 +1,72 @@
+use anchor_lang::{prelude::*, solana_program::program::invoke};
+use anchor_spl::{
+    token_2022::{
+        spl_token_2022::{
+            self,
+            extension::{
+                transfer_fee::{instruction::harvest_withheld_tokens_to_mint, TransferFeeAmount},
+                BaseStateWithExtensions, StateWithExtensions,
+            },
+        },
+        Token2022,
+    },
+    token_interface::{Mint, TokenAccount},
+};
+
+use crate::{events::HarvestReferralTokenAccountFeesEvent, ReferralAccount};
+
+// Moves the transfer fees withheld on a Token-2022 referral token account to the mint, where the
+// mint's withdraw authority can collect them. A token account holding withheld fees cannot be closed.
+pub fn harvest_referral_token_account_fees(
+    ctx: Context<HarvestReferralTokenAccountFees>,
+) -> Result<()> {
+    let referral_token_account_info = ctx.accounts.referral_token_account.to_account_info();
+    let withheld_amount = {
+        let data = referral_token_account_info.try_borrow_data()?;
+        let account = StateWithExtensions::<spl_token_2022::state::Account>::unpack(&data)?;
+        account
+            .get_extension::<TransferFeeAmount>()
+            .map(|transfer_fee_amount| u64::from(transfer_fee_amount.withheld_amount))
+            .unwrap_or_default()
+    };
+
+    invoke(
+        &harvest_withheld_tokens_to_mint(
+            ctx.accounts.token_program.key,
+            &ctx.accounts.mint.key(),
+            &[referral_token_account_info.key],
+        )?,
+        &[
+            ctx.accounts.mint.to_account_info(),
+            referral_token_account_info.clone(),
+        ],
+    )?;
+
+    emit_event!(
+        &ctx,
+        HarvestReferralTokenAccountFeesEvent {
+            referral_account: ctx.accounts.referral_account.key(),
+            referral_token_account: referral_token_account_info.key(),
+            mint: ctx.accounts.mint.key(),
+            withheld_amount,
+        }
+    );
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct HarvestReferralTokenAccountFees<'info> {
+    referral_account: Account<'info, ReferralAccount>,
+    #[account(
+        mut,
+        associated_token::authority = referral_account,
+        associated_token::mint = mint,
+        associated_token::token_program = token_program,
+    )]
+    referral_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(mut)]
+    mint: InterfaceAccount<'info, Mint>,
+    token_program: Program<'info, Token2022>,
+}
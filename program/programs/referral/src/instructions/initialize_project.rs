@@ -1,6 +1,10 @@
 use anchor_lang::prelude::*;
 
-use crate::{events::InitializeProjectEvent, ProgramErrorCode, Project, PROJECT_SEED};
+use crate::{
+    events::InitializeProjectEvent, AdminTokenAccountPolicy, AttributionPolicy,
+    MintExtensionPolicy, ProgramErrorCode, Project, PROJECT_RESERVED_LEN, PROJECT_SEED,
+    PROJECT_VERSION,
+};
 
 pub fn initialize_project(
     ctx: Context<InitializeProject>,
@@ -21,18 +25,40 @@ pub fn initialize_project(
         base: ctx.accounts.base.key(),
         name: params.name.clone(),
         default_share_bps: params.default_share_bps,
+        multisig_threshold: 0,
+        multisig_signers: vec![],
+        share_decrease_notice_period: 0,
+        pending_notice_period: None,
+        pending_default_share: None,
+        swap_program: None,
+        mint_extension_policy: MintExtensionPolicy::default(),
+        has_mint_policy: false,
+        has_claim_thresholds: false,
+        keeper_fee_bps: 0,
+        sponsorship_enabled: false,
+        max_sponsored_lamports_per_partner: 0,
+        admin_token_account_policy: AdminTokenAccountPolicy::default(),
+        admin_token_account_spend_epoch: 0,
+        admin_token_account_spent_lamports: 0,
+        version: PROJECT_VERSION,
+        attribution_policy: AttributionPolicy::default(),
+        reserved: [0; PROJECT_RESERVED_LEN],
     });
 
-    emit!(InitializeProjectEvent {
-        project: ctx.accounts.project.key(),
-        admin: ctx.accounts.admin.key(),
-        name: params.name,
-        default_share_bps: params.default_share_bps,
-    });
+    emit_event!(
+        &ctx,
+        InitializeProjectEvent {
+            project: ctx.accounts.project.key(),
+            admin: ctx.accounts.admin.key(),
+            name: params.name,
+            default_share_bps: params.default_share_bps,
+        }
+    );
 
     Ok(())
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct InitializeProject<'info> {
     #[account(mut)]
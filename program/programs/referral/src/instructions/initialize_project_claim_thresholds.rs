@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::InitializeProjectClaimThresholdsEvent, Project, ProjectClaimThresholds,
+    CLAIM_THRESHOLDS_SEED,
+};
+
+// Thresholds start at zero, which accepts every claim, so the admin may create them on a multisig
+// project and leave the actual minimums to proposals.
+pub fn initialize_project_claim_thresholds(
+    ctx: Context<InitializeProjectClaimThresholds>,
+) -> Result<()> {
+    ctx.accounts
+        .claim_thresholds
+        .set_inner(ProjectClaimThresholds {
+            project: ctx.accounts.project.key(),
+            default_min_amount: 0,
+            mints: vec![],
+        });
+    ctx.accounts.project.has_claim_thresholds = true;
+
+    emit_event!(
+        &ctx,
+        InitializeProjectClaimThresholdsEvent {
+            project: ctx.accounts.project.key(),
+            claim_thresholds: ctx.accounts.claim_thresholds.key(),
+        }
+    );
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct InitializeProjectClaimThresholds<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+    admin: Signer<'info>,
+    #[account(
+        mut,
+        has_one = admin,
+    )]
+    project: Account<'info, Project>,
+    #[account(
+        init,
+        payer = payer,
+        seeds = [CLAIM_THRESHOLDS_SEED, project.key().as_ref()],
+        bump,
+        space = ProjectClaimThresholds::LEN
+    )]
+    claim_thresholds: Account<'info, ProjectClaimThresholds>,
+    system_program: Program<'info, System>,
+}
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::{events::InitializeProjectMetadataEvent, Metadata, Project, METADATA_SEED};
+
+// The metadata starts empty, so the admin may create it on a multisig project and leave the
+// content to proposals.
+pub fn initialize_project_metadata(ctx: Context<InitializeProjectMetadata>) -> Result<()> {
+    ctx.accounts.metadata.set_inner(Metadata {
+        target: ctx.accounts.project.key(),
+        uri: String::new(),
+        content_hash: [0; 32],
+    });
+
+    emit_event!(
+        &ctx,
+        InitializeProjectMetadataEvent {
+            project: ctx.accounts.project.key(),
+            metadata: ctx.accounts.metadata.key(),
+        }
+    );
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct InitializeProjectMetadata<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+    admin: Signer<'info>,
+    #[account(has_one = admin)]
+    project: Account<'info, Project>,
+    #[account(
+        init,
+        payer = payer,
+        seeds = [METADATA_SEED, project.key().as_ref()],
+        bump,
+        space = Metadata::LEN
+    )]
+    metadata: Account<'info, Metadata>,
+    system_program: Program<'info, System>,
+}
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::InitializeProjectMintPolicyEvent, MintPolicyMode, Project, ProjectMintPolicy,
+    MINT_POLICY_SEED,
+};
+
+// The policy starts as an empty deny list, which accepts every mint, so the admin may create it on
+// a multisig project and leave the actual restrictions to proposals.
+pub fn initialize_project_mint_policy(ctx: Context<InitializeProjectMintPolicy>) -> Result<()> {
+    ctx.accounts.mint_policy.set_inner(ProjectMintPolicy {
+        project: ctx.accounts.project.key(),
+        mode: MintPolicyMode::Deny,
+        mints: vec![],
+    });
+    ctx.accounts.project.has_mint_policy = true;
+
+    emit_event!(
+        &ctx,
+        InitializeProjectMintPolicyEvent {
+            project: ctx.accounts.project.key(),
+            mint_policy: ctx.accounts.mint_policy.key(),
+        }
+    );
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct InitializeProjectMintPolicy<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+    admin: Signer<'info>,
+    #[account(
+        mut,
+        has_one = admin,
+    )]
+    project: Account<'info, Project>,
+    #[account(
+        init,
+        payer = payer,
+        seeds = [MINT_POLICY_SEED, project.key().as_ref()],
+        bump,
+        space = ProjectMintPolicy::LEN
+    )]
+    mint_policy: Account<'info, ProjectMintPolicy>,
+    system_program: Program<'info, System>,
+}
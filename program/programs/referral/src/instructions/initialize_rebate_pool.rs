@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{
+    events::InitializeRebatePoolEvent, Project, RebatePool, ReferralAccount, REBATE_POOL_SEED,
+};
+
+// Anyone can open the pool of a referral account and mint, it holds nothing until a root is published.
+pub fn initialize_rebate_pool(ctx: Context<InitializeRebatePool>) -> Result<()> {
+    ctx.accounts.rebate_pool.set_inner(RebatePool {
+        project: ctx.accounts.project.key(),
+        referral_account: ctx.accounts.referral_account.key(),
+        mint: ctx.accounts.mint.key(),
+        round: 0,
+        merkle_root: [0; 32],
+    });
+
+    emit_event!(
+        &ctx,
+        InitializeRebatePoolEvent {
+            project: ctx.accounts.project.key(),
+            referral_account: ctx.accounts.referral_account.key(),
+            rebate_pool: ctx.accounts.rebate_pool.key(),
+            rebate_pool_token_account: ctx.accounts.rebate_pool_token_account.key(),
+            mint: ctx.accounts.mint.key(),
+        }
+    );
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct InitializeRebatePool<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+    project: Account<'info, Project>,
+    #[account(has_one = project)]
+    referral_account: Account<'info, ReferralAccount>,
+    #[account(
+        init,
+        payer = payer,
+        seeds = [REBATE_POOL_SEED, referral_account.key().as_ref(), mint.key().as_ref()],
+        bump,
+        space = RebatePool::LEN
+    )]
+    rebate_pool: Account<'info, RebatePool>,
+    #[account(
+        init,
+        payer = payer,
+        associated_token::authority = rebate_pool,
+        associated_token::mint = mint,
+        associated_token::token_program = token_program,
+    )]
+    rebate_pool_token_account: InterfaceAccount<'info, TokenAccount>,
+    mint: InterfaceAccount<'info, Mint>,
+    system_program: Program<'info, System>,
+    token_program: Interface<'info, TokenInterface>,
+    associated_token_program: Program<'info, AssociatedToken>,
+}
@@ -1,31 +1,48 @@
 use anchor_lang::prelude::*;
 
-use crate::{events::InitializeReferralAccountEvent, Project, ReferralAccount, PROJECT_SEED};
+use crate::{
+    events::InitializeReferralAccountEvent, Project, ReferralAccount, PROJECT_SEED,
+    REFERRAL_ACCOUNT_VERSION, REFERRAL_RESERVED_LEN,
+};
 
 pub fn initialize_referral_account(
     ctx: Context<InitializeReferralAccount>,
     _params: InitializeReferralAccountParams,
 ) -> Result<()> {
-    let share_bps = ctx.accounts.project.default_share_bps;
+    let share_bps = ctx
+        .accounts
+        .project
+        .default_share_bps_at(Clock::get()?.unix_timestamp);
 
     ctx.accounts.referral_account.set_inner(ReferralAccount {
         partner: ctx.accounts.partner.key(),
         project: ctx.accounts.project.key(),
         share_bps: share_bps,
         name: None,
+        pending_share: None,
+        inherit_default: false,
+        version: REFERRAL_ACCOUNT_VERSION,
+        display_name: None,
+        rebate_bps: 0,
+        pending_rebate: None,
+        reserved: [0; REFERRAL_RESERVED_LEN],
     });
 
-    emit!(InitializeReferralAccountEvent {
-        project: ctx.accounts.project.key(),
-        partner: ctx.accounts.partner.key(),
-        referral_account: ctx.accounts.referral_account.key(),
-        share_bps: share_bps,
-        name: None
-    });
+    emit_event!(
+        &ctx,
+        InitializeReferralAccountEvent {
+            project: ctx.accounts.project.key(),
+            partner: ctx.accounts.partner.key(),
+            referral_account: ctx.accounts.referral_account.key(),
+            share_bps: share_bps,
+            name: None
+        }
+    );
 
     Ok(())
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct InitializeReferralAccount<'info> {
     #[account(mut)]
@@ -1,39 +1,66 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    events::InitializeReferralAccountEvent, ProgramErrorCode, Project, ReferralAccount,
-    PROJECT_SEED, REFERRAL_SEED,
+    events::{EventCpi, InitializeReferralAccountEvent},
+    ProgramErrorCode, Project, ReferralAccount, PROJECT_SEED, REFERRAL_ACCOUNT_VERSION,
+    REFERRAL_RESERVED_LEN, REFERRAL_SEED,
 };
 
 pub fn initialize_referral_account_with_name(
     ctx: Context<InitializeReferralAccountWithName>,
     params: InitializeReferralAccountWithNameParams,
 ) -> Result<()> {
-    require!(
-        params.name.chars().count() < 20,
-        ProgramErrorCode::NameTooLong
-    );
+    let event_cpi = EventCpi::new(&ctx.accounts.event_authority, ctx.bumps.event_authority);
 
-    let share_bps = ctx.accounts.project.default_share_bps;
+    initialize_named_referral_account(
+        &ctx.accounts.project,
+        &mut ctx.accounts.referral_account,
+        ctx.accounts.partner.key(),
+        params.name,
+        &event_cpi,
+    )
+}
 
-    ctx.accounts.referral_account.set_inner(ReferralAccount {
-        partner: ctx.accounts.partner.key(),
-        project: ctx.accounts.project.key(),
-        share_bps: share_bps,
-        name: Some(params.name.clone()),
-    });
+pub(crate) fn initialize_named_referral_account(
+    project: &Account<Project>,
+    referral_account: &mut Account<ReferralAccount>,
+    partner: Pubkey,
+    name: String,
+    event_cpi: &EventCpi,
+) -> Result<()> {
+    require!(name.chars().count() < 20, ProgramErrorCode::NameTooLong);
+
+    let share_bps = project.default_share_bps_at(Clock::get()?.unix_timestamp);
 
-    emit!(InitializeReferralAccountEvent {
-        project: ctx.accounts.project.key(),
-        partner: ctx.accounts.partner.key(),
-        referral_account: ctx.accounts.referral_account.key(),
+    referral_account.set_inner(ReferralAccount {
+        partner,
+        project: project.key(),
         share_bps: share_bps,
-        name: Some(params.name)
+        name: Some(name.clone()),
+        pending_share: None,
+        inherit_default: false,
+        version: REFERRAL_ACCOUNT_VERSION,
+        display_name: None,
+        rebate_bps: 0,
+        pending_rebate: None,
+        reserved: [0; REFERRAL_RESERVED_LEN],
     });
 
+    emit_event!(
+        event_cpi,
+        InitializeReferralAccountEvent {
+            project: project.key(),
+            partner,
+            referral_account: referral_account.key(),
+            share_bps: share_bps,
+            name: Some(name)
+        }
+    );
+
     Ok(())
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(params: InitializeReferralAccountWithNameParams)]
 pub struct InitializeReferralAccountWithName<'info> {
@@ -2,23 +2,38 @@ use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::{
-    events::InitializeReferralTokenAccountEvent, Project, ReferralAccount, PROJECT_SEED,
-    REFERRAL_ATA_SEED,
+    events::InitializeReferralTokenAccountEvent,
+    utils::{check_mint_extensions, check_mint_policy},
+    Project, ProjectMintPolicy, ReferralAccount, MINT_POLICY_SEED, PROJECT_SEED, REFERRAL_ATA_SEED,
 };
 
 pub fn initialize_referral_token_account(
     ctx: Context<InitializeReferralTokenAccount>,
 ) -> Result<()> {
-    emit!(InitializeReferralTokenAccountEvent {
-        project: ctx.accounts.project.key(),
-        referral_account: ctx.accounts.referral_account.key(),
-        referral_token_account: ctx.accounts.referral_token_account.key(),
-        mint: ctx.accounts.mint.key(),
-    });
+    check_mint_policy(
+        &ctx.accounts.project,
+        ctx.accounts.mint_policy.as_deref(),
+        &ctx.accounts.mint.key(),
+    )?;
+    check_mint_extensions(
+        &ctx.accounts.mint.to_account_info(),
+        &ctx.accounts.project.mint_extension_policy,
+    )?;
+
+    emit_event!(
+        &ctx,
+        InitializeReferralTokenAccountEvent {
+            project: ctx.accounts.project.key(),
+            referral_account: ctx.accounts.referral_account.key(),
+            referral_token_account: ctx.accounts.referral_token_account.key(),
+            mint: ctx.accounts.mint.key(),
+        }
+    );
 
     Ok(())
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct InitializeReferralTokenAccount<'info> {
     #[account(mut)]
@@ -44,4 +59,9 @@ pub struct InitializeReferralTokenAccount<'info> {
     mint: InterfaceAccount<'info, Mint>,
     system_program: Program<'info, System>,
     token_program: Interface<'info, TokenInterface>,
+    #[account(
+        seeds = [MINT_POLICY_SEED, project.key().as_ref()],
+        bump,
+    )]
+    mint_policy: Option<Account<'info, ProjectMintPolicy>>,
 }
@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{
+    events::InitializeReferralTokenAccountEvent,
+    utils::{check_mint_extensions, check_mint_policy},
+    Project, ProjectMintPolicy, ReferralAccount, MINT_POLICY_SEED, PROJECT_SEED, REFERRAL_SEED,
+};
+
+// Opens the referral associated token account that claim_v2 pays out of, for named referral accounts.
+pub fn initialize_referral_token_account_v2(
+    ctx: Context<InitializeReferralTokenAccountV2>,
+) -> Result<()> {
+    check_mint_policy(
+        &ctx.accounts.project,
+        ctx.accounts.mint_policy.as_deref(),
+        &ctx.accounts.mint.key(),
+    )?;
+    check_mint_extensions(
+        &ctx.accounts.mint.to_account_info(),
+        &ctx.accounts.project.mint_extension_policy,
+    )?;
+
+    emit_event!(
+        &ctx,
+        InitializeReferralTokenAccountEvent {
+            project: ctx.accounts.project.key(),
+            referral_account: ctx.accounts.referral_account.key(),
+            referral_token_account: ctx.accounts.referral_token_account.key(),
+            mint: ctx.accounts.mint.key(),
+        }
+    );
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct InitializeReferralTokenAccountV2<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+    #[account(
+        seeds = [PROJECT_SEED, project.base.key().as_ref()],
+        bump,
+    )]
+    project: Account<'info, Project>,
+    #[account(
+        has_one = project,
+        constraint = referral_account.name.is_some(),
+        seeds = [REFERRAL_SEED, project.key().as_ref(), referral_account.name.as_ref().unwrap().as_bytes()],
+        bump
+    )]
+    referral_account: Account<'info, ReferralAccount>,
+    #[account(
+        init,
+        payer = payer,
+        associated_token::authority = referral_account,
+        associated_token::mint = mint,
+        associated_token::token_program = token_program,
+    )]
+    referral_token_account: InterfaceAccount<'info, TokenAccount>,
+    mint: InterfaceAccount<'info, Mint>,
+    system_program: Program<'info, System>,
+    token_program: Interface<'info, TokenInterface>,
+    associated_token_program: Program<'info, AssociatedToken>,
+    #[account(
+        seeds = [MINT_POLICY_SEED, project.key().as_ref()],
+        bump,
+    )]
+    mint_policy: Option<Account<'info, ProjectMintPolicy>>,
+}
@@ -0,0 +1,141 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::{EventCpi, SponsorRentEvent},
+    PartnerSponsorship, ProgramErrorCode, Project, ReferralAccount, PARTNER_SPONSORSHIP_SEED,
+    PROJECT_AUTHORITY_SEED, PROJECT_SEED, REFERRAL_SEED,
+};
+
+use super::{
+    initialize_named_referral_account, withdraw_project_lamports,
+    InitializeReferralAccountWithNameParams,
+};
+
+// Same as initialize_referral_account_with_name, with the rent paid back to the payer by the project
+// authority. The partner signs so that nobody else can spend their sponsorship.
+pub fn initialize_sponsored_referral_account_with_name(
+    ctx: Context<InitializeSponsoredReferralAccountWithName>,
+    params: InitializeReferralAccountWithNameParams,
+) -> Result<()> {
+    let event_cpi = EventCpi::new(&ctx.accounts.event_authority, ctx.bumps.event_authority);
+
+    let accounts = ctx.accounts;
+    initialize_named_referral_account(
+        &accounts.project,
+        &mut accounts.referral_account,
+        accounts.partner.key(),
+        params.name,
+        &event_cpi,
+    )?;
+
+    let lamports = accounts.referral_account.get_lamports();
+    sponsor_rent(
+        &accounts.project,
+        ctx.bumps.project_authority,
+        accounts.project_authority.to_account_info(),
+        &mut accounts.partner_sponsorship,
+        accounts.partner.key(),
+        accounts.payer.to_account_info(),
+        accounts.system_program.to_account_info(),
+        lamports,
+        &event_cpi,
+    )
+}
+
+// Pays the payer back for rent it fronted on behalf of the partner, counted against the partner's cap
+// together with the rent of the partner sponsorship account itself when it was just created.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn sponsor_rent<'info>(
+    project: &Account<'info, Project>,
+    project_authority_bump: u8,
+    project_authority: AccountInfo<'info>,
+    partner_sponsorship: &mut Account<'info, PartnerSponsorship>,
+    partner: Pubkey,
+    payer: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+    lamports: u64,
+    event_cpi: &EventCpi,
+) -> Result<()> {
+    require!(
+        project.sponsorship_enabled,
+        ProgramErrorCode::SponsorshipDisabled
+    );
+
+    let mut lamports = lamports;
+    if partner_sponsorship.partner == Pubkey::default() {
+        partner_sponsorship.project = project.key();
+        partner_sponsorship.partner = partner;
+        lamports = lamports
+            .checked_add(partner_sponsorship.get_lamports())
+            .ok_or(ProgramErrorCode::InvalidCalculation)?;
+    }
+
+    let sponsored_lamports = partner_sponsorship
+        .sponsored_lamports
+        .checked_add(lamports)
+        .ok_or(ProgramErrorCode::InvalidCalculation)?;
+    require!(
+        sponsored_lamports <= project.max_sponsored_lamports_per_partner,
+        ProgramErrorCode::SponsorshipCapExceeded
+    );
+    partner_sponsorship.sponsored_lamports = sponsored_lamports;
+
+    withdraw_project_lamports(
+        project,
+        project_authority_bump,
+        project_authority,
+        payer.clone(),
+        system_program,
+        lamports,
+    )?;
+
+    emit_event!(
+        event_cpi,
+        SponsorRentEvent {
+            project: project.key(),
+            partner,
+            payer: payer.key(),
+            lamports,
+            sponsored_lamports,
+        }
+    );
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(params: InitializeReferralAccountWithNameParams)]
+pub struct InitializeSponsoredReferralAccountWithName<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+    partner: Signer<'info>,
+    #[account(
+        seeds = [PROJECT_SEED, project.base.key().as_ref()],
+        bump,
+    )]
+    project: Account<'info, Project>,
+    #[account(
+        mut,
+        seeds = [PROJECT_AUTHORITY_SEED, project.base.key().as_ref()],
+        bump
+    )]
+    project_authority: SystemAccount<'info>,
+    #[account(
+        init,
+        payer = payer,
+        seeds = [REFERRAL_SEED, project.key().as_ref(), params.name.as_ref()],
+        bump,
+        space = ReferralAccount::LEN
+    )]
+    referral_account: Account<'info, ReferralAccount>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        seeds = [PARTNER_SPONSORSHIP_SEED, project.key().as_ref(), partner.key().as_ref()],
+        bump,
+        space = PartnerSponsorship::LEN
+    )]
+    partner_sponsorship: Account<'info, PartnerSponsorship>,
+    system_program: Program<'info, System>,
+}
@@ -0,0 +1,110 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{
+    events::{EventCpi, InitializeReferralTokenAccountEvent},
+    utils::{check_mint_extensions, check_mint_policy},
+    PartnerSponsorship, Project, ProjectMintPolicy, ReferralAccount, MINT_POLICY_SEED,
+    PARTNER_SPONSORSHIP_SEED, PROJECT_AUTHORITY_SEED, PROJECT_SEED, REFERRAL_SEED,
+};
+
+use super::sponsor_rent;
+
+// Same as initialize_referral_token_account_v2, with the rent paid back to the payer by the project
+// authority.
+pub fn initialize_sponsored_referral_token_account(
+    ctx: Context<InitializeSponsoredReferralTokenAccount>,
+) -> Result<()> {
+    let event_cpi = EventCpi::new(&ctx.accounts.event_authority, ctx.bumps.event_authority);
+
+    let accounts = ctx.accounts;
+    check_mint_policy(
+        &accounts.project,
+        accounts.mint_policy.as_deref(),
+        &accounts.mint.key(),
+    )?;
+    check_mint_extensions(
+        &accounts.mint.to_account_info(),
+        &accounts.project.mint_extension_policy,
+    )?;
+
+    let lamports = accounts.referral_token_account.get_lamports();
+    sponsor_rent(
+        &accounts.project,
+        ctx.bumps.project_authority,
+        accounts.project_authority.to_account_info(),
+        &mut accounts.partner_sponsorship,
+        accounts.partner.key(),
+        accounts.payer.to_account_info(),
+        accounts.system_program.to_account_info(),
+        lamports,
+        &event_cpi,
+    )?;
+
+    emit_event!(
+        &event_cpi,
+        InitializeReferralTokenAccountEvent {
+            project: accounts.project.key(),
+            referral_account: accounts.referral_account.key(),
+            referral_token_account: accounts.referral_token_account.key(),
+            mint: accounts.mint.key(),
+        }
+    );
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct InitializeSponsoredReferralTokenAccount<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+    partner: Signer<'info>,
+    #[account(
+        seeds = [PROJECT_SEED, project.base.key().as_ref()],
+        bump,
+    )]
+    project: Account<'info, Project>,
+    #[account(
+        mut,
+        seeds = [PROJECT_AUTHORITY_SEED, project.base.key().as_ref()],
+        bump
+    )]
+    project_authority: SystemAccount<'info>,
+    #[account(
+        has_one = project,
+        has_one = partner,
+        constraint = referral_account.name.is_some(),
+        seeds = [REFERRAL_SEED, project.key().as_ref(), referral_account.name.as_ref().unwrap().as_bytes()],
+        bump
+    )]
+    referral_account: Account<'info, ReferralAccount>,
+    #[account(
+        init,
+        payer = payer,
+        associated_token::authority = referral_account,
+        associated_token::mint = mint,
+        associated_token::token_program = token_program,
+    )]
+    referral_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        seeds = [PARTNER_SPONSORSHIP_SEED, project.key().as_ref(), partner.key().as_ref()],
+        bump,
+        space = PartnerSponsorship::LEN
+    )]
+    partner_sponsorship: Account<'info, PartnerSponsorship>,
+    mint: InterfaceAccount<'info, Mint>,
+    system_program: Program<'info, System>,
+    token_program: Interface<'info, TokenInterface>,
+    associated_token_program: Program<'info, AssociatedToken>,
+    #[account(
+        seeds = [MINT_POLICY_SEED, project.key().as_ref()],
+        bump,
+    )]
+    mint_policy: Option<Account<'info, ProjectMintPolicy>>,
+}
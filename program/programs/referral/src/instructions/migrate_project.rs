@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::MigrateProjectEvent,
+    utils::{clear_legacy_padding, realloc_account},
+    AdminTokenAccountPolicy, Project, PROJECT_VERSION,
+};
+
+// The project layout from before accounts were versioned.
+#[derive(AnchorDeserialize, AnchorSerialize)]
+struct LegacyProject {
+    base: Pubkey,
+    admin: Pubkey,
+    name: String,
+    default_share_bps: u16,
+}
+
+// Accounts created before the current layout can be too short to deserialize, so the project is
+// grown first and only then read and checked.
+pub fn migrate_project(ctx: Context<MigrateProject>) -> Result<()> {
+    let project_info = ctx.accounts.project.to_account_info();
+    let legacy = project_info.data_len() == Project::LEGACY_LEN;
+    realloc_account(
+        &project_info,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        Project::LEN,
+    )?;
+    if legacy {
+        clear_legacy_padding::<LegacyProject>(&project_info)?;
+    }
+
+    let mut project = Project::try_deserialize(&mut &project_info.try_borrow_data()?[..])?;
+    require_keys_eq!(
+        project.admin,
+        ctx.accounts.admin.key(),
+        ErrorCode::ConstraintHasOne
+    );
+
+    let previous_version = project.version;
+    // Fields appended since the account was created read the zeroed realloc and legacy padding
+    // bytes and fields carved out of the reserved bytes read zeros as well, which is the initial
+    // value of each of them except for the admin token account budget.
+    if previous_version == 0 {
+        project.admin_token_account_policy = AdminTokenAccountPolicy::default();
+    }
+    project.version = PROJECT_VERSION;
+    project.try_serialize(&mut &mut project_info.try_borrow_mut_data()?[..])?;
+
+    emit_event!(
+        &ctx,
+        MigrateProjectEvent {
+            project: project_info.key(),
+            previous_version,
+            version: PROJECT_VERSION,
+        }
+    );
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct MigrateProject<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+    admin: Signer<'info>,
+    /// CHECK: Deserialized as a project once it has been grown to the current layout.
+    #[account(mut, owner = crate::ID)]
+    project: UncheckedAccount<'info>,
+    system_program: Program<'info, System>,
+}
@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::MigrateReferralAccountEvent,
+    utils::{clear_legacy_padding, realloc_account},
+    ReferralAccount, REFERRAL_ACCOUNT_VERSION,
+};
+
+// The referral account layout from before accounts were versioned.
+#[derive(AnchorDeserialize, AnchorSerialize)]
+struct LegacyReferralAccount {
+    partner: Pubkey,
+    project: Pubkey,
+    share_bps: u16,
+    name: Option<String>,
+}
+
+// Anyone can migrate a referral account, it only changes the layout and the payer covers the rent.
+pub fn migrate_referral_account(ctx: Context<MigrateReferralAccount>) -> Result<()> {
+    let referral_account_info = ctx.accounts.referral_account.to_account_info();
+    let legacy = referral_account_info.data_len() == ReferralAccount::LEGACY_LEN;
+    realloc_account(
+        &referral_account_info,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        ReferralAccount::LEN,
+    )?;
+    if legacy {
+        clear_legacy_padding::<LegacyReferralAccount>(&referral_account_info)?;
+    }
+
+    let mut referral_account =
+        ReferralAccount::try_deserialize(&mut &referral_account_info.try_borrow_data()?[..])?;
+
+    // Nothing is converted per version: fields appended since the account was created read the
+    // zeroed realloc and legacy padding bytes and fields carved out of the reserved bytes read
+    // zeros as well, which is the initial value of each of them.
+    let previous_version = referral_account.version;
+    referral_account.version = REFERRAL_ACCOUNT_VERSION;
+    referral_account.try_serialize(&mut &mut referral_account_info.try_borrow_mut_data()?[..])?;
+
+    emit_event!(
+        &ctx,
+        MigrateReferralAccountEvent {
+            project: referral_account.project,
+            referral_account: referral_account_info.key(),
+            previous_version,
+            version: REFERRAL_ACCOUNT_VERSION,
+        }
+    );
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct MigrateReferralAccount<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+    /// CHECK: Deserialized as a referral account once it has been grown to the current layout.
+    #[account(mut, owner = crate::ID)]
+    referral_account: UncheckedAccount<'info>,
+    system_program: Program<'info, System>,
+}
@@ -1,29 +1,121 @@
+pub mod add_project_policy_mint;
+pub mod approve_project_proposal;
+pub mod attribute_user;
 pub mod claim;
+pub mod claim_amount;
+pub mod claim_amount_v2;
+pub mod claim_and_swap;
+pub mod claim_many_v2;
+pub mod claim_native_v2;
+pub mod claim_rebate;
 pub mod claim_v2;
+pub mod close_project_proposal;
 pub mod close_referral_token_account;
 pub mod close_referral_token_account_v2;
+pub mod collect_fee;
 pub mod create_admin_token_account;
+pub mod create_project_proposal;
+pub mod create_referral_alias;
+pub mod delete_referral_alias;
+pub mod execute_project_proposal;
+pub mod fund_project_authority;
+pub mod harvest_referral_token_account_fees;
 pub mod initialize_project;
+pub mod initialize_project_claim_thresholds;
+pub mod initialize_project_metadata;
+pub mod initialize_project_mint_policy;
+pub mod initialize_rebate_pool;
 pub mod initialize_referral_account;
 pub mod initialize_referral_account_with_name;
 pub mod initialize_referral_token_account;
+pub mod initialize_referral_token_account_v2;
+pub mod initialize_sponsored_referral_account_with_name;
+pub mod initialize_sponsored_referral_token_account;
+pub mod migrate_project;
+pub mod migrate_referral_account;
+pub mod publish_rebate_root;
+pub mod remove_project_policy_mint;
+pub mod resolve_referral_alias;
+pub mod resolve_user_attribution;
+pub mod set_project_admin_token_account_policy;
+pub mod set_project_attribution_policy;
+pub mod set_project_keeper_fee;
+pub mod set_project_max_keeper_fee;
+pub mod set_project_metadata;
+pub mod set_project_min_claim_amount;
+pub mod set_project_mint_extension_policy;
+pub mod set_project_mint_policy_mode;
+pub mod set_project_multisig;
+pub mod set_project_sponsorship;
+pub mod set_project_swap_program;
+pub mod set_referral_account_display_name;
+pub mod set_referral_account_metadata;
+pub mod set_referral_account_rebate;
+pub mod set_referral_account_share_mode;
 pub mod transfer_project;
 pub mod transfer_referral_account;
 pub mod update_project;
 pub mod update_referral_account;
+pub mod update_referral_accounts;
 pub mod withdraw_from_project;
 
+pub use add_project_policy_mint::*;
+pub use approve_project_proposal::*;
+pub use attribute_user::*;
 pub use claim::*;
+pub use claim_amount::*;
+pub use claim_amount_v2::*;
+pub use claim_and_swap::*;
+pub use claim_many_v2::*;
+pub use claim_native_v2::*;
+pub use claim_rebate::*;
 pub use claim_v2::*;
+pub use close_project_proposal::*;
 pub use close_referral_token_account::*;
 pub use close_referral_token_account_v2::*;
+pub use collect_fee::*;
 pub use create_admin_token_account::*;
+pub use create_project_proposal::*;
+pub use create_referral_alias::*;
+pub use delete_referral_alias::*;
+pub use execute_project_proposal::*;
+pub use fund_project_authority::*;
+pub use harvest_referral_token_account_fees::*;
 pub use initialize_project::*;
+pub use initialize_project_claim_thresholds::*;
+pub use initialize_project_metadata::*;
+pub use initialize_project_mint_policy::*;
+pub use initialize_rebate_pool::*;
 pub use initialize_referral_account::*;
 pub use initialize_referral_account_with_name::*;
 pub use initialize_referral_token_account::*;
+pub use initialize_referral_token_account_v2::*;
+pub use initialize_sponsored_referral_account_with_name::*;
+pub use initialize_sponsored_referral_token_account::*;
+pub use migrate_project::*;
+pub use migrate_referral_account::*;
+pub use publish_rebate_root::*;
+pub use remove_project_policy_mint::*;
+pub use resolve_referral_alias::*;
+pub use resolve_user_attribution::*;
+pub use set_project_admin_token_account_policy::*;
+pub use set_project_attribution_policy::*;
+pub use set_project_keeper_fee::*;
+pub use set_project_max_keeper_fee::*;
+pub use set_project_metadata::*;
+pub use set_project_min_claim_amount::*;
+pub use set_project_mint_extension_policy::*;
+pub use set_project_mint_policy_mode::*;
+pub use set_project_multisig::*;
+pub use set_project_sponsorship::*;
+pub use set_project_swap_program::*;
+pub use set_referral_account_display_name::*;
+pub use set_referral_account_metadata::*;
+pub use set_referral_account_rebate::*;
+pub use set_referral_account_share_mode::*;
 pub use transfer_project::*;
 pub use transfer_referral_account::*;
 pub use update_project::*;
 pub use update_referral_account::*;
+pub use update_referral_accounts::*;
 pub use withdraw_from_project::*;
@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::{EventCpi, PublishRebateRootEvent},
+    ProgramErrorCode, Project, RebatePool,
+};
+
+pub fn publish_rebate_root(
+    ctx: Context<PublishRebateRoot>,
+    params: PublishRebateRootParams,
+) -> Result<()> {
+    let event_cpi = EventCpi::new(&ctx.accounts.event_authority, ctx.bumps.event_authority);
+
+    apply_publish_rebate_root(
+        &mut ctx.accounts.rebate_pool,
+        params.merkle_root,
+        &event_cpi,
+    )
+}
+
+// Each root starts a new round. Its tree covers what traders have not claimed yet, claims of the
+// previous round can no longer be made.
+pub(crate) fn apply_publish_rebate_root(
+    rebate_pool: &mut Account<RebatePool>,
+    merkle_root: [u8; 32],
+    event_cpi: &EventCpi,
+) -> Result<()> {
+    rebate_pool.round = rebate_pool
+        .round
+        .checked_add(1)
+        .ok_or(ProgramErrorCode::InvalidCalculation)?;
+    rebate_pool.merkle_root = merkle_root;
+
+    emit_event!(
+        event_cpi,
+        PublishRebateRootEvent {
+            project: rebate_pool.project,
+            rebate_pool: rebate_pool.key(),
+            round: rebate_pool.round,
+            merkle_root,
+        }
+    );
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct PublishRebateRoot<'info> {
+    admin: Signer<'info>,
+    #[account(
+        has_one = admin,
+        constraint = !project.has_multisig() @ ProgramErrorCode::MultisigRequired,
+    )]
+    project: Account<'info, Project>,
+    #[account(
+        mut,
+        has_one = project
+    )]
+    rebate_pool: Account<'info, RebatePool>,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Debug, Clone, Default)]
+pub struct PublishRebateRootParams {
+    pub merkle_root: [u8; 32],
+}
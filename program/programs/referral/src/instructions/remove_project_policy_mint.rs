@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::{EventCpi, RemoveProjectPolicyMintEvent},
+    ProgramErrorCode, Project, ProjectMintPolicy,
+};
+
+use super::{ProjectPolicyMintParams, UpdateProjectMintPolicy};
+
+pub fn remove_project_policy_mint(
+    ctx: Context<UpdateProjectMintPolicy>,
+    params: ProjectPolicyMintParams,
+) -> Result<()> {
+    let event_cpi = EventCpi::new(&ctx.accounts.event_authority, ctx.bumps.event_authority);
+
+    apply_remove_project_policy_mint(
+        &ctx.accounts.project,
+        &mut ctx.accounts.mint_policy,
+        params,
+        &event_cpi,
+    )
+}
+
+pub(crate) fn apply_remove_project_policy_mint(
+    project: &Account<Project>,
+    mint_policy: &mut Account<ProjectMintPolicy>,
+    params: ProjectPolicyMintParams,
+    event_cpi: &EventCpi,
+) -> Result<()> {
+    let index = mint_policy
+        .mints
+        .iter()
+        .position(|mint| *mint == params.mint)
+        .ok_or(ProgramErrorCode::MintNotInPolicy)?;
+    mint_policy.mints.swap_remove(index);
+
+    emit_event!(
+        event_cpi,
+        RemoveProjectPolicyMintEvent {
+            project: project.key(),
+            mint: params.mint,
+        }
+    );
+
+    Ok(())
+}
@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+
+use crate::{Alias, ALIAS_SEED};
+
+// Returns the referral account an alias credits through return data, so integrators can look it up
+// by CPI before routing fees.
+pub fn resolve_referral_alias(ctx: Context<ResolveReferralAlias>) -> Result<Pubkey> {
+    Ok(ctx.accounts.alias.referral_account)
+}
+
+#[derive(Accounts)]
+pub struct ResolveReferralAlias<'info> {
+    #[account(
+        seeds = [ALIAS_SEED, alias.project.as_ref(), alias.alias.as_bytes()],
+        bump,
+    )]
+    alias: Account<'info, Alias>,
+}
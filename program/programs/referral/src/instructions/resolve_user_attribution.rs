@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+use crate::{Project, UserAttribution, USER_ATTRIBUTION_SEED};
+
+// Returns the referral account to credit for the user through return data, none once the
+// attribution has expired, so integrators can pick the referral token account by CPI.
+pub fn resolve_user_attribution(ctx: Context<ResolveUserAttribution>) -> Result<Option<Pubkey>> {
+    let user_attribution = &ctx.accounts.user_attribution;
+    let now = Clock::get()?.unix_timestamp;
+
+    Ok(user_attribution
+        .is_active(&ctx.accounts.project.attribution_policy, now)
+        .then_some(user_attribution.referral_account))
+}
+
+#[derive(Accounts)]
+pub struct ResolveUserAttribution<'info> {
+    project: Account<'info, Project>,
+    /// CHECK: Only used as a seed, so that an attribution of another user can't be passed
+    user: UncheckedAccount<'info>,
+    #[account(
+        seeds = [USER_ATTRIBUTION_SEED, project.key().as_ref(), user.key().as_ref()],
+        bump,
+    )]
+    user_attribution: Account<'info, UserAttribution>,
+}
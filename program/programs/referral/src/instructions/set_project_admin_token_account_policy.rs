@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::{EventCpi, SetProjectAdminTokenAccountPolicyEvent},
+    AdminTokenAccountPolicy, ProgramErrorCode, Project,
+};
+
+pub fn set_project_admin_token_account_policy(
+    ctx: Context<SetProjectAdminTokenAccountPolicy>,
+    params: SetProjectAdminTokenAccountPolicyParams,
+) -> Result<()> {
+    let event_cpi = EventCpi::new(&ctx.accounts.event_authority, ctx.bumps.event_authority);
+
+    apply_set_project_admin_token_account_policy(&mut ctx.accounts.project, params, &event_cpi)
+}
+
+pub(crate) fn apply_set_project_admin_token_account_policy(
+    project: &mut Account<Project>,
+    params: SetProjectAdminTokenAccountPolicyParams,
+    event_cpi: &EventCpi,
+) -> Result<()> {
+    require!(
+        params.policy.min_referral_balance == 0 || params.policy.require_allowed_mint,
+        ProgramErrorCode::MintAllowlistRequired
+    );
+
+    project.admin_token_account_policy = params.policy;
+
+    emit_event!(
+        event_cpi,
+        SetProjectAdminTokenAccountPolicyEvent {
+            project: project.key(),
+            policy: params.policy,
+        }
+    );
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetProjectAdminTokenAccountPolicy<'info> {
+    admin: Signer<'info>,
+    #[account(
+        mut,
+        has_one = admin,
+        constraint = !project.has_multisig() @ ProgramErrorCode::MultisigRequired,
+    )]
+    project: Account<'info, Project>,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Debug, Clone, Default)]
+pub struct SetProjectAdminTokenAccountPolicyParams {
+    pub policy: AdminTokenAccountPolicy,
+}
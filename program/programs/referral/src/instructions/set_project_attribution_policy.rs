@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::{EventCpi, SetProjectAttributionPolicyEvent},
+    AttributionPolicy, ProgramErrorCode, Project,
+};
+
+pub fn set_project_attribution_policy(
+    ctx: Context<SetProjectAttributionPolicy>,
+    params: SetProjectAttributionPolicyParams,
+) -> Result<()> {
+    let event_cpi = EventCpi::new(&ctx.accounts.event_authority, ctx.bumps.event_authority);
+
+    apply_set_project_attribution_policy(&mut ctx.accounts.project, params, &event_cpi)
+}
+
+pub(crate) fn apply_set_project_attribution_policy(
+    project: &mut Account<Project>,
+    params: SetProjectAttributionPolicyParams,
+    event_cpi: &EventCpi,
+) -> Result<()> {
+    require!(
+        params.policy.expiry >= 0,
+        ProgramErrorCode::InvalidAttributionExpiry
+    );
+
+    project.attribution_policy = params.policy;
+
+    emit_event!(
+        event_cpi,
+        SetProjectAttributionPolicyEvent {
+            project: project.key(),
+            policy: params.policy,
+        }
+    );
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetProjectAttributionPolicy<'info> {
+    admin: Signer<'info>,
+    #[account(
+        mut,
+        has_one = admin,
+        constraint = !project.has_multisig() @ ProgramErrorCode::MultisigRequired,
+    )]
+    project: Account<'info, Project>,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Debug, Clone, Default)]
+pub struct SetProjectAttributionPolicyParams {
+    pub policy: AttributionPolicy,
+}
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::{EventCpi, SetProjectKeeperFeeEvent},
+    ProgramErrorCode, Project, MAX_KEEPER_FEE_BPS,
+};
+
+pub fn set_project_keeper_fee(
+    ctx: Context<SetProjectKeeperFee>,
+    params: SetProjectKeeperFeeParams,
+) -> Result<()> {
+    let event_cpi = EventCpi::new(&ctx.accounts.event_authority, ctx.bumps.event_authority);
+
+    apply_set_project_keeper_fee(&mut ctx.accounts.project, params, &event_cpi)
+}
+
+pub(crate) fn apply_set_project_keeper_fee(
+    project: &mut Account<Project>,
+    params: SetProjectKeeperFeeParams,
+    event_cpi: &EventCpi,
+) -> Result<()> {
+    require!(
+        params.keeper_fee_bps <= MAX_KEEPER_FEE_BPS,
+        ProgramErrorCode::InvalidKeeperFee
+    );
+
+    project.keeper_fee_bps = params.keeper_fee_bps;
+
+    emit_event!(
+        event_cpi,
+        SetProjectKeeperFeeEvent {
+            project: project.key(),
+            keeper_fee_bps: params.keeper_fee_bps,
+        }
+    );
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetProjectKeeperFee<'info> {
+    admin: Signer<'info>,
+    #[account(
+        mut,
+        has_one = admin,
+        constraint = !project.has_multisig() @ ProgramErrorCode::MultisigRequired,
+    )]
+    project: Account<'info, Project>,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Debug, Clone, Default)]
+pub struct SetProjectKeeperFeeParams {
+    // The fee of a mint is capped by its entry in the claim thresholds, see set_project_max_keeper_fee.
+    pub keeper_fee_bps: u16,
+}
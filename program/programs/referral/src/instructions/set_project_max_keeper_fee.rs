@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::{EventCpi, SetProjectMaxKeeperFeeEvent},
+    ProgramErrorCode, Project, ProjectClaimThresholds, CLAIM_THRESHOLDS_SEED,
+};
+
+pub fn set_project_max_keeper_fee(
+    ctx: Context<SetProjectMaxKeeperFee>,
+    params: SetProjectMaxKeeperFeeParams,
+) -> Result<()> {
+    let event_cpi = EventCpi::new(&ctx.accounts.event_authority, ctx.bumps.event_authority);
+
+    apply_set_project_max_keeper_fee(
+        &ctx.accounts.project,
+        &mut ctx.accounts.claim_thresholds,
+        params,
+        &event_cpi,
+    )
+}
+
+pub(crate) fn apply_set_project_max_keeper_fee(
+    project: &Account<Project>,
+    claim_thresholds: &mut Account<ProjectClaimThresholds>,
+    params: SetProjectMaxKeeperFeeParams,
+    event_cpi: &EventCpi,
+) -> Result<()> {
+    claim_thresholds.update_mint_threshold(params.mint, |threshold| {
+        threshold.max_keeper_fee = params.max_keeper_fee;
+    })?;
+
+    emit_event!(
+        event_cpi,
+        SetProjectMaxKeeperFeeEvent {
+            project: project.key(),
+            mint: params.mint,
+            max_keeper_fee: params.max_keeper_fee,
+        }
+    );
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetProjectMaxKeeperFee<'info> {
+    admin: Signer<'info>,
+    #[account(
+        has_one = admin,
+        constraint = !project.has_multisig() @ ProgramErrorCode::MultisigRequired,
+    )]
+    project: Account<'info, Project>,
+    #[account(
+        mut,
+        seeds = [CLAIM_THRESHOLDS_SEED, project.key().as_ref()],
+        bump,
+    )]
+    claim_thresholds: Account<'info, ProjectClaimThresholds>,
+}
+
+// A zero max_keeper_fee stops paying keepers in the mint.
+#[derive(AnchorDeserialize, AnchorSerialize, Debug, Clone, Default)]
+pub struct SetProjectMaxKeeperFeeParams {
+    pub mint: Pubkey,
+    // Upper bound on the keeper fee of a single claim, in the mint's base units.
+    pub max_keeper_fee: u64,
+}
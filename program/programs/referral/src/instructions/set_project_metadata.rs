@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::{EventCpi, SetProjectMetadataEvent},
+    Metadata, ProgramErrorCode, Project, METADATA_SEED,
+};
+
+pub fn set_project_metadata(
+    ctx: Context<SetProjectMetadata>,
+    params: SetMetadataParams,
+) -> Result<()> {
+    let event_cpi = EventCpi::new(&ctx.accounts.event_authority, ctx.bumps.event_authority);
+
+    apply_set_project_metadata(
+        &ctx.accounts.project,
+        &mut ctx.accounts.metadata,
+        params,
+        &event_cpi,
+    )
+}
+
+pub(crate) fn apply_set_project_metadata(
+    project: &Account<Project>,
+    metadata: &mut Account<Metadata>,
+    params: SetMetadataParams,
+    event_cpi: &EventCpi,
+) -> Result<()> {
+    metadata.set(project.key(), params)?;
+
+    emit_event!(
+        event_cpi,
+        SetProjectMetadataEvent {
+            project: project.key(),
+            metadata: metadata.key(),
+            uri: metadata.uri.clone(),
+            content_hash: metadata.content_hash,
+        }
+    );
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetProjectMetadata<'info> {
+    admin: Signer<'info>,
+    #[account(
+        has_one = admin,
+        constraint = !project.has_multisig() @ ProgramErrorCode::MultisigRequired,
+    )]
+    project: Account<'info, Project>,
+    #[account(
+        mut,
+        seeds = [METADATA_SEED, project.key().as_ref()],
+        bump,
+    )]
+    metadata: Account<'info, Metadata>,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Debug, Clone, Default)]
+pub struct SetMetadataParams {
+    pub uri: String,
+    pub content_hash: [u8; 32],
+}
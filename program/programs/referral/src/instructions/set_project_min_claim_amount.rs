@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::{EventCpi, SetProjectMinClaimAmountEvent},
+    ProgramErrorCode, Project, ProjectClaimThresholds, CLAIM_THRESHOLDS_SEED,
+};
+
+pub fn set_project_min_claim_amount(
+    ctx: Context<SetProjectMinClaimAmount>,
+    params: SetProjectMinClaimAmountParams,
+) -> Result<()> {
+    let event_cpi = EventCpi::new(&ctx.accounts.event_authority, ctx.bumps.event_authority);
+
+    apply_set_project_min_claim_amount(
+        &ctx.accounts.project,
+        &mut ctx.accounts.claim_thresholds,
+        params,
+        &event_cpi,
+    )
+}
+
+pub(crate) fn apply_set_project_min_claim_amount(
+    project: &Account<Project>,
+    claim_thresholds: &mut Account<ProjectClaimThresholds>,
+    params: SetProjectMinClaimAmountParams,
+    event_cpi: &EventCpi,
+) -> Result<()> {
+    match params.mint {
+        None => claim_thresholds.default_min_amount = params.min_amount.unwrap_or_default(),
+        Some(mint) => claim_thresholds.update_mint_threshold(mint, |threshold| {
+            threshold.min_amount = params.min_amount;
+        })?,
+    }
+
+    emit_event!(
+        event_cpi,
+        SetProjectMinClaimAmountEvent {
+            project: project.key(),
+            mint: params.mint,
+            min_amount: params.min_amount,
+        }
+    );
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetProjectMinClaimAmount<'info> {
+    admin: Signer<'info>,
+    #[account(
+        has_one = admin,
+        constraint = !project.has_multisig() @ ProgramErrorCode::MultisigRequired,
+    )]
+    project: Account<'info, Project>,
+    #[account(
+        mut,
+        seeds = [CLAIM_THRESHOLDS_SEED, project.key().as_ref()],
+        bump,
+    )]
+    claim_thresholds: Account<'info, ProjectClaimThresholds>,
+}
+
+// Without a mint the default minimum is set, with a mint a None min_amount drops the mint back to
+// the default minimum.
+#[derive(AnchorDeserialize, AnchorSerialize, Debug, Clone, Default)]
+pub struct SetProjectMinClaimAmountParams {
+    pub mint: Option<Pubkey>,
+    pub min_amount: Option<u64>,
+}
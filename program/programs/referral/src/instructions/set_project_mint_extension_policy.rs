@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::{EventCpi, SetProjectMintExtensionPolicyEvent},
+    MintExtensionPolicy, ProgramErrorCode, Project,
+};
+
+pub fn set_project_mint_extension_policy(
+    ctx: Context<SetProjectMintExtensionPolicy>,
+    params: SetProjectMintExtensionPolicyParams,
+) -> Result<()> {
+    let event_cpi = EventCpi::new(&ctx.accounts.event_authority, ctx.bumps.event_authority);
+
+    apply_set_project_mint_extension_policy(&mut ctx.accounts.project, params, &event_cpi)
+}
+
+pub(crate) fn apply_set_project_mint_extension_policy(
+    project: &mut Account<Project>,
+    params: SetProjectMintExtensionPolicyParams,
+    event_cpi: &EventCpi,
+) -> Result<()> {
+    project.mint_extension_policy = params.policy;
+
+    emit_event!(
+        event_cpi,
+        SetProjectMintExtensionPolicyEvent {
+            project: project.key(),
+            policy: params.policy,
+        }
+    );
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetProjectMintExtensionPolicy<'info> {
+    admin: Signer<'info>,
+    #[account(
+        mut,
+        has_one = admin,
+        constraint = !project.has_multisig() @ ProgramErrorCode::MultisigRequired,
+    )]
+    project: Account<'info, Project>,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Debug, Clone, Default)]
+pub struct SetProjectMintExtensionPolicyParams {
+    pub policy: MintExtensionPolicy,
+}
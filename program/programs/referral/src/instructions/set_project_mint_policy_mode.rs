@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::{EventCpi, SetProjectMintPolicyModeEvent},
+    MintPolicyMode, ProgramErrorCode, Project, ProjectMintPolicy, MINT_POLICY_SEED,
+};
+
+pub fn set_project_mint_policy_mode(
+    ctx: Context<UpdateProjectMintPolicy>,
+    params: SetProjectMintPolicyModeParams,
+) -> Result<()> {
+    let event_cpi = EventCpi::new(&ctx.accounts.event_authority, ctx.bumps.event_authority);
+
+    apply_set_project_mint_policy_mode(
+        &ctx.accounts.project,
+        &mut ctx.accounts.mint_policy,
+        params,
+        &event_cpi,
+    )
+}
+
+pub(crate) fn apply_set_project_mint_policy_mode(
+    project: &Account<Project>,
+    mint_policy: &mut Account<ProjectMintPolicy>,
+    params: SetProjectMintPolicyModeParams,
+    event_cpi: &EventCpi,
+) -> Result<()> {
+    mint_policy.mode = params.mode;
+
+    emit_event!(
+        event_cpi,
+        SetProjectMintPolicyModeEvent {
+            project: project.key(),
+            mode: params.mode,
+        }
+    );
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateProjectMintPolicy<'info> {
+    admin: Signer<'info>,
+    #[account(
+        has_one = admin,
+        constraint = !project.has_multisig() @ ProgramErrorCode::MultisigRequired,
+    )]
+    pub(crate) project: Account<'info, Project>,
+    #[account(
+        mut,
+        seeds = [MINT_POLICY_SEED, project.key().as_ref()],
+        bump,
+    )]
+    pub(crate) mint_policy: Account<'info, ProjectMintPolicy>,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Debug, Clone)]
+pub struct SetProjectMintPolicyModeParams {
+    pub mode: MintPolicyMode,
+}
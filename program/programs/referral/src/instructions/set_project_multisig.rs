@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::{EventCpi, SetProjectMultisigEvent},
+    ProgramErrorCode, Project, MAX_MULTISIG_SIGNERS,
+};
+
+pub fn set_project_multisig(
+    ctx: Context<SetProjectMultisig>,
+    params: SetProjectMultisigParams,
+) -> Result<()> {
+    let event_cpi = EventCpi::new(&ctx.accounts.event_authority, ctx.bumps.event_authority);
+
+    apply_set_project_multisig(&mut ctx.accounts.project, params, &event_cpi)
+}
+
+pub(crate) fn apply_set_project_multisig(
+    project: &mut Account<Project>,
+    params: SetProjectMultisigParams,
+    event_cpi: &EventCpi,
+) -> Result<()> {
+    require!(
+        params.signers.len() <= MAX_MULTISIG_SIGNERS,
+        ProgramErrorCode::InvalidMultisigConfig
+    );
+
+    // A zero threshold disables the multisig and must come without signers.
+    require!(
+        usize::from(params.threshold) <= params.signers.len()
+            && (params.threshold > 0 || params.signers.is_empty()),
+        ProgramErrorCode::InvalidMultisigConfig
+    );
+
+    for (index, signer) in params.signers.iter().enumerate() {
+        require!(
+            !params.signers[..index].contains(signer),
+            ProgramErrorCode::InvalidMultisigConfig
+        );
+    }
+
+    project.multisig_threshold = params.threshold;
+    project.multisig_signers = params.signers.clone();
+
+    emit_event!(
+        event_cpi,
+        SetProjectMultisigEvent {
+            project: project.key(),
+            signers: params.signers,
+            threshold: params.threshold,
+        }
+    );
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetProjectMultisig<'info> {
+    #[account(mut)]
+    admin: Signer<'info>,
+    #[account(
+        mut,
+        has_one = admin,
+        constraint = !project.has_multisig() @ ProgramErrorCode::MultisigRequired,
+        realloc = Project::LEN,
+        realloc::payer = admin,
+        realloc::zero = false,
+    )]
+    project: Account<'info, Project>,
+    system_program: Program<'info, System>,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Debug, Clone, Default)]
+pub struct SetProjectMultisigParams {
+    pub signers: Vec<Pubkey>,
+    pub threshold: u8,
+}
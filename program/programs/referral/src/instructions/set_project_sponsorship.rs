@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::{EventCpi, SetProjectSponsorshipEvent},
+    ProgramErrorCode, Project,
+};
+
+pub fn set_project_sponsorship(
+    ctx: Context<SetProjectSponsorship>,
+    params: SetProjectSponsorshipParams,
+) -> Result<()> {
+    let event_cpi = EventCpi::new(&ctx.accounts.event_authority, ctx.bumps.event_authority);
+
+    apply_set_project_sponsorship(&mut ctx.accounts.project, params, &event_cpi)
+}
+
+pub(crate) fn apply_set_project_sponsorship(
+    project: &mut Account<Project>,
+    params: SetProjectSponsorshipParams,
+    event_cpi: &EventCpi,
+) -> Result<()> {
+    project.sponsorship_enabled = params.enabled;
+    project.max_sponsored_lamports_per_partner = params.max_sponsored_lamports_per_partner;
+
+    emit_event!(
+        event_cpi,
+        SetProjectSponsorshipEvent {
+            project: project.key(),
+            enabled: params.enabled,
+            max_sponsored_lamports_per_partner: params.max_sponsored_lamports_per_partner,
+        }
+    );
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetProjectSponsorship<'info> {
+    admin: Signer<'info>,
+    #[account(
+        mut,
+        has_one = admin,
+        constraint = !project.has_multisig() @ ProgramErrorCode::MultisigRequired,
+    )]
+    project: Account<'info, Project>,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Debug, Clone, Default)]
+pub struct SetProjectSponsorshipParams {
+    pub enabled: bool,
+    // Advisory, see Project::sponsorship_enabled.
+    pub max_sponsored_lamports_per_partner: u64,
+}
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::{EventCpi, SetProjectSwapProgramEvent},
+    ProgramErrorCode, Project,
+};
+
+pub fn set_project_swap_program(
+    ctx: Context<SetProjectSwapProgram>,
+    params: SetProjectSwapProgramParams,
+) -> Result<()> {
+    let event_cpi = EventCpi::new(&ctx.accounts.event_authority, ctx.bumps.event_authority);
+
+    apply_set_project_swap_program(&mut ctx.accounts.project, params, &event_cpi)
+}
+
+pub(crate) fn apply_set_project_swap_program(
+    project: &mut Account<Project>,
+    params: SetProjectSwapProgramParams,
+    event_cpi: &EventCpi,
+) -> Result<()> {
+    project.swap_program = params.swap_program;
+
+    emit_event!(
+        event_cpi,
+        SetProjectSwapProgramEvent {
+            project: project.key(),
+            swap_program: params.swap_program,
+        }
+    );
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetProjectSwapProgram<'info> {
+    admin: Signer<'info>,
+    #[account(
+        mut,
+        has_one = admin,
+        constraint = !project.has_multisig() @ ProgramErrorCode::MultisigRequired,
+    )]
+    project: Account<'info, Project>,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Debug, Clone, Default)]
+pub struct SetProjectSwapProgramParams {
+    pub swap_program: Option<Pubkey>,
+}
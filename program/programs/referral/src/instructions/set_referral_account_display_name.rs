@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::UpdateReferralAccountMetadataEvent, ProgramErrorCode, ReferralAccount,
+    REFERRAL_ACCOUNT_VERSION,
+};
+
+pub fn set_referral_account_display_name(
+    ctx: Context<SetReferralAccountDisplayName>,
+    params: SetReferralAccountDisplayNameParams,
+) -> Result<()> {
+    if let Some(display_name) = &params.display_name {
+        require!(
+            display_name.chars().count() < 50,
+            ProgramErrorCode::NameTooLong
+        );
+    }
+
+    let referral_account = &mut ctx.accounts.referral_account;
+    let previous_display_name = referral_account.display_name.take();
+    referral_account.display_name = params.display_name;
+
+    emit_event!(
+        &ctx,
+        UpdateReferralAccountMetadataEvent {
+            project: ctx.accounts.referral_account.project,
+            referral_account: ctx.accounts.referral_account.key(),
+            name: ctx.accounts.referral_account.name.clone(),
+            previous_display_name,
+            display_name: ctx.accounts.referral_account.display_name.clone(),
+        }
+    );
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetReferralAccountDisplayName<'info> {
+    partner: Signer<'info>,
+    // Accounts from before version 2 have no room for the display name, see migrate_referral_account.
+    #[account(
+        mut,
+        has_one = partner,
+        constraint = referral_account.version == REFERRAL_ACCOUNT_VERSION @ ProgramErrorCode::ReferralAccountNotMigrated,
+    )]
+    referral_account: Account<'info, ReferralAccount>,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Debug, Clone, Default)]
+pub struct SetReferralAccountDisplayNameParams {
+    pub display_name: Option<String>,
+}
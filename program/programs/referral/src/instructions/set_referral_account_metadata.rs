@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::{events::SetReferralAccountMetadataEvent, Metadata, ReferralAccount, METADATA_SEED};
+
+use super::SetMetadataParams;
+
+pub fn set_referral_account_metadata(
+    ctx: Context<SetReferralAccountMetadata>,
+    params: SetMetadataParams,
+) -> Result<()> {
+    let referral_account_key = ctx.accounts.referral_account.key();
+    ctx.accounts.metadata.set(referral_account_key, params)?;
+
+    emit_event!(
+        &ctx,
+        SetReferralAccountMetadataEvent {
+            project: ctx.accounts.referral_account.project,
+            referral_account: referral_account_key,
+            metadata: ctx.accounts.metadata.key(),
+            uri: ctx.accounts.metadata.uri.clone(),
+            content_hash: ctx.accounts.metadata.content_hash,
+        }
+    );
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetReferralAccountMetadata<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+    partner: Signer<'info>,
+    #[account(has_one = partner)]
+    referral_account: Account<'info, ReferralAccount>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        seeds = [METADATA_SEED, referral_account.key().as_ref()],
+        bump,
+        space = Metadata::LEN
+    )]
+    metadata: Account<'info, Metadata>,
+    system_program: Program<'info, System>,
+}
@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::{EventCpi, QueueReferralAccountRebateIncreaseEvent, SetReferralAccountRebateEvent},
+    PendingShareChange, ProgramErrorCode, Project, ReferralAccount,
+};
+
+pub fn set_referral_account_rebate(
+    ctx: Context<SetReferralAccountRebate>,
+    params: SetReferralAccountRebateParams,
+) -> Result<()> {
+    let event_cpi = EventCpi::new(&ctx.accounts.event_authority, ctx.bumps.event_authority);
+
+    apply_set_referral_account_rebate(
+        &ctx.accounts.project,
+        &mut ctx.accounts.referral_account,
+        params.rebate_bps,
+        &event_cpi,
+    )
+}
+
+// The rebate is a part of the referral share, not of the whole claim, so the project part is unchanged.
+// It takes from the partner, so decreases apply immediately and increases are queued behind the
+// project's notice period, like share decreases.
+pub(crate) fn apply_set_referral_account_rebate(
+    project: &Project,
+    referral_account: &mut Account<ReferralAccount>,
+    rebate_bps: u16,
+    event_cpi: &EventCpi,
+) -> Result<()> {
+    require!(
+        rebate_bps <= 10_000,
+        ProgramErrorCode::InvalidSharePercentage
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let current_rebate_bps = referral_account.rebate_bps_at(now);
+    let notice_period = project.share_decrease_notice_period_at(now);
+
+    referral_account.rebate_bps = current_rebate_bps;
+    referral_account.pending_rebate = None;
+
+    if rebate_bps <= current_rebate_bps || notice_period == 0 {
+        referral_account.rebate_bps = rebate_bps;
+
+        emit_event!(
+            event_cpi,
+            SetReferralAccountRebateEvent {
+                referral_account: referral_account.key(),
+                previous_rebate_bps: current_rebate_bps,
+                rebate_bps,
+            }
+        );
+    } else {
+        let effective_at = now
+            .checked_add(notice_period)
+            .ok_or(ProgramErrorCode::InvalidCalculation)?;
+        referral_account.pending_rebate = Some(PendingShareChange {
+            share_bps: rebate_bps,
+            effective_at,
+        });
+
+        emit_event!(
+            event_cpi,
+            QueueReferralAccountRebateIncreaseEvent {
+                referral_account: referral_account.key(),
+                rebate_bps: current_rebate_bps,
+                pending_rebate_bps: rebate_bps,
+                effective_at,
+            }
+        );
+    }
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetReferralAccountRebate<'info> {
+    admin: Signer<'info>,
+    #[account(
+        has_one = admin,
+        constraint = !project.has_multisig() @ ProgramErrorCode::MultisigRequired,
+    )]
+    project: Account<'info, Project>,
+    #[account(
+        mut,
+        has_one = project
+    )]
+    referral_account: Account<'info, ReferralAccount>,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Debug, Clone, Default)]
+pub struct SetReferralAccountRebateParams {
+    pub rebate_bps: u16,
+}
@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::{EventCpi, UpdateReferralAccountShareModeEvent},
+    ProgramErrorCode, Project, ReferralAccount,
+};
+
+pub fn set_referral_account_share_mode(
+    ctx: Context<SetReferralAccountShareMode>,
+    params: SetReferralAccountShareModeParams,
+) -> Result<()> {
+    let event_cpi = EventCpi::new(&ctx.accounts.event_authority, ctx.bumps.event_authority);
+
+    apply_set_referral_account_share_mode(
+        &ctx.accounts.project,
+        &mut ctx.accounts.referral_account,
+        params.inherit_default,
+        &event_cpi,
+    )
+}
+
+// Switching to a pinned share pins the currently effective default share, switching to the
+// inherited share must not lower it while the project enforces a notice period.
+pub(crate) fn apply_set_referral_account_share_mode(
+    project: &Project,
+    referral_account: &mut Account<ReferralAccount>,
+    inherit_default: bool,
+    event_cpi: &EventCpi,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let current_share_bps = referral_account.share_bps_at(project, now);
+
+    if inherit_default {
+        require!(
+            project.default_share_bps_at(now) >= current_share_bps
+                || project.share_decrease_notice_period_at(now) == 0,
+            ProgramErrorCode::ShareModeChangeDecreasesShare
+        );
+    }
+
+    referral_account.share_bps = current_share_bps;
+    referral_account.pending_share = None;
+    referral_account.inherit_default = inherit_default;
+
+    emit_event!(
+        event_cpi,
+        UpdateReferralAccountShareModeEvent {
+            referral_account: referral_account.key(),
+            inherit_default,
+            share_bps: referral_account.share_bps_at(project, now),
+        }
+    );
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetReferralAccountShareMode<'info> {
+    admin: Signer<'info>,
+    #[account(
+        has_one = admin,
+        constraint = !project.has_multisig() @ ProgramErrorCode::MultisigRequired,
+    )]
+    project: Account<'info, Project>,
+    #[account(
+        mut,
+        has_one = project
+    )]
+    referral_account: Account<'info, ReferralAccount>,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Debug, Clone, Default)]
+pub struct SetReferralAccountShareModeParams {
+    pub inherit_default: bool,
+}
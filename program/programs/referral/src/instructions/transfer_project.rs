@@ -1,16 +1,44 @@
 use anchor_lang::prelude::*;
 
-use crate::Project;
+use crate::{
+    events::{EventCpi, TransferProjectEvent},
+    ProgramErrorCode, Project,
+};
 
 pub fn transfer_project(
     ctx: Context<TransferProject>,
     _params: TransferProjectParams,
 ) -> Result<()> {
-    ctx.accounts.project.admin = ctx.accounts.new_admin.key();
+    let event_cpi = EventCpi::new(&ctx.accounts.event_authority, ctx.bumps.event_authority);
+
+    apply_transfer_project(
+        &mut ctx.accounts.project,
+        ctx.accounts.new_admin.key(),
+        &event_cpi,
+    )
+}
+
+pub(crate) fn apply_transfer_project(
+    project: &mut Account<Project>,
+    new_admin: Pubkey,
+    event_cpi: &EventCpi,
+) -> Result<()> {
+    let previous_admin = project.admin;
+    project.admin = new_admin;
+
+    emit_event!(
+        event_cpi,
+        TransferProjectEvent {
+            project: project.key(),
+            previous_admin,
+            new_admin,
+        }
+    );
 
     Ok(())
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct TransferProject<'info> {
     admin: Signer<'info>,
@@ -18,6 +46,7 @@ pub struct TransferProject<'info> {
     #[account(
         mut,
         has_one = admin,
+        constraint = !project.has_multisig() @ ProgramErrorCode::MultisigRequired,
     )]
     project: Account<'info, Project>,
 }
@@ -1,16 +1,34 @@
 use anchor_lang::prelude::*;
 
-use crate::ReferralAccount;
+use crate::{
+    events::{EventCpi, TransferReferralAccountEvent},
+    ReferralAccount,
+};
 
 pub fn transfer_referral_account(
     ctx: Context<TransferReferralAccount>,
     _params: TransferReferralAccountParams,
 ) -> Result<()> {
-    ctx.accounts.referral_account.partner = ctx.accounts.new_partner.key();
+    let event_cpi = EventCpi::new(&ctx.accounts.event_authority, ctx.bumps.event_authority);
+
+    let referral_account = &mut ctx.accounts.referral_account;
+    let new_partner = ctx.accounts.new_partner.key();
+    referral_account.partner = new_partner;
+
+    emit_event!(
+        &event_cpi,
+        TransferReferralAccountEvent {
+            project: referral_account.project,
+            referral_account: referral_account.key(),
+            previous_partner: ctx.accounts.partner.key(),
+            new_partner,
+        }
+    );
 
     Ok(())
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct TransferReferralAccount<'info> {
     partner: Signer<'info>,
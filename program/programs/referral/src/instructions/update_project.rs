@@ -1,33 +1,101 @@
 use anchor_lang::prelude::*;
 
-use crate::{events::UpdateProjectEvent, Project};
+use crate::{
+    events::{EventCpi, UpdateProjectEvent},
+    PendingNoticePeriodChange, PendingShareChange, ProgramErrorCode, Project,
+};
 
 pub fn update_project(ctx: Context<UpdateProject>, params: UpdateProjectParams) -> Result<()> {
-    let project = &mut ctx.accounts.project;
+    let event_cpi = EventCpi::new(&ctx.accounts.event_authority, ctx.bumps.event_authority);
 
-    if params.name.is_some() {
-        project.name = params.name.unwrap();
+    apply_update_project(&mut ctx.accounts.project, params, &event_cpi)
+}
+
+pub(crate) fn apply_update_project(
+    project: &mut Account<Project>,
+    params: UpdateProjectParams,
+    event_cpi: &EventCpi,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let previous_name = project.name.clone();
+    let previous_default_share_bps = project.default_share_bps_at(now);
+    let previous_share_decrease_notice_period = project.share_decrease_notice_period_at(now);
+
+    if let Some(name) = params.name {
+        project.name = name;
     }
 
-    if params.default_share_bps.is_some() {
-        project.default_share_bps = params.default_share_bps.unwrap();
+    if let Some(default_share_bps) = params.default_share_bps {
+        require!(
+            default_share_bps <= 10_000,
+            ProgramErrorCode::InvalidSharePercentage
+        );
+
+        // Referral accounts inheriting the default share get the same notice as pinned ones.
+        let current_share_bps = project.default_share_bps_at(now);
+        let notice_period = project.share_decrease_notice_period_at(now);
+        project.default_share_bps = current_share_bps;
+        project.pending_default_share = None;
+
+        if default_share_bps >= current_share_bps || notice_period == 0 {
+            project.default_share_bps = default_share_bps;
+        } else {
+            project.pending_default_share = Some(PendingShareChange {
+                share_bps: default_share_bps,
+                effective_at: now
+                    .checked_add(notice_period)
+                    .ok_or(ProgramErrorCode::InvalidCalculation)?,
+            });
+        }
+    }
+
+    if let Some(notice_period) = params.share_decrease_notice_period {
+        require!(notice_period >= 0, ProgramErrorCode::InvalidNoticePeriod);
+
+        let current_notice_period = project.share_decrease_notice_period_at(now);
+        project.share_decrease_notice_period = current_notice_period;
+        project.pending_notice_period = None;
+
+        // Shortening the notice period is itself subject to the current notice period,
+        // otherwise an admin could drop it to zero right before cutting shares.
+        if notice_period >= current_notice_period {
+            project.share_decrease_notice_period = notice_period;
+        } else {
+            project.pending_notice_period = Some(PendingNoticePeriodChange {
+                notice_period,
+                effective_at: now
+                    .checked_add(current_notice_period)
+                    .ok_or(ProgramErrorCode::InvalidCalculation)?,
+            });
+        }
     }
 
-    emit!(UpdateProjectEvent {
-        project: project.key(),
-        name: project.name.clone(),
-        default_share_bps: project.default_share_bps,
-    });
+    emit_event!(
+        event_cpi,
+        UpdateProjectEvent {
+            project: project.key(),
+            previous_name,
+            name: project.name.clone(),
+            previous_default_share_bps,
+            default_share_bps: project.default_share_bps,
+            previous_share_decrease_notice_period,
+            share_decrease_notice_period: project.share_decrease_notice_period,
+            pending_notice_period: project.pending_notice_period,
+            pending_default_share: project.pending_default_share,
+        }
+    );
 
     Ok(())
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct UpdateProject<'info> {
     admin: Signer<'info>,
     #[account(
         mut,
         has_one = admin,
+        constraint = !project.has_multisig() @ ProgramErrorCode::MultisigRequired,
     )]
     project: Account<'info, Project>,
 }
@@ -36,4 +104,5 @@ pub struct UpdateProject<'info> {
 pub struct UpdateProjectParams {
     pub name: Option<String>,
     pub default_share_bps: Option<u16>,
+    pub share_decrease_notice_period: Option<i64>,
 }
@@ -1,26 +1,86 @@
 use anchor_lang::prelude::*;
 
-use crate::{events::UpdateReferralAccountEvent, Project, ReferralAccount};
+use crate::{
+    events::{EventCpi, QueueReferralAccountShareDecreaseEvent, UpdateReferralAccountEvent},
+    PendingShareChange, ProgramErrorCode, Project, ReferralAccount,
+};
 
 pub fn update_referral_account(
     ctx: Context<UpdateReferralAccount>,
     params: UpdateReferralAccountParams,
 ) -> Result<()> {
-    ctx.accounts.referral_account.share_bps = params.share_bps;
+    let event_cpi = EventCpi::new(&ctx.accounts.event_authority, ctx.bumps.event_authority);
 
-    emit!(UpdateReferralAccountEvent {
-        referral_account: ctx.accounts.referral_account.key(),
-        share_bps: params.share_bps,
-    });
+    apply_update_referral_account(
+        &ctx.accounts.project,
+        &mut ctx.accounts.referral_account,
+        params.share_bps,
+        &event_cpi,
+    )
+}
+
+// Increases apply immediately, decreases are queued behind the project's notice period.
+// Setting a share pins the referral account if it was inheriting the project default.
+pub(crate) fn apply_update_referral_account(
+    project: &Project,
+    referral_account: &mut Account<ReferralAccount>,
+    share_bps: u16,
+    event_cpi: &EventCpi,
+) -> Result<()> {
+    require!(
+        share_bps <= 10_000,
+        ProgramErrorCode::InvalidSharePercentage
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let current_share_bps = referral_account.share_bps_at(project, now);
+    let notice_period = project.share_decrease_notice_period_at(now);
+
+    referral_account.share_bps = current_share_bps;
+    referral_account.pending_share = None;
+    referral_account.inherit_default = false;
+
+    if share_bps >= current_share_bps || notice_period == 0 {
+        referral_account.share_bps = share_bps;
+
+        emit_event!(
+            event_cpi,
+            UpdateReferralAccountEvent {
+                referral_account: referral_account.key(),
+                previous_share_bps: current_share_bps,
+                share_bps,
+            }
+        );
+    } else {
+        let effective_at = now
+            .checked_add(notice_period)
+            .ok_or(ProgramErrorCode::InvalidCalculation)?;
+        referral_account.pending_share = Some(PendingShareChange {
+            share_bps,
+            effective_at,
+        });
+
+        emit_event!(
+            event_cpi,
+            QueueReferralAccountShareDecreaseEvent {
+                referral_account: referral_account.key(),
+                share_bps: current_share_bps,
+                pending_share_bps: share_bps,
+                effective_at,
+            }
+        );
+    }
 
     Ok(())
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct UpdateReferralAccount<'info> {
     admin: Signer<'info>,
     #[account(
         has_one = admin,
+        constraint = !project.has_multisig() @ ProgramErrorCode::MultisigRequired,
     )]
     project: Account<'info, Project>,
     #[account(
@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+
+use crate::{events::EventCpi, ProgramErrorCode, Project, ReferralAccount};
+
+use super::apply_update_referral_account;
+
+// Referral accounts to update are passed in remaining_accounts, in the same order as
+// params.share_bps.
+pub fn update_referral_accounts<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, UpdateReferralAccounts<'info>>,
+    params: UpdateReferralAccountsParams,
+) -> Result<()> {
+    let event_cpi = EventCpi::new(&ctx.accounts.event_authority, ctx.bumps.event_authority);
+
+    apply_update_referral_accounts(
+        &ctx.accounts.project,
+        ctx.remaining_accounts,
+        params.share_bps,
+        &event_cpi,
+    )
+}
+
+pub(crate) fn apply_update_referral_accounts<'info>(
+    project: &Account<Project>,
+    referral_accounts: &'info [AccountInfo<'info>],
+    share_bps: Vec<u16>,
+    event_cpi: &EventCpi,
+) -> Result<()> {
+    require!(
+        !share_bps.is_empty() && share_bps.len() == referral_accounts.len(),
+        ProgramErrorCode::InvalidReferralAccountsUpdate
+    );
+
+    for (account_info, share_bps) in referral_accounts.iter().zip(share_bps) {
+        require!(
+            account_info.is_writable,
+            ProgramErrorCode::InvalidReferralAccountsUpdate
+        );
+
+        let mut referral_account = Account::<ReferralAccount>::try_from(account_info)?;
+        require_keys_eq!(
+            referral_account.project,
+            project.key(),
+            ProgramErrorCode::InvalidReferralAccountsUpdate
+        );
+
+        apply_update_referral_account(project, &mut referral_account, share_bps, event_cpi)?;
+        referral_account.exit(&crate::ID)?;
+    }
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateReferralAccounts<'info> {
+    admin: Signer<'info>,
+    #[account(
+        has_one = admin,
+        constraint = !project.has_multisig() @ ProgramErrorCode::MultisigRequired,
+    )]
+    project: Account<'info, Project>,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Debug, Clone, Default)]
+pub struct UpdateReferralAccountsParams {
+    pub share_bps: Vec<u16>,
+}
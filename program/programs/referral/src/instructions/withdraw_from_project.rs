@@ -1,40 +1,99 @@
 use anchor_lang::prelude::*;
 use anchor_lang::system_program;
 
-use crate::{Project, PROJECT_AUTHORITY_SEED};
+use crate::{
+    events::{EventCpi, WithdrawFromProjectEvent},
+    ProgramErrorCode, Project, PROJECT_AUTHORITY_SEED,
+};
 
 pub fn withdraw_from_project(
     ctx: Context<WithdrawFromProject>,
     params: WithdrawFromProjectParams,
 ) -> Result<()> {
-    let bump = ctx.bumps.project_authority;
+    let event_cpi = EventCpi::new(&ctx.accounts.event_authority, ctx.bumps.event_authority);
+
+    apply_withdraw_from_project(
+        &ctx.accounts.project,
+        ctx.bumps.project_authority,
+        ctx.accounts.project_authority.to_account_info(),
+        ctx.accounts.admin.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        params.amount,
+        &event_cpi,
+    )
+}
+
+pub(crate) fn apply_withdraw_from_project<'info>(
+    project: &Account<'info, Project>,
+    project_authority_bump: u8,
+    project_authority: AccountInfo<'info>,
+    destination: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+    amount: u64,
+    event_cpi: &EventCpi,
+) -> Result<()> {
+    let project_authority_key = project_authority.key();
+    let destination_key = destination.key();
+
+    withdraw_project_lamports(
+        project,
+        project_authority_bump,
+        project_authority,
+        destination,
+        system_program,
+        amount,
+    )?;
+
+    emit_event!(
+        event_cpi,
+        WithdrawFromProjectEvent {
+            project: project.key(),
+            project_authority: project_authority_key,
+            destination: destination_key,
+            amount,
+        }
+    );
+
+    Ok(())
+}
+
+pub(crate) fn withdraw_project_lamports<'info>(
+    project: &Project,
+    project_authority_bump: u8,
+    project_authority: AccountInfo<'info>,
+    destination: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
     let signer_seeds: &[&[&[u8]]] = &[&[
         PROJECT_AUTHORITY_SEED,
-        ctx.accounts.project.base.as_ref(),
-        &[bump],
+        project.base.as_ref(),
+        &[project_authority_bump],
     ]];
 
     system_program::transfer(
         CpiContext::new_with_signer(
-            ctx.accounts.system_program.to_account_info(),
+            system_program,
             system_program::Transfer {
-                from: ctx.accounts.project_authority.to_account_info(),
-                to: ctx.accounts.admin.to_account_info(),
+                from: project_authority,
+                to: destination,
             },
             signer_seeds,
         ),
-        params.amount,
+        amount,
     )?;
 
     Ok(())
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct WithdrawFromProject<'info> {
     #[account(mut)]
     admin: Signer<'info>,
     #[account(
-        has_one = admin
+        has_one = admin,
+        constraint = !project.has_multisig() @ ProgramErrorCode::MultisigRequired,
     )]
     project: Account<'info, Project>,
     #[account(
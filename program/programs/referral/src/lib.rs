@@ -1,8 +1,13 @@
+#[cfg(not(target_os = "solana"))]
+pub mod client;
+#[macro_use]
 mod events;
 mod instructions;
+mod utils;
 
 use anchor_lang::prelude::*;
 use instructions::*;
+use utils::calculate_referral_amount;
 
 #[cfg(all(not(feature = "devnet"), not(feature = "mainnet")))]
 declare_id!("9vhgK3i91cTwTHQag85zoA3PmJUTfgvgYFc9AJPRNhGn");
@@ -18,6 +23,22 @@ pub const PROJECT_AUTHORITY_SEED: &[u8] = b"project_authority";
 pub const REFERRAL_SEED: &[u8] = b"referral";
 pub const REFERRAL_ATA_SEED: &[u8] = b"referral_ata";
 pub const AUTHORITY_SEED: &[u8] = b"authority";
+pub const PROPOSAL_SEED: &[u8] = b"proposal";
+pub const TEMPORARY_WSOL_SEED: &[u8] = b"temporary_wsol";
+pub const SWAP_AUTHORITY_SEED: &[u8] = b"swap_authority";
+pub const SWAP_TOKEN_ACCOUNT_SEED: &[u8] = b"swap_token_account";
+pub const MINT_POLICY_SEED: &[u8] = b"mint_policy";
+pub const CLAIM_THRESHOLDS_SEED: &[u8] = b"claim_thresholds";
+pub const PARTNER_SPONSORSHIP_SEED: &[u8] = b"partner_sponsorship";
+pub const METADATA_SEED: &[u8] = b"metadata";
+pub const ALIAS_SEED: &[u8] = b"alias";
+pub const USER_ATTRIBUTION_SEED: &[u8] = b"user_attribution";
+pub const REBATE_POOL_SEED: &[u8] = b"rebate_pool";
+pub const REBATE_BITMAP_SEED: &[u8] = b"rebate_bitmap";
+pub const FEE_STATS_SEED: &[u8] = b"fee_stats";
+
+pub const PROJECT_VERSION: u8 = 2;
+pub const REFERRAL_ACCOUNT_VERSION: u8 = 3;
 
 #[program]
 pub mod referral {
@@ -50,6 +71,13 @@ pub mod referral {
         instructions::update_referral_account(ctx, params)
     }
 
+    pub fn update_referral_accounts<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, UpdateReferralAccounts<'info>>,
+        params: UpdateReferralAccountsParams,
+    ) -> Result<()> {
+        instructions::update_referral_accounts(ctx, params)
+    }
+
     pub fn withdraw_from_project(
         ctx: Context<WithdrawFromProject>,
         params: WithdrawFromProjectParams,
@@ -61,6 +89,166 @@ pub mod referral {
         instructions::create_admin_token_account(ctx)
     }
 
+    pub fn set_project_swap_program(
+        ctx: Context<SetProjectSwapProgram>,
+        params: SetProjectSwapProgramParams,
+    ) -> Result<()> {
+        instructions::set_project_swap_program(ctx, params)
+    }
+
+    pub fn set_project_mint_extension_policy(
+        ctx: Context<SetProjectMintExtensionPolicy>,
+        params: SetProjectMintExtensionPolicyParams,
+    ) -> Result<()> {
+        instructions::set_project_mint_extension_policy(ctx, params)
+    }
+
+    pub fn initialize_project_mint_policy(ctx: Context<InitializeProjectMintPolicy>) -> Result<()> {
+        instructions::initialize_project_mint_policy(ctx)
+    }
+
+    pub fn set_project_mint_policy_mode(
+        ctx: Context<UpdateProjectMintPolicy>,
+        params: SetProjectMintPolicyModeParams,
+    ) -> Result<()> {
+        instructions::set_project_mint_policy_mode(ctx, params)
+    }
+
+    pub fn add_project_policy_mint(
+        ctx: Context<UpdateProjectMintPolicy>,
+        params: ProjectPolicyMintParams,
+    ) -> Result<()> {
+        instructions::add_project_policy_mint(ctx, params)
+    }
+
+    pub fn remove_project_policy_mint(
+        ctx: Context<UpdateProjectMintPolicy>,
+        params: ProjectPolicyMintParams,
+    ) -> Result<()> {
+        instructions::remove_project_policy_mint(ctx, params)
+    }
+
+    pub fn initialize_project_claim_thresholds(
+        ctx: Context<InitializeProjectClaimThresholds>,
+    ) -> Result<()> {
+        instructions::initialize_project_claim_thresholds(ctx)
+    }
+
+    pub fn set_project_min_claim_amount(
+        ctx: Context<SetProjectMinClaimAmount>,
+        params: SetProjectMinClaimAmountParams,
+    ) -> Result<()> {
+        instructions::set_project_min_claim_amount(ctx, params)
+    }
+
+    pub fn set_project_keeper_fee(
+        ctx: Context<SetProjectKeeperFee>,
+        params: SetProjectKeeperFeeParams,
+    ) -> Result<()> {
+        instructions::set_project_keeper_fee(ctx, params)
+    }
+
+    pub fn set_project_max_keeper_fee(
+        ctx: Context<SetProjectMaxKeeperFee>,
+        params: SetProjectMaxKeeperFeeParams,
+    ) -> Result<()> {
+        instructions::set_project_max_keeper_fee(ctx, params)
+    }
+
+    pub fn set_project_sponsorship(
+        ctx: Context<SetProjectSponsorship>,
+        params: SetProjectSponsorshipParams,
+    ) -> Result<()> {
+        instructions::set_project_sponsorship(ctx, params)
+    }
+
+    pub fn set_project_admin_token_account_policy(
+        ctx: Context<SetProjectAdminTokenAccountPolicy>,
+        params: SetProjectAdminTokenAccountPolicyParams,
+    ) -> Result<()> {
+        instructions::set_project_admin_token_account_policy(ctx, params)
+    }
+
+    pub fn initialize_project_metadata(ctx: Context<InitializeProjectMetadata>) -> Result<()> {
+        instructions::initialize_project_metadata(ctx)
+    }
+
+    pub fn set_project_metadata(
+        ctx: Context<SetProjectMetadata>,
+        params: SetMetadataParams,
+    ) -> Result<()> {
+        instructions::set_project_metadata(ctx, params)
+    }
+
+    pub fn set_project_attribution_policy(
+        ctx: Context<SetProjectAttributionPolicy>,
+        params: SetProjectAttributionPolicyParams,
+    ) -> Result<()> {
+        instructions::set_project_attribution_policy(ctx, params)
+    }
+
+    pub fn fund_project_authority(
+        ctx: Context<FundProjectAuthority>,
+        params: FundProjectAuthorityParams,
+    ) -> Result<()> {
+        instructions::fund_project_authority(ctx, params)
+    }
+
+    pub fn migrate_project(ctx: Context<MigrateProject>) -> Result<()> {
+        instructions::migrate_project(ctx)
+    }
+
+    pub fn set_referral_account_share_mode(
+        ctx: Context<SetReferralAccountShareMode>,
+        params: SetReferralAccountShareModeParams,
+    ) -> Result<()> {
+        instructions::set_referral_account_share_mode(ctx, params)
+    }
+
+    pub fn set_referral_account_rebate(
+        ctx: Context<SetReferralAccountRebate>,
+        params: SetReferralAccountRebateParams,
+    ) -> Result<()> {
+        instructions::set_referral_account_rebate(ctx, params)
+    }
+
+    pub fn publish_rebate_root(
+        ctx: Context<PublishRebateRoot>,
+        params: PublishRebateRootParams,
+    ) -> Result<()> {
+        instructions::publish_rebate_root(ctx, params)
+    }
+
+    // Project multisig instructions.
+
+    pub fn set_project_multisig(
+        ctx: Context<SetProjectMultisig>,
+        params: SetProjectMultisigParams,
+    ) -> Result<()> {
+        instructions::set_project_multisig(ctx, params)
+    }
+
+    pub fn create_project_proposal(
+        ctx: Context<CreateProjectProposal>,
+        params: CreateProjectProposalParams,
+    ) -> Result<()> {
+        instructions::create_project_proposal(ctx, params)
+    }
+
+    pub fn approve_project_proposal(ctx: Context<ApproveProjectProposal>) -> Result<()> {
+        instructions::approve_project_proposal(ctx)
+    }
+
+    pub fn execute_project_proposal<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, ExecuteProjectProposal<'info>>,
+    ) -> Result<()> {
+        instructions::execute_project_proposal(ctx)
+    }
+
+    pub fn close_project_proposal(ctx: Context<CloseProjectProposal>) -> Result<()> {
+        instructions::close_project_proposal(ctx)
+    }
+
     // Referral account instructions.
 
     pub fn initialize_referral_account(
@@ -84,29 +272,145 @@ pub mod referral {
         instructions::transfer_referral_account(ctx, params)
     }
 
+    pub fn set_referral_account_display_name(
+        ctx: Context<SetReferralAccountDisplayName>,
+        params: SetReferralAccountDisplayNameParams,
+    ) -> Result<()> {
+        instructions::set_referral_account_display_name(ctx, params)
+    }
+
+    pub fn create_referral_alias(
+        ctx: Context<CreateReferralAlias>,
+        params: CreateReferralAliasParams,
+    ) -> Result<()> {
+        instructions::create_referral_alias(ctx, params)
+    }
+
+    pub fn delete_referral_alias(ctx: Context<DeleteReferralAlias>) -> Result<()> {
+        instructions::delete_referral_alias(ctx)
+    }
+
+    pub fn resolve_referral_alias(ctx: Context<ResolveReferralAlias>) -> Result<Pubkey> {
+        instructions::resolve_referral_alias(ctx)
+    }
+
+    pub fn attribute_user(ctx: Context<AttributeUser>) -> Result<()> {
+        instructions::attribute_user(ctx)
+    }
+
+    pub fn resolve_user_attribution(
+        ctx: Context<ResolveUserAttribution>,
+    ) -> Result<Option<Pubkey>> {
+        instructions::resolve_user_attribution(ctx)
+    }
+
+    pub fn set_referral_account_metadata(
+        ctx: Context<SetReferralAccountMetadata>,
+        params: SetMetadataParams,
+    ) -> Result<()> {
+        instructions::set_referral_account_metadata(ctx, params)
+    }
+
+    pub fn initialize_sponsored_referral_account_with_name(
+        ctx: Context<InitializeSponsoredReferralAccountWithName>,
+        params: InitializeReferralAccountWithNameParams,
+    ) -> Result<()> {
+        instructions::initialize_sponsored_referral_account_with_name(ctx, params)
+    }
+
+    pub fn migrate_referral_account(ctx: Context<MigrateReferralAccount>) -> Result<()> {
+        instructions::migrate_referral_account(ctx)
+    }
+
     pub fn initialize_referral_token_account(
         ctx: Context<InitializeReferralTokenAccount>,
     ) -> Result<()> {
         instructions::initialize_referral_token_account(ctx)
     }
 
+    pub fn initialize_referral_token_account_v2(
+        ctx: Context<InitializeReferralTokenAccountV2>,
+    ) -> Result<()> {
+        instructions::initialize_referral_token_account_v2(ctx)
+    }
+
+    pub fn initialize_sponsored_referral_token_account(
+        ctx: Context<InitializeSponsoredReferralTokenAccount>,
+    ) -> Result<()> {
+        instructions::initialize_sponsored_referral_token_account(ctx)
+    }
+
     pub fn claim(ctx: Context<Claim>) -> Result<()> {
         instructions::claim(ctx)
     }
 
+    pub fn claim_amount(ctx: Context<Claim>, params: ClaimAmountParams) -> Result<()> {
+        instructions::claim_amount(ctx, params)
+    }
+
     pub fn close_referral_token_account(ctx: Context<CloseReferralTokenAccount>) -> Result<()> {
         instructions::close_referral_token_account(ctx)
     }
 
-    pub fn claim_v2(ctx: Context<ClaimV2>) -> Result<()> {
+    pub fn claim_v2<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, ClaimV2<'info>>,
+    ) -> Result<()> {
         instructions::claim_v2(ctx)
     }
 
+    pub fn claim_amount_v2<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, ClaimV2<'info>>,
+        params: ClaimAmountParams,
+    ) -> Result<()> {
+        instructions::claim_amount_v2(ctx, params)
+    }
+
+    pub fn claim_many_v2<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, ClaimManyV2<'info>>,
+    ) -> Result<()> {
+        instructions::claim_many_v2(ctx)
+    }
+
+    pub fn claim_native_v2(ctx: Context<ClaimNativeV2>, params: ClaimNativeV2Params) -> Result<()> {
+        instructions::claim_native_v2(ctx, params)
+    }
+
+    pub fn claim_and_swap<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, ClaimAndSwap<'info>>,
+        params: ClaimAndSwapParams,
+    ) -> Result<()> {
+        instructions::claim_and_swap(ctx, params)
+    }
+
     pub fn close_referral_token_account_v2(
         ctx: Context<CloseReferralTokenAccountV2>,
     ) -> Result<()> {
         instructions::close_referral_token_account_v2(ctx)
     }
+
+    pub fn harvest_referral_token_account_fees(
+        ctx: Context<HarvestReferralTokenAccountFees>,
+    ) -> Result<()> {
+        instructions::harvest_referral_token_account_fees(ctx)
+    }
+
+    pub fn initialize_rebate_pool(ctx: Context<InitializeRebatePool>) -> Result<()> {
+        instructions::initialize_rebate_pool(ctx)
+    }
+
+    pub fn claim_rebate<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, ClaimRebate<'info>>,
+        params: ClaimRebateParams,
+    ) -> Result<()> {
+        instructions::claim_rebate(ctx, params)
+    }
+
+    pub fn collect_fee<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, CollectFee<'info>>,
+        params: CollectFeeParams,
+    ) -> Result<()> {
+        instructions::collect_fee(ctx, params)
+    }
 }
 
 #[derive(Accounts)]
@@ -118,10 +422,106 @@ pub struct Project {
     admin: Pubkey,
     name: String,
     default_share_bps: u16,
+    // Number of approvals required to execute a proposal, zero when the project has no multisig.
+    multisig_threshold: u8,
+    multisig_signers: Vec<Pubkey>,
+    // Delay in seconds before a share decrease takes effect on a referral account.
+    share_decrease_notice_period: i64,
+    pending_notice_period: Option<PendingNoticePeriodChange>,
+    // Queued decrease of the default share, followed by referral accounts that inherit it.
+    pending_default_share: Option<PendingShareChange>,
+    // Program that claim_and_swap is allowed to route referral fees through.
+    swap_program: Option<Pubkey>,
+    // Token-2022 mint extensions tolerated when opening referral token accounts.
+    mint_extension_policy: MintExtensionPolicy,
+    // Set once the project has a mint policy, which referral token account creation and claims must then pass.
+    has_mint_policy: bool,
+    // Set once the project has claim thresholds, which claims must then pass.
+    has_claim_thresholds: bool,
+    // Share of a claim_v2 paid to the payer for running the claim out of the project amount,
+    // capped per mint by the claim thresholds.
+    keeper_fee_bps: u16,
+    // Whether the project authority pays the rent of partner onboarding, up to a lamport cap per partner.
+    // The cap is counted per partner key across all of its referral accounts, but partner keys are
+    // free to create, so it only slows down draining: the project authority balance is the actual
+    // budget.
+    sponsorship_enabled: bool,
+    max_sponsored_lamports_per_partner: u64,
+    // Limits on the project authority SOL that create_admin_token_account spends.
+    admin_token_account_policy: AdminTokenAccountPolicy,
+    admin_token_account_spend_epoch: u64,
+    admin_token_account_spent_lamports: u64,
+    // Layout version, zero for accounts created before versioning until they are migrated.
+    version: u8,
+    // Whether attribute_user may replace an attribution and how long one lasts. Added in version 2.
+    attribution_policy: AttributionPolicy,
+    // Room for new fields ahead of this one, so they don't need another realloc.
+    reserved: [u8; PROJECT_RESERVED_LEN],
 }
 
 impl Project {
-    const LEN: usize = 8 + 32 + 32 + MAX_PROJECT_NAME_LENGTH + 12;
+    const LEN: usize = 8
+        + 32
+        + 32
+        + MAX_PROJECT_NAME_LENGTH
+        + 12
+        + 1
+        + 4
+        + 32 * MAX_MULTISIG_SIGNERS
+        + 8
+        + 1
+        + PendingNoticePeriodChange::LEN
+        + 1
+        + PendingShareChange::LEN
+        + 1
+        + 32
+        + MintExtensionPolicy::LEN
+        + 1
+        + 1
+        + 2
+        + 1
+        + 8
+        + AdminTokenAccountPolicy::LEN
+        + 8
+        + 8
+        + 1
+        + AttributionPolicy::LEN
+        + PROJECT_RESERVED_LEN;
+    // Length of the accounts created before versioning, see migrate_project.
+    const LEGACY_LEN: usize = 8 + 32 + 32 + MAX_PROJECT_NAME_LENGTH + 12;
+
+    fn default_share_bps_at(&self, now: i64) -> u16 {
+        match self.pending_default_share {
+            Some(pending) if pending.effective_at <= now => pending.share_bps,
+            _ => self.default_share_bps,
+        }
+    }
+
+    fn share_decrease_notice_period_at(&self, now: i64) -> i64 {
+        match self.pending_notice_period {
+            Some(pending) if pending.effective_at <= now => pending.notice_period,
+            _ => self.share_decrease_notice_period,
+        }
+    }
+
+    // The keeper part of a claimed amount, taken out of the project amount.
+    fn keeper_fee(
+        &self,
+        amount: u64,
+        claim_thresholds: &ProjectClaimThresholds,
+        mint: &Pubkey,
+    ) -> Result<u64> {
+        Ok(calculate_referral_amount(amount, self.keeper_fee_bps)?
+            .min(claim_thresholds.max_keeper_fee(mint)))
+    }
+
+    fn has_multisig(&self) -> bool {
+        self.multisig_threshold > 0
+    }
+
+    fn is_multisig_signer(&self, key: &Pubkey) -> bool {
+        self.multisig_signers.contains(key)
+    }
 }
 
 #[account]
@@ -130,18 +530,525 @@ pub struct ReferralAccount {
     project: Pubkey,
     share_bps: u16,
     name: Option<String>,
+    pending_share: Option<PendingShareChange>,
+    // Follow the project's default share instead of the pinned share_bps.
+    inherit_default: bool,
+    // Layout version, zero for accounts created before versioning until they are migrated.
+    version: u8,
+    // Shown instead of the name, which can't change since it seeds the PDA. Added in version 2.
+    display_name: Option<String>,
+    // Part of the referral share that claim_v2 sets aside for traders. Added in version 3.
+    rebate_bps: u16,
+    // Queued rebate increase, which takes from the partner like a share decrease. Added in version 3.
+    pending_rebate: Option<PendingShareChange>,
+    // Room for new fields ahead of this one, so they don't need another realloc.
+    reserved: [u8; REFERRAL_RESERVED_LEN],
 }
 
 impl ReferralAccount {
-    const LEN: usize = 8 + 32 + 32 + MAX_REFERRAL_ACCOUNT_NAME_LENGTH + 2;
+    const LEN: usize = 8
+        + 32
+        + 32
+        + MAX_REFERRAL_ACCOUNT_NAME_LENGTH
+        + 2
+        + 1
+        + PendingShareChange::LEN
+        + 1
+        + 1
+        + 1
+        + 4
+        + MAX_REFERRAL_ACCOUNT_NAME_LENGTH
+        + 2
+        + 1
+        + PendingShareChange::LEN
+        + REFERRAL_RESERVED_LEN;
+    // Length of the accounts created before versioning, see migrate_referral_account.
+    const LEGACY_LEN: usize = 8 + 32 + 32 + MAX_REFERRAL_ACCOUNT_NAME_LENGTH + 2;
+
+    // The share used by claims, a queued decrease applies once its notice period has passed.
+    fn share_bps_at(&self, project: &Project, now: i64) -> u16 {
+        if self.inherit_default {
+            return project.default_share_bps_at(now);
+        }
+
+        match self.pending_share {
+            Some(pending) if pending.effective_at <= now => pending.share_bps,
+            _ => self.share_bps,
+        }
+    }
+
+    // The rebate used by claim_v2, a queued increase applies once its notice period has passed.
+    fn rebate_bps_at(&self, now: i64) -> u16 {
+        match self.pending_rebate {
+            Some(pending) if pending.effective_at <= now => pending.share_bps,
+            _ => self.rebate_bps,
+        }
+    }
+
+    // Only claim_v2 funds the rebate pool, the other claim paths refuse a referral account with a
+    // rebate, even a queued one, rather than paying its traders' part to the partner.
+    fn has_rebate(&self) -> bool {
+        self.rebate_bps > 0 || self.pending_rebate.is_some()
+    }
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Debug, Clone, Copy)]
+pub struct PendingShareChange {
+    share_bps: u16,
+    effective_at: i64,
+}
+
+impl PendingShareChange {
+    const LEN: usize = 2 + 8;
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Debug, Clone, Copy)]
+pub struct PendingNoticePeriodChange {
+    notice_period: i64,
+    effective_at: i64,
+}
+
+impl PendingNoticePeriodChange {
+    const LEN: usize = 8 + 8;
+}
+
+// Each flag allows referral token accounts for mints with an extension that can leave referral
+// fees frozen, seized or stuck. Everything is rejected by default.
+#[derive(AnchorDeserialize, AnchorSerialize, Debug, Clone, Copy, Default)]
+pub struct MintExtensionPolicy {
+    pub allow_permanent_delegate: bool,
+    pub allow_default_frozen: bool,
+    pub allow_non_transferable: bool,
+    pub allow_pausable: bool,
+}
+
+impl MintExtensionPolicy {
+    const LEN: usize = 1 + 1 + 1 + 1;
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Debug, Clone, Copy)]
+pub struct AdminTokenAccountPolicy {
+    // The mint must be accepted by the project mint policy.
+    pub require_allowed_mint: bool,
+    // A referral token account of the project must hold at least this much of the mint, zero
+    // disables the check. Requires require_allowed_mint, as anyone can fund an account of their
+    // own mint.
+    pub min_referral_balance: u64,
+    // Lamports the project authority may spend on admin token accounts per epoch, zero stops it
+    // paying for them.
+    pub epoch_budget_lamports: u64,
+}
+
+impl AdminTokenAccountPolicy {
+    const LEN: usize = 1 + 8 + 8;
+}
+
+impl Default for AdminTokenAccountPolicy {
+    fn default() -> Self {
+        Self {
+            require_allowed_mint: false,
+            min_referral_balance: 0,
+            epoch_budget_lamports: DEFAULT_ADMIN_TOKEN_ACCOUNT_EPOCH_BUDGET_LAMPORTS,
+        }
+    }
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Debug, Clone, Copy, Default)]
+pub struct AttributionPolicy {
+    // A user may switch referral accounts while their attribution is still active.
+    pub allow_override: bool,
+    // Seconds an attribution stays active, zero keeps it forever.
+    pub expiry: i64,
+}
+
+impl AttributionPolicy {
+    const LEN: usize = 1 + 8;
+}
+
+// The referral account a user first came through, kept so later trades keep crediting it.
+#[account]
+pub struct UserAttribution {
+    project: Pubkey,
+    user: Pubkey,
+    referral_account: Pubkey,
+    attributed_at: i64,
 }
 
+impl UserAttribution {
+    const LEN: usize = 8 + 32 + 32 + 32 + 8;
+
+    fn is_active(&self, policy: &AttributionPolicy, now: i64) -> bool {
+        policy.expiry == 0 || self.attributed_at.saturating_add(policy.expiry) > now
+    }
+}
+
+#[account]
+pub struct PartnerSponsorship {
+    project: Pubkey,
+    partner: Pubkey,
+    // Rent the project authority has paid for the partner so far.
+    sponsored_lamports: u64,
+}
+
+impl PartnerSponsorship {
+    const LEN: usize = 8 + 32 + 32 + 8;
+}
+
+#[account]
+pub struct ProjectMintPolicy {
+    project: Pubkey,
+    mode: MintPolicyMode,
+    mints: Vec<Pubkey>,
+}
+
+impl ProjectMintPolicy {
+    const LEN: usize = 8 + 32 + 1 + 4 + 32 * MAX_MINT_POLICY_MINTS;
+
+    fn is_allowed(&self, mint: &Pubkey) -> bool {
+        let listed = self.mints.contains(mint);
+        match self.mode {
+            MintPolicyMode::Allow => listed,
+            MintPolicyMode::Deny => !listed,
+        }
+    }
+}
+
+#[account]
+pub struct ProjectClaimThresholds {
+    project: Pubkey,
+    // Applies to mints without their own minimum.
+    default_min_amount: u64,
+    mints: Vec<MintClaimThreshold>,
+}
+
+impl ProjectClaimThresholds {
+    const LEN: usize = 8 + 32 + 8 + 4 + MintClaimThreshold::LEN * MAX_CLAIM_THRESHOLD_MINTS;
+
+    fn min_amount(&self, mint: &Pubkey) -> u64 {
+        self.mint_threshold(mint)
+            .and_then(|threshold| threshold.min_amount)
+            .unwrap_or(self.default_min_amount)
+    }
+
+    // Mints without a cap pay no keeper fee.
+    fn max_keeper_fee(&self, mint: &Pubkey) -> u64 {
+        self.mint_threshold(mint)
+            .map_or(0, |threshold| threshold.max_keeper_fee)
+    }
+
+    fn mint_threshold(&self, mint: &Pubkey) -> Option<&MintClaimThreshold> {
+        self.mints.iter().find(|threshold| threshold.mint == *mint)
+    }
+
+    // Applies `update` to the entry of the mint, adding it if needed, and drops the entry again once
+    // it holds neither a minimum nor a keeper fee cap.
+    fn update_mint_threshold(
+        &mut self,
+        mint: Pubkey,
+        update: impl FnOnce(&mut MintClaimThreshold),
+    ) -> Result<()> {
+        let index = match self
+            .mints
+            .iter()
+            .position(|threshold| threshold.mint == mint)
+        {
+            Some(index) => index,
+            None => {
+                require!(
+                    self.mints.len() < MAX_CLAIM_THRESHOLD_MINTS,
+                    ProgramErrorCode::ClaimThresholdsFull
+                );
+                self.mints.push(MintClaimThreshold {
+                    mint,
+                    min_amount: None,
+                    max_keeper_fee: 0,
+                });
+                self.mints.len() - 1
+            }
+        };
+
+        update(&mut self.mints[index]);
+
+        let threshold = &self.mints[index];
+        if threshold.min_amount.is_none() && threshold.max_keeper_fee == 0 {
+            self.mints.swap_remove(index);
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Debug, Clone, Copy)]
+pub struct MintClaimThreshold {
+    mint: Pubkey,
+    // None when the mint uses the default minimum.
+    min_amount: Option<u64>,
+    // Upper bound on the keeper fee of a single claim, in the mint's base units.
+    max_keeper_fee: u64,
+}
+
+impl MintClaimThreshold {
+    const LEN: usize = 32 + 9 + 8;
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MintPolicyMode {
+    // Only the listed mints are accepted.
+    Allow,
+    // Every mint but the listed ones is accepted.
+    Deny,
+}
+
+// Points dashboards at an off-chain JSON document, with logo, website and description for a
+// project or display label and social handle for a referral account. The hash pins its content.
+#[account]
+pub struct Metadata {
+    // The project or referral account described.
+    target: Pubkey,
+    uri: String,
+    content_hash: [u8; 32],
+}
+
+impl Metadata {
+    const LEN: usize = 8 + 32 + 4 + MAX_METADATA_URI_LENGTH + 32;
+
+    fn set(&mut self, target: Pubkey, params: SetMetadataParams) -> Result<()> {
+        require!(
+            params.uri.len() <= MAX_METADATA_URI_LENGTH,
+            ProgramErrorCode::MetadataUriTooLong
+        );
+
+        self.target = target;
+        self.uri = params.uri;
+        self.content_hash = params.content_hash;
+
+        Ok(())
+    }
+}
+
+// A short code of a project crediting one of its referral accounts, partners can have several.
+#[account]
+pub struct Alias {
+    project: Pubkey,
+    referral_account: Pubkey,
+    alias: String,
+}
+
+impl Alias {
+    const LEN: usize = 8 + 32 + 32 + 4 + MAX_ALIAS_LENGTH;
+}
+
+// Holds the rebates a referral account set aside in one mint, until traders claim them against the
+// Merkle root the project admin publishes for the current round.
+#[account]
+pub struct RebatePool {
+    project: Pubkey,
+    referral_account: Pubkey,
+    mint: Pubkey,
+    // Bumped with every published root, so claims of a round are tracked by its own bitmaps.
+    round: u64,
+    merkle_root: [u8; 32],
+}
+
+impl RebatePool {
+    const LEN: usize = 8 + 32 + 32 + 32 + 8 + 32;
+}
+
+// Claimed leaf indexes of one chunk of a rebate round.
+#[account]
+pub struct RebateBitmap {
+    rebate_pool: Pubkey,
+    round: u64,
+    chunk: u64,
+    bits: [u8; REBATE_BITMAP_BYTES],
+}
+
+impl RebateBitmap {
+    const LEN: usize = 8 + 32 + 8 + 8 + REBATE_BITMAP_BYTES;
+
+    fn chunk(index: u64) -> u64 {
+        index / REBATE_BITMAP_BITS
+    }
+
+    // Marks the index as claimed, returning false when it already was.
+    fn claim(&mut self, index: u64) -> bool {
+        let bit = (index % REBATE_BITMAP_BITS) as usize;
+        let mask = 1u8 << (bit % 8);
+        if self.bits[bit / 8] & mask != 0 {
+            return false;
+        }
+        self.bits[bit / 8] |= mask;
+        true
+    }
+}
+
+// Running totals of the fees an integrating program paid a referral account in one mint through
+// collect_fee, per authority and source tag.
+#[account]
+pub struct FeeStats {
+    referral_account: Pubkey,
+    mint: Pubkey,
+    // Signer of the collections, so one integration can't inflate the stats of another.
+    authority: Pubkey,
+    source_tag: String,
+    // Volume the fees were charged on, as reported by the integrating program.
+    gross_volume: u64,
+    fee_amount: u64,
+    collections: u64,
+}
+
+impl FeeStats {
+    const LEN: usize = 8 + 32 + 32 + 32 + 4 + MAX_SOURCE_TAG_LENGTH + 8 + 8 + 8;
+}
+
+#[account]
+pub struct ProjectProposal {
+    project: Pubkey,
+    proposer: Pubkey,
+    id: u64,
+    action: ProjectAction,
+    approvals: Vec<Pubkey>,
+    expires_at: i64,
+    executed: bool,
+}
+
+impl ProjectProposal {
+    const LEN: usize = 8 + 32 + 32 + 8 + ProjectAction::LEN + 4 + 32 * MAX_MULTISIG_SIGNERS + 8 + 1;
+}
+
+// Admin-gated actions that a project with a multisig executes through a proposal.
+#[derive(AnchorDeserialize, AnchorSerialize, Debug, Clone)]
+pub enum ProjectAction {
+    UpdateProject(UpdateProjectParams),
+    UpdateReferralAccount {
+        referral_account: Pubkey,
+        share_bps: u16,
+    },
+    // The referral accounts are passed in remaining_accounts on execution, in the same order.
+    UpdateReferralAccounts {
+        referral_accounts: Vec<Pubkey>,
+        share_bps: Vec<u16>,
+    },
+    TransferProject {
+        new_admin: Pubkey,
+    },
+    WithdrawFromProject(WithdrawFromProjectParams),
+    SetMultisig(SetProjectMultisigParams),
+    SetReferralAccountShareMode {
+        referral_account: Pubkey,
+        inherit_default: bool,
+    },
+    SetSwapProgram(SetProjectSwapProgramParams),
+    SetMintExtensionPolicy(SetProjectMintExtensionPolicyParams),
+    SetMintPolicyMode(SetProjectMintPolicyModeParams),
+    AddPolicyMint(ProjectPolicyMintParams),
+    RemovePolicyMint(ProjectPolicyMintParams),
+    SetMinClaimAmount(SetProjectMinClaimAmountParams),
+    SetKeeperFee(SetProjectKeeperFeeParams),
+    SetMaxKeeperFee(SetProjectMaxKeeperFeeParams),
+    SetSponsorship(SetProjectSponsorshipParams),
+    SetAdminTokenAccountPolicy(SetProjectAdminTokenAccountPolicyParams),
+    SetMetadata(SetMetadataParams),
+    SetAttributionPolicy(SetProjectAttributionPolicyParams),
+    SetReferralAccountRebate {
+        referral_account: Pubkey,
+        rebate_bps: u16,
+    },
+    PublishRebateRoot {
+        rebate_pool: Pubkey,
+        merkle_root: [u8; 32],
+    },
+}
+
+impl ProjectAction {
+    // SetMultisig with a full signer set is the largest variant.
+    const LEN: usize = 1 + 4 + 32 * MAX_MULTISIG_SIGNERS + 1;
+}
+
+const MAX_PROPOSAL_REFERRAL_ACCOUNT_UPDATES: usize = 9;
+const _: () =
+    assert!(1 + 4 + (32 + 2) * MAX_PROPOSAL_REFERRAL_ACCOUNT_UPDATES + 4 <= ProjectAction::LEN);
+
 #[error_code]
 pub enum ProgramErrorCode {
     InvalidCalculation,
     InvalidSharePercentage,
     NameTooLong,
+    MultisigRequired,
+    InvalidMultisigConfig,
+    NotMultisigSigner,
+    InvalidProposalExpiry,
+    ProposalExpired,
+    ProposalAlreadyExecuted,
+    ProposalAlreadyApproved,
+    ProposalThresholdNotMet,
+    ProposalNotClosable,
+    MissingProposalAccount,
+    InvalidNoticePeriod,
+    ShareModeChangeDecreasesShare,
+    InvalidReferralAccountsUpdate,
+    ClaimAmountExceedsBalance,
+    InvalidClaimManyAccounts,
+    MissingPayoutTokenAccount,
+    SwapProgramNotAllowed,
+    SwapInputNotConsumed,
+    SwapOutputTooLow,
+    MintHasPermanentDelegate,
+    MintDefaultFrozen,
+    MintNonTransferable,
+    MintPausable,
+    MissingMintPolicyAccount,
+    MintNotAllowed,
+    MintPolicyFull,
+    MintAlreadyInPolicy,
+    MintNotInPolicy,
+    MissingClaimThresholdsAccount,
+    ClaimAmountBelowMinimum,
+    ClaimThresholdsFull,
+    SponsorshipDisabled,
+    SponsorshipCapExceeded,
+    MissingReferralTokenAccount,
+    ReferralBalanceTooLow,
+    EpochBudgetExceeded,
+    MetadataUriTooLong,
+    InvalidAlias,
+    InvalidAttributionExpiry,
+    UserAlreadyAttributed,
+    RebateRequiresClaimV2,
+    MissingRebatePoolAccount,
+    InvalidRebatePoolAccount,
+    InvalidMerkleProof,
+    RebateAlreadyClaimed,
+    InvalidSourceTag,
+    MintAllowlistRequired,
+    InvalidReferralTokenAccount,
+    InvalidFeeAmount,
+    InvalidKeeperFee,
+    ReferralAccountNotMigrated,
 }
 
 const MAX_PROJECT_NAME_LENGTH: usize = 50 * 4; // 50 chars max.
 const MAX_REFERRAL_ACCOUNT_NAME_LENGTH: usize = 50 * 4; // 50 chars max.
+const MAX_MULTISIG_SIGNERS: usize = 10;
+const ACCOUNT_RESERVED_LEN: usize = 64;
+// The IDL parser only resolves array lengths given as literals, so carve-outs of the reserved
+// space are spelled out and checked below.
+const PROJECT_RESERVED_LEN: usize = 55;
+const _: () = assert!(PROJECT_RESERVED_LEN == ACCOUNT_RESERVED_LEN - AttributionPolicy::LEN);
+// The IDL parser substitutes consts by suffix, so this name must not end in ACCOUNT_RESERVED_LEN.
+const REFERRAL_RESERVED_LEN: usize = 51;
+const _: () =
+    assert!(REFERRAL_RESERVED_LEN == ACCOUNT_RESERVED_LEN - 2 - 1 - PendingShareChange::LEN);
+const MAX_METADATA_URI_LENGTH: usize = 200;
+// Aliases are used as a PDA seed, which is capped at 32 bytes.
+const MAX_ALIAS_LENGTH: usize = 32;
+// Source tags seed the fee stats PDA as well.
+const MAX_SOURCE_TAG_LENGTH: usize = 32;
+const MAX_MINT_POLICY_MINTS: usize = 64;
+const MAX_CLAIM_THRESHOLD_MINTS: usize = 32;
+// Enough for a few dozen admin token accounts per epoch.
+const DEFAULT_ADMIN_TOKEN_ACCOUNT_EPOCH_BUDGET_LAMPORTS: u64 = 100_000_000;
+// The keeper fee takes effect without a notice period, so it's kept to a small part of a claim.
+const MAX_KEEPER_FEE_BPS: u16 = 100;
+const REBATE_BITMAP_BYTES: usize = 512;
+const REBATE_BITMAP_BITS: u64 = REBATE_BITMAP_BYTES as u64 * 8;
@@ -0,0 +1,257 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{hash::hashv, program_pack::Pack},
+    system_program,
+};
+use anchor_spl::token_2022::spl_token_2022::{
+    self,
+    extension::{
+        default_account_state::DefaultAccountState, non_transferable::NonTransferable,
+        permanent_delegate::PermanentDelegate, transfer_fee::TransferFeeConfig,
+        BaseStateWithExtensions, StateWithExtensions,
+    },
+    onchain::invoke_transfer_checked,
+    state::{Account as TokenAccountState, AccountState},
+};
+
+use crate::{
+    MintExtensionPolicy, ProgramErrorCode, Project, ProjectClaimThresholds, ProjectMintPolicy,
+};
+
+const DENOMINATOR: u128 = 10_000;
+
+// Newer than the Token-2022 version the program builds against, so looked up by its TLV type.
+const PAUSABLE_CONFIG_EXTENSION_TYPE: u16 = 26;
+
+// Dependent on the referral share bps, calculate the referral part of a claimed amount.
+pub(crate) fn calculate_referral_amount(amount: u64, share_bps: u16) -> Result<u64> {
+    let referral_amount = u128::from(amount)
+        .checked_mul(u128::from(share_bps))
+        .ok_or(ProgramErrorCode::InvalidCalculation)?
+        .checked_div(DENOMINATOR)
+        .ok_or(ProgramErrorCode::InvalidCalculation)?
+        .try_into()
+        .map_err(|_| ProgramErrorCode::InvalidCalculation)?;
+
+    Ok(referral_amount)
+}
+
+// Fee withheld by a Token-2022 TransferFee mint when transferring the amount, zero otherwise.
+pub(crate) fn calculate_transfer_fee(mint: &AccountInfo, amount: u64) -> Result<u64> {
+    if *mint.owner != spl_token_2022::ID {
+        return Ok(0);
+    }
+
+    let mint_data = mint.try_borrow_data()?;
+    let mint_state = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&mint_data)?;
+    let Ok(transfer_fee_config) = mint_state.get_extension::<TransferFeeConfig>() else {
+        return Ok(0);
+    };
+
+    let fee = transfer_fee_config
+        .calculate_epoch_fee(Clock::get()?.epoch, amount)
+        .ok_or(ProgramErrorCode::InvalidCalculation)?;
+
+    Ok(fee)
+}
+
+// transfer_checked that also forwards the transfer hook accounts of the mint, looked up by address in
+// the additional accounts so the same slice can serve several transfers of the mint.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn transfer_checked_with_hook<'info>(
+    token_program: AccountInfo<'info>,
+    from: AccountInfo<'info>,
+    mint: AccountInfo<'info>,
+    to: AccountInfo<'info>,
+    authority: AccountInfo<'info>,
+    additional_accounts: &[AccountInfo<'info>],
+    amount: u64,
+    decimals: u8,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    invoke_transfer_checked(
+        token_program.key,
+        from,
+        mint,
+        to,
+        authority,
+        additional_accounts,
+        amount,
+        decimals,
+        signer_seeds,
+    )?;
+
+    Ok(())
+}
+
+// Rejects Token-2022 mints whose extensions could freeze, seize or lock the referral fees, unless
+// the project policy allows them.
+pub(crate) fn check_mint_extensions(
+    mint: &AccountInfo,
+    policy: &MintExtensionPolicy,
+) -> Result<()> {
+    if *mint.owner != spl_token_2022::ID {
+        return Ok(());
+    }
+
+    let mint_data = mint.try_borrow_data()?;
+    let mint_state = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&mint_data)?;
+
+    if let Ok(permanent_delegate) = mint_state.get_extension::<PermanentDelegate>() {
+        require!(
+            policy.allow_permanent_delegate
+                || Option::<Pubkey>::from(permanent_delegate.delegate).is_none(),
+            ProgramErrorCode::MintHasPermanentDelegate
+        );
+    }
+    if let Ok(default_account_state) = mint_state.get_extension::<DefaultAccountState>() {
+        require!(
+            policy.allow_default_frozen
+                || default_account_state.state != AccountState::Frozen as u8,
+            ProgramErrorCode::MintDefaultFrozen
+        );
+    }
+    require!(
+        policy.allow_non_transferable || mint_state.get_extension::<NonTransferable>().is_err(),
+        ProgramErrorCode::MintNonTransferable
+    );
+    require!(
+        policy.allow_pausable || !has_extension_type(&mint_data, PAUSABLE_CONFIG_EXTENSION_TYPE),
+        ProgramErrorCode::MintPausable
+    );
+
+    Ok(())
+}
+
+// Walks the TLV entries following the account type byte of a Token-2022 mint or account.
+fn has_extension_type(data: &[u8], extension_type: u16) -> bool {
+    let mut offset = TokenAccountState::LEN + 1;
+    while let Some(header) = data.get(offset..offset + 4) {
+        let entry_type = u16::from_le_bytes([header[0], header[1]]);
+        if entry_type == 0 {
+            return false;
+        }
+        if entry_type == extension_type {
+            return true;
+        }
+        offset += 4 + usize::from(u16::from_le_bytes([header[2], header[3]]));
+    }
+
+    false
+}
+
+// Once a project has a mint policy the policy account must be passed, so it cannot be bypassed by
+// leaving the optional account out.
+pub(crate) fn check_mint_policy(
+    project: &Project,
+    mint_policy: Option<&ProjectMintPolicy>,
+    mint: &Pubkey,
+) -> Result<()> {
+    if !project.has_mint_policy {
+        return Ok(());
+    }
+
+    let mint_policy = mint_policy.ok_or(ProgramErrorCode::MissingMintPolicyAccount)?;
+    require!(
+        mint_policy.is_allowed(mint),
+        ProgramErrorCode::MintNotAllowed
+    );
+
+    Ok(())
+}
+
+// Rejects claims below the project minimum for the mint, with the same forced account as the mint policy.
+pub(crate) fn check_min_claim_amount(
+    project: &Project,
+    claim_thresholds: Option<&ProjectClaimThresholds>,
+    mint: &Pubkey,
+    amount: u64,
+) -> Result<()> {
+    if !project.has_claim_thresholds {
+        return Ok(());
+    }
+
+    let claim_thresholds =
+        claim_thresholds.ok_or(ProgramErrorCode::MissingClaimThresholdsAccount)?;
+    require!(
+        amount >= claim_thresholds.min_amount(mint),
+        ProgramErrorCode::ClaimAmountBelowMinimum
+    );
+
+    Ok(())
+}
+
+// Zeroes the bytes of an account created before versioning past its `T` layout. They were never
+// read back, so they can hold leftovers of a longer value, like a name that was shortened since.
+pub(crate) fn clear_legacy_padding<T: AnchorDeserialize + AnchorSerialize>(
+    account: &AccountInfo,
+) -> Result<()> {
+    let mut data = account.try_borrow_mut_data()?;
+    let legacy_len = T::deserialize(&mut &data[8..])
+        .and_then(|legacy| legacy.try_to_vec())
+        .map_err(|_| ErrorCode::AccountDidNotDeserialize)?
+        .len();
+    data[8 + legacy_len..].fill(0);
+
+    Ok(())
+}
+
+// Grows an account created under an older, shorter layout to `len`, the payer covers the extra rent.
+// The new bytes are zeroed, so fields appended since then read as their zero value.
+pub(crate) fn realloc_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    len: usize,
+) -> Result<()> {
+    if account.data_len() >= len {
+        return Ok(());
+    }
+
+    let rent = Rent::get()?
+        .minimum_balance(len)
+        .saturating_sub(account.lamports());
+    if rent > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                system_program::Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            rent,
+        )?;
+    }
+
+    account.realloc(len, true)?;
+
+    Ok(())
+}
+
+// A rebate tree leaf, hashed with the round so a root can't replay claims of an earlier one.
+pub(crate) fn rebate_leaf(round: u64, index: u64, trader: &Pubkey, amount: u64) -> [u8; 32] {
+    hashv(&[
+        &[0],
+        &round.to_le_bytes(),
+        &index.to_le_bytes(),
+        trader.as_ref(),
+        &amount.to_le_bytes(),
+    ])
+    .to_bytes()
+}
+
+// Leaves and nodes use distinct prefixes and each pair is hashed in sorted order, so a proof is
+// just the sibling hashes from the leaf up.
+pub(crate) fn verify_merkle_proof(proof: &[[u8; 32]], root: &[u8; 32], leaf: [u8; 32]) -> bool {
+    let computed = proof.iter().fold(leaf, |node, sibling| {
+        let (left, right) = if node <= *sibling {
+            (node, *sibling)
+        } else {
+            (*sibling, node)
+        };
+        hashv(&[&[1], &left, &right]).to_bytes()
+    });
+
+    computed == *root
+}
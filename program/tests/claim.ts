@@ -10,6 +10,7 @@ import {
   fundAccount,
   fundTokenAccount,
   getAccountBalance,
+  setReferralAccountRebate,
 } from "./helpers/helpers";
 
 describe("program", () => {
@@ -187,6 +188,40 @@ describe("program", () => {
           );
         });
 
+        it("raised if the referral account has a rebate", async () => {
+          await setReferralAccountRebate(
+            projectPubkey,
+            referralAccountKeypair.publicKey,
+            admin.payer,
+            1000,
+          );
+
+          try {
+            await program.methods
+              .claim()
+              .accounts({
+                payer: admin.payer.publicKey,
+                admin: admin.payer.publicKey,
+                partner: partner.publicKey,
+                project: projectPubkey,
+                projectAdminTokenAccount,
+                referralAccount: referralAccountKeypair.publicKey,
+                referralTokenAccount,
+                partnerTokenAccount,
+                mint: token,
+                tokenProgram: tokenProgram.programId,
+              })
+              .signers([admin.payer])
+              .rpc();
+
+            chai.assert(false, "should've failed but didn't");
+          } catch (_err) {
+            expect(_err).to.be.instanceOf(AnchorError);
+            const err: AnchorError = _err;
+            expect(err.error.errorCode.code).to.equal("RebateRequiresClaimV2");
+          }
+        });
+
         it("raised if project admin token account is wrong", async () => {
           try {
             await program.methods
@@ -0,0 +1,310 @@
+import * as anchor from "@coral-xyz/anchor";
+import { AnchorError, BN, Program } from "@coral-xyz/anchor";
+import { ASSOCIATED_PROGRAM_ID } from "@coral-xyz/anchor/dist/cjs/utils/token";
+import { SPL_TOKEN_PROGRAM_ID, splTokenProgram } from "@coral-xyz/spl-token";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { expect } from "chai";
+
+import { MockSwap } from "../target/types/mock_swap";
+import { Referral } from "../target/types/referral";
+import {
+  createAssociatedTokenAccountWithOffCurve,
+  createTokenAccount,
+  createTokenMint,
+  fundAccount,
+  fundTokenAccount,
+  getAccountBalance,
+  eventCpiAccounts,
+  setDefaultMinClaimAmount,
+  setReferralAccountRebate,
+} from "./helpers/helpers";
+
+describe("claim and swap", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.Referral as Program<Referral>;
+  const mockSwap = anchor.workspace.MockSwap as Program<MockSwap>;
+  const admin = anchor.workspace.Referral.provider.wallet;
+  const tokenProgram = splTokenProgram({
+    provider,
+    programId: SPL_TOKEN_PROGRAM_ID,
+  });
+
+  let base: anchor.web3.Keypair;
+  let partner: anchor.web3.Keypair;
+  let projectPubkey: anchor.web3.PublicKey;
+  let referralAccountPubkey: anchor.web3.PublicKey;
+  let referralTokenAccount: anchor.web3.PublicKey;
+  let token: anchor.web3.PublicKey;
+  let settlementMint: anchor.web3.PublicKey;
+  let vault: anchor.web3.PublicKey;
+  let referralName = "SwapReferral";
+  let projectName = "Referral";
+  let referralAmount = 1e8;
+  let defaultShareBps = 8000;
+  let amountOut = 4242;
+
+  const [mintAuthority] = anchor.web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("mint_authority")],
+    mockSwap.programId,
+  );
+
+  beforeEach(async () => {
+    base = anchor.web3.Keypair.generate();
+    partner = anchor.web3.Keypair.generate();
+    await fundAccount(partner.publicKey, provider);
+
+    [projectPubkey] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("project"), base.publicKey.toBuffer()],
+      program.programId,
+    );
+
+    await program.methods
+      .initializeProject({ name: projectName, defaultShareBps })
+      .accounts({
+        payer: admin.payer.publicKey,
+        base: base.publicKey,
+        admin: admin.payer.publicKey,
+        project: projectPubkey,
+      })
+      .signers([base, admin.payer])
+      .rpc();
+
+    await program.methods
+      .setProjectSwapProgram({ swapProgram: mockSwap.programId })
+      .accounts({
+        admin: admin.payer.publicKey,
+        project: projectPubkey,
+      })
+      .signers([admin.payer])
+      .rpc();
+
+    [referralAccountPubkey] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("referral"),
+        projectPubkey.toBuffer(),
+        Buffer.from(referralName),
+      ],
+      program.programId,
+    );
+
+    await program.methods
+      .initializeReferralAccountWithName({ name: referralName })
+      .accounts({
+        payer: partner.publicKey,
+        project: projectPubkey,
+        partner: partner.publicKey,
+        referralAccount: referralAccountPubkey,
+      })
+      .signers([partner])
+      .rpc();
+
+    token = await createTokenMint(tokenProgram, provider);
+    referralTokenAccount = await createAssociatedTokenAccountWithOffCurve(
+      token,
+      tokenProgram.programId,
+      referralAccountPubkey,
+      provider,
+    );
+    await fundTokenAccount(
+      referralTokenAccount,
+      token,
+      admin.payer,
+      referralAmount,
+      tokenProgram,
+    );
+    vault = await createTokenAccount(
+      token,
+      tokenProgram.programId,
+      admin.payer.publicKey,
+      provider,
+    );
+
+    // The mock swap program mints the settlement mint.
+    const settlementMintKeypair = anchor.web3.Keypair.generate();
+    const createMintIx = await tokenProgram.account.mint.createInstruction(
+      settlementMintKeypair,
+    );
+    const initMintIx = await tokenProgram.methods
+      .initializeMint2(0, mintAuthority, null)
+      .accounts({ mint: settlementMintKeypair.publicKey })
+      .instruction();
+    await provider.sendAndConfirm(
+      new anchor.web3.Transaction().add(createMintIx, initMintIx),
+      [settlementMintKeypair],
+    );
+    settlementMint = settlementMintKeypair.publicKey;
+  });
+
+  const claimAndSwap = async (
+    minimumOut: number,
+    {
+      signers = [admin.payer, partner],
+      destination = null,
+      claimThresholds = null,
+    }: {
+      signers?: anchor.web3.Keypair[];
+      destination?: anchor.web3.PublicKey | null;
+      claimThresholds?: anchor.web3.PublicKey | null;
+    } = {},
+  ) => {
+    const [swapAuthority] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("swap_authority"), referralAccountPubkey.toBuffer()],
+      program.programId,
+    );
+    const [swapTokenAccount] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("swap_token_account"), referralAccountPubkey.toBuffer()],
+      program.programId,
+    );
+    const partnerSettlementTokenAccount = getAssociatedTokenAddressSync(
+      settlementMint,
+      partner.publicKey,
+    );
+    const projectAdminTokenAccount = getAssociatedTokenAddressSync(
+      token,
+      admin.payer.publicKey,
+    );
+
+    // The swap output goes to the partner unless the caller routes it elsewhere.
+    const swapDestination = destination ?? partnerSettlementTokenAccount;
+
+    const swapData = mockSwap.coder.instruction.encode("swap", {
+      amountOut: new BN(amountOut),
+    });
+
+    await program.methods
+      .claimAndSwap({ swapData, minimumOut: new BN(minimumOut) })
+      .accountsStrict({
+        payer: signers[0].publicKey,
+        project: projectPubkey,
+        admin: admin.payer.publicKey,
+        projectAdminTokenAccount,
+        referralAccount: referralAccountPubkey,
+        referralTokenAccount,
+        partner: partner.publicKey,
+        partnerSettlementTokenAccount,
+        swapAuthority,
+        swapTokenAccount,
+        mint: token,
+        settlementMint,
+        swapProgram: mockSwap.programId,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        tokenProgram: tokenProgram.programId,
+        settlementTokenProgram: tokenProgram.programId,
+        associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+        mintPolicy: null,
+        claimThresholds,
+        ...eventCpiAccounts,
+      })
+      .remainingAccounts([
+        { pubkey: swapAuthority, isSigner: false, isWritable: false },
+        { pubkey: swapTokenAccount, isSigner: false, isWritable: true },
+        { pubkey: vault, isSigner: false, isWritable: true },
+        { pubkey: token, isSigner: false, isWritable: false },
+        { pubkey: swapDestination, isSigner: false, isWritable: true },
+        { pubkey: settlementMint, isSigner: false, isWritable: true },
+        { pubkey: mintAuthority, isSigner: false, isWritable: false },
+        { pubkey: tokenProgram.programId, isSigner: false, isWritable: false },
+        { pubkey: tokenProgram.programId, isSigner: false, isWritable: false },
+      ])
+      .signers(signers)
+      .rpc();
+
+    return { partnerSettlementTokenAccount, projectAdminTokenAccount };
+  };
+
+  it("delivers the swapped referral portion to the partner", async () => {
+    const { partnerSettlementTokenAccount, projectAdminTokenAccount } =
+      await claimAndSwap(amountOut);
+
+    expect(await getAccountBalance(referralTokenAccount, provider)).to.equal(0);
+    expect(await getAccountBalance(vault, provider)).to.equal(
+      (referralAmount * defaultShareBps) / 10000,
+    );
+    expect(
+      await getAccountBalance(projectAdminTokenAccount, provider),
+    ).to.equal((referralAmount * (10000 - defaultShareBps)) / 10000);
+    expect(
+      await getAccountBalance(partnerSettlementTokenAccount, provider),
+    ).to.equal(amountOut);
+  });
+
+  it("raised if the swap output is below the minimum", async () => {
+    try {
+      await claimAndSwap(amountOut + 1);
+
+      expect(false, "should've failed but didn't").to.be.true;
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      const err: AnchorError = _err;
+      expect(err.error.errorCode.code).to.equal("SwapOutputTooLow");
+    }
+  });
+
+  it("raised if the balance is below the minimum claim amount", async () => {
+    const claimThresholds = await setDefaultMinClaimAmount(
+      projectPubkey,
+      admin.payer,
+      referralAmount + 1,
+    );
+
+    try {
+      await claimAndSwap(amountOut, { claimThresholds });
+
+      expect(false, "should've failed but didn't").to.be.true;
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      const err: AnchorError = _err;
+      expect(err.error.errorCode.code).to.equal("ClaimAmountBelowMinimum");
+    }
+  });
+
+  it("raised if the referral account has a rebate", async () => {
+    await setReferralAccountRebate(
+      projectPubkey,
+      referralAccountPubkey,
+      admin.payer,
+      1000,
+    );
+
+    try {
+      await claimAndSwap(amountOut);
+
+      expect(false, "should've failed but didn't").to.be.true;
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      const err: AnchorError = _err;
+      expect(err.error.errorCode.code).to.equal("RebateRequiresClaimV2");
+    }
+  });
+
+  it("doesn't let a third party route the partner's portion", async () => {
+    const attacker = anchor.web3.Keypair.generate();
+    await fundAccount(attacker.publicKey, provider);
+    const attackerSettlementTokenAccount = await createTokenAccount(
+      settlementMint,
+      tokenProgram.programId,
+      attacker.publicKey,
+      provider,
+    );
+
+    try {
+      await claimAndSwap(0, {
+        signers: [attacker],
+        destination: attackerSettlementTokenAccount,
+      });
+
+      expect(false, "should've failed but didn't").to.be.true;
+    } catch (_err) {
+      expect(_err.message).to.include("Missing signature");
+    }
+
+    expect(await getAccountBalance(referralTokenAccount, provider)).to.equal(
+      referralAmount,
+    );
+    expect(
+      await getAccountBalance(attackerSettlementTokenAccount, provider),
+    ).to.equal(0);
+  });
+});
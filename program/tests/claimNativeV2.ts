@@ -0,0 +1,368 @@
+import * as anchor from "@coral-xyz/anchor";
+import { AnchorError, BN, Program } from "@coral-xyz/anchor";
+import { ASSOCIATED_PROGRAM_ID } from "@coral-xyz/anchor/dist/cjs/utils/token";
+import {
+  createSyncNativeInstruction,
+  getAssociatedTokenAddressSync,
+  NATIVE_MINT,
+  TOKEN_PROGRAM_ID,
+} from "@solana/spl-token";
+import { expect } from "chai";
+
+import { Referral } from "../target/types/referral";
+import {
+  createAssociatedTokenAccountWithOffCurve,
+  fundAccount,
+  getAccountBalance,
+  eventCpiAccounts,
+  setDefaultMinClaimAmount,
+  setReferralAccountRebate,
+} from "./helpers/helpers";
+
+describe("claim native v2", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.Referral as Program<Referral>;
+  const admin = anchor.workspace.Referral.provider.wallet;
+  let base: anchor.web3.Keypair;
+  let partner: anchor.web3.Keypair;
+  let projectPubkey: anchor.web3.PublicKey;
+  let referralAccountPubkey: anchor.web3.PublicKey;
+  let referralTokenAccount: anchor.web3.PublicKey;
+  let referralName = "NativeReferral";
+  let projectName = "Referral";
+  let referralAmount = 1e8;
+  let defaultShareBps = 8000;
+
+  beforeEach(async () => {
+    base = anchor.web3.Keypair.generate();
+    partner = anchor.web3.Keypair.generate();
+    await fundAccount(partner.publicKey, provider);
+
+    [projectPubkey] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("project"), base.publicKey.toBuffer()],
+      program.programId,
+    );
+
+    await program.methods
+      .initializeProject({ name: projectName, defaultShareBps })
+      .accounts({
+        payer: admin.payer.publicKey,
+        base: base.publicKey,
+        admin: admin.payer.publicKey,
+        project: projectPubkey,
+      })
+      .signers([base, admin.payer])
+      .rpc();
+
+    [referralAccountPubkey] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("referral"),
+        projectPubkey.toBuffer(),
+        Buffer.from(referralName),
+      ],
+      program.programId,
+    );
+
+    await program.methods
+      .initializeReferralAccountWithName({ name: referralName })
+      .accounts({
+        payer: partner.publicKey,
+        project: projectPubkey,
+        partner: partner.publicKey,
+        referralAccount: referralAccountPubkey,
+      })
+      .signers([partner])
+      .rpc();
+
+    referralTokenAccount = await createAssociatedTokenAccountWithOffCurve(
+      NATIVE_MINT,
+      TOKEN_PROGRAM_ID,
+      referralAccountPubkey,
+      provider,
+    );
+
+    const tx = new anchor.web3.Transaction().add(
+      anchor.web3.SystemProgram.transfer({
+        fromPubkey: admin.payer.publicKey,
+        toPubkey: referralTokenAccount,
+        lamports: referralAmount,
+      }),
+      createSyncNativeInstruction(referralTokenAccount),
+    );
+    await provider.sendAndConfirm(tx, [admin.payer]);
+  });
+
+  it("pays the referral portion out as native SOL", async () => {
+    const payer = anchor.web3.Keypair.generate();
+    await fundAccount(payer.publicKey, provider);
+
+    const [temporaryTokenAccount] =
+      anchor.web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("temporary_wsol"), referralAccountPubkey.toBuffer()],
+        program.programId,
+      );
+    const projectAdminTokenAccount = getAssociatedTokenAddressSync(
+      NATIVE_MINT,
+      admin.payer.publicKey,
+    );
+
+    const partnerLamportsBefore = await provider.connection.getBalance(
+      partner.publicKey,
+    );
+    const payerLamportsBefore = await provider.connection.getBalance(
+      payer.publicKey,
+    );
+
+    let claimNativeEvent;
+    const listener = program.addEventListener(
+      "ClaimNativeEvent",
+      (event) => {
+        claimNativeEvent = event;
+      },
+    );
+
+    await program.methods
+      .claimNativeV2({ unwrapReferral: true, unwrapProject: false })
+      .accountsStrict({
+        payer: payer.publicKey,
+        project: projectPubkey,
+        admin: admin.payer.publicKey,
+        projectAdminTokenAccount,
+        referralAccount: referralAccountPubkey,
+        referralTokenAccount,
+        partner: partner.publicKey,
+        partnerTokenAccount: null,
+        temporaryTokenAccount,
+        mint: NATIVE_MINT,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+        mintPolicy: null,
+        claimThresholds: null,
+        ...eventCpiAccounts,
+      })
+      .signers([payer])
+      .rpc();
+
+    await new Promise((resolve) => setTimeout(resolve, 1000));
+    await program.removeEventListener(listener);
+
+    const referralPortion = (referralAmount * defaultShareBps) / 10000;
+    expect(await getAccountBalance(referralTokenAccount, provider)).to.equal(0);
+    expect(
+      (await provider.connection.getBalance(partner.publicKey)) -
+        partnerLamportsBefore,
+    ).to.equal(referralPortion);
+    expect(
+      await getAccountBalance(projectAdminTokenAccount, provider),
+    ).to.equal(referralAmount - referralPortion);
+    expect(await provider.connection.getAccountInfo(temporaryTokenAccount)).to
+      .be.null;
+
+    // The payer only covers the transaction fee and the admin token account rent.
+    const adminTokenAccountRent =
+      await provider.connection.getMinimumBalanceForRentExemption(165);
+    expect(
+      payerLamportsBefore -
+        (await provider.connection.getBalance(payer.publicKey)),
+    ).to.equal(adminTokenAccountRent + 5000);
+
+    expect(claimNativeEvent.partner).to.eql(partner.publicKey);
+    expect(claimNativeEvent.partnerTokenAccount).to.be.null;
+    expect(claimNativeEvent.admin).to.eql(admin.payer.publicKey);
+    expect(claimNativeEvent.projectAdminTokenAccount).to.eql(
+      projectAdminTokenAccount,
+    );
+    expect(claimNativeEvent.unwrapReferral).to.be.true;
+    expect(claimNativeEvent.unwrapProject).to.be.false;
+    expect(claimNativeEvent.unwrappedAmount.toNumber()).to.equal(
+      referralPortion,
+    );
+  });
+
+  it("keeps a leg wrapped when it can't fund an empty wallet", async () => {
+    // An unfunded partner can't receive less than the rent exemption as native SOL.
+    const emptyPartner = anchor.web3.Keypair.generate();
+    const smallReferralName = "SmallNativeReferral";
+    const smallAmount = 100_000;
+
+    const [smallReferralAccount] =
+      anchor.web3.PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("referral"),
+          projectPubkey.toBuffer(),
+          Buffer.from(smallReferralName),
+        ],
+        program.programId,
+      );
+    await program.methods
+      .initializeReferralAccountWithName({ name: smallReferralName })
+      .accounts({
+        payer: admin.payer.publicKey,
+        project: projectPubkey,
+        partner: emptyPartner.publicKey,
+        referralAccount: smallReferralAccount,
+      })
+      .signers([admin.payer])
+      .rpc();
+
+    const smallReferralTokenAccount =
+      await createAssociatedTokenAccountWithOffCurve(
+        NATIVE_MINT,
+        TOKEN_PROGRAM_ID,
+        smallReferralAccount,
+        provider,
+      );
+    await provider.sendAndConfirm(
+      new anchor.web3.Transaction().add(
+        anchor.web3.SystemProgram.transfer({
+          fromPubkey: admin.payer.publicKey,
+          toPubkey: smallReferralTokenAccount,
+          lamports: smallAmount,
+        }),
+        createSyncNativeInstruction(smallReferralTokenAccount),
+      ),
+      [admin.payer],
+    );
+
+    const [temporaryTokenAccount] =
+      anchor.web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("temporary_wsol"), smallReferralAccount.toBuffer()],
+        program.programId,
+      );
+    const partnerTokenAccount = getAssociatedTokenAddressSync(
+      NATIVE_MINT,
+      emptyPartner.publicKey,
+    );
+
+    await program.methods
+      .claimNativeV2({ unwrapReferral: true, unwrapProject: false })
+      .accountsStrict({
+        payer: admin.payer.publicKey,
+        project: projectPubkey,
+        admin: admin.payer.publicKey,
+        projectAdminTokenAccount: getAssociatedTokenAddressSync(
+          NATIVE_MINT,
+          admin.payer.publicKey,
+        ),
+        referralAccount: smallReferralAccount,
+        referralTokenAccount: smallReferralTokenAccount,
+        partner: emptyPartner.publicKey,
+        partnerTokenAccount,
+        temporaryTokenAccount,
+        mint: NATIVE_MINT,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+        mintPolicy: null,
+        claimThresholds: null,
+        ...eventCpiAccounts,
+      })
+      .signers([admin.payer])
+      .rpc();
+
+    expect(await provider.connection.getBalance(emptyPartner.publicKey)).to.equal(
+      0,
+    );
+    expect(await getAccountBalance(partnerTokenAccount, provider)).to.equal(
+      (smallAmount * defaultShareBps) / 10000,
+    );
+  });
+
+  it("raised if the balance is below the minimum claim amount", async () => {
+    const claimThresholds = await setDefaultMinClaimAmount(
+      projectPubkey,
+      admin.payer,
+      referralAmount + 1,
+    );
+    const [temporaryTokenAccount] =
+      anchor.web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("temporary_wsol"), referralAccountPubkey.toBuffer()],
+        program.programId,
+      );
+
+    try {
+      await program.methods
+        .claimNativeV2({ unwrapReferral: true, unwrapProject: false })
+        .accountsStrict({
+          payer: admin.payer.publicKey,
+          project: projectPubkey,
+          admin: admin.payer.publicKey,
+          projectAdminTokenAccount: getAssociatedTokenAddressSync(
+            NATIVE_MINT,
+            admin.payer.publicKey,
+          ),
+          referralAccount: referralAccountPubkey,
+          referralTokenAccount,
+          partner: partner.publicKey,
+          partnerTokenAccount: null,
+          temporaryTokenAccount,
+          mint: NATIVE_MINT,
+          systemProgram: anchor.web3.SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+          mintPolicy: null,
+          claimThresholds,
+          ...eventCpiAccounts,
+        })
+        .signers([admin.payer])
+        .rpc();
+
+      expect(false, "should've failed but didn't").to.be.true;
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      const err: AnchorError = _err;
+      expect(err.error.errorCode.code).to.equal("ClaimAmountBelowMinimum");
+    }
+  });
+
+  it("raised if the referral account has a rebate", async () => {
+    await setReferralAccountRebate(
+      projectPubkey,
+      referralAccountPubkey,
+      admin.payer,
+      1000,
+    );
+    const [temporaryTokenAccount] =
+      anchor.web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("temporary_wsol"), referralAccountPubkey.toBuffer()],
+        program.programId,
+      );
+
+    try {
+      await program.methods
+        .claimNativeV2({ unwrapReferral: true, unwrapProject: false })
+        .accountsStrict({
+          payer: admin.payer.publicKey,
+          project: projectPubkey,
+          admin: admin.payer.publicKey,
+          projectAdminTokenAccount: getAssociatedTokenAddressSync(
+            NATIVE_MINT,
+            admin.payer.publicKey,
+          ),
+          referralAccount: referralAccountPubkey,
+          referralTokenAccount,
+          partner: partner.publicKey,
+          partnerTokenAccount: null,
+          temporaryTokenAccount,
+          mint: NATIVE_MINT,
+          systemProgram: anchor.web3.SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+          mintPolicy: null,
+          claimThresholds: null,
+          ...eventCpiAccounts,
+        })
+        .signers([admin.payer])
+        .rpc();
+
+      expect(false, "should've failed but didn't").to.be.true;
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      const err: AnchorError = _err;
+      expect(err.error.errorCode.code).to.equal("RebateRequiresClaimV2");
+    }
+  });
+});
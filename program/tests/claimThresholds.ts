@@ -0,0 +1,182 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN, Program } from "@coral-xyz/anchor";
+import { ASSOCIATED_PROGRAM_ID } from "@coral-xyz/anchor/dist/cjs/utils/token";
+import { SPL_TOKEN_PROGRAM_ID, splTokenProgram } from "@coral-xyz/spl-token";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { expect } from "chai";
+
+import { Referral } from "../target/types/referral";
+import {
+  createAssociatedTokenAccountWithOffCurve,
+  createTokenMint,
+  fundAccount,
+  fundTokenAccount,
+  getAccountBalance,
+  eventCpiAccounts,
+  expectError,
+} from "./helpers/helpers";
+
+describe("claim thresholds", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.Referral as Program<Referral>;
+  const admin = anchor.workspace.Referral.provider.wallet;
+  const tokenProgram = splTokenProgram({
+    provider,
+    programId: SPL_TOKEN_PROGRAM_ID,
+  });
+
+  let base: anchor.web3.Keypair;
+  let partner: anchor.web3.Keypair;
+  let projectPubkey: anchor.web3.PublicKey;
+  let claimThresholdsPubkey: anchor.web3.PublicKey;
+  let referralAccountPubkey: anchor.web3.PublicKey;
+  let referralTokenAccount: anchor.web3.PublicKey;
+  let token: anchor.web3.PublicKey;
+  let referralName = "ThresholdReferral";
+  let referralAmount = 1000;
+
+  const setMinClaimAmount = async (
+    mint: anchor.web3.PublicKey | null,
+    minAmount: number | null,
+  ) => {
+    await program.methods
+      .setProjectMinClaimAmount({
+        mint,
+        minAmount: minAmount === null ? null : new BN(minAmount),
+      })
+      .accounts({
+        admin: admin.payer.publicKey,
+        project: projectPubkey,
+        claimThresholds: claimThresholdsPubkey,
+      })
+      .signers([admin.payer])
+      .rpc();
+  };
+
+  const claimV2 = async (
+    claimThresholds: anchor.web3.PublicKey | null = claimThresholdsPubkey,
+  ) => {
+    await program.methods
+      .claimV2()
+      .accountsStrict({
+        payer: admin.payer.publicKey,
+        admin: admin.payer.publicKey,
+        partner: partner.publicKey,
+        project: projectPubkey,
+        projectAdminTokenAccount: getAssociatedTokenAddressSync(
+          token,
+          admin.payer.publicKey,
+        ),
+        referralAccount: referralAccountPubkey,
+        referralTokenAccount,
+        partnerTokenAccount: getAssociatedTokenAddressSync(
+          token,
+          partner.publicKey,
+        ),
+        mint: token,
+        tokenProgram: tokenProgram.programId,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+        mintPolicy: null,
+        claimThresholds,
+        keeperTokenAccount: null,
+        rebatePoolTokenAccount: null,
+        ...eventCpiAccounts,
+      })
+      .signers([admin.payer])
+      .rpc();
+  };
+
+  beforeEach(async () => {
+    base = anchor.web3.Keypair.generate();
+    partner = anchor.web3.Keypair.generate();
+    await fundAccount(partner.publicKey, provider);
+
+    [projectPubkey] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("project"), base.publicKey.toBuffer()],
+      program.programId,
+    );
+    [claimThresholdsPubkey] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("claim_thresholds"), projectPubkey.toBuffer()],
+      program.programId,
+    );
+
+    await program.methods
+      .initializeProject({ name: "Referral", defaultShareBps: 5000 })
+      .accounts({
+        payer: admin.payer.publicKey,
+        base: base.publicKey,
+        admin: admin.payer.publicKey,
+        project: projectPubkey,
+      })
+      .signers([base, admin.payer])
+      .rpc();
+
+    [referralAccountPubkey] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("referral"),
+        projectPubkey.toBuffer(),
+        Buffer.from(referralName),
+      ],
+      program.programId,
+    );
+
+    await program.methods
+      .initializeReferralAccountWithName({ name: referralName })
+      .accounts({
+        payer: partner.publicKey,
+        project: projectPubkey,
+        partner: partner.publicKey,
+        referralAccount: referralAccountPubkey,
+      })
+      .signers([partner])
+      .rpc();
+
+    token = await createTokenMint(tokenProgram, provider);
+    referralTokenAccount = await createAssociatedTokenAccountWithOffCurve(
+      token,
+      tokenProgram.programId,
+      referralAccountPubkey,
+      provider,
+    );
+    await fundTokenAccount(
+      referralTokenAccount,
+      token,
+      admin.payer,
+      referralAmount,
+      tokenProgram,
+    );
+
+    await program.methods
+      .initializeProjectClaimThresholds()
+      .accounts({
+        payer: admin.payer.publicKey,
+        admin: admin.payer.publicKey,
+        project: projectPubkey,
+        claimThresholds: claimThresholdsPubkey,
+      })
+      .signers([admin.payer])
+      .rpc();
+  });
+
+  it("raised if the balance is below the default minimum", async () => {
+    await setMinClaimAmount(null, referralAmount + 1);
+
+    await expectError(claimV2(), "ClaimAmountBelowMinimum");
+  });
+
+  it("raised if the claim thresholds account is missing", async () => {
+    await expectError(claimV2(null), "MissingClaimThresholdsAccount");
+  });
+
+  it("applies the mint minimum over the default minimum", async () => {
+    await setMinClaimAmount(null, referralAmount + 1);
+    await setMinClaimAmount(token, referralAmount);
+
+    await claimV2();
+
+    expect(await getAccountBalance(referralTokenAccount, provider)).to.equal(0);
+  });
+});
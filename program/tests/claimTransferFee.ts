@@ -0,0 +1,347 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { ASSOCIATED_PROGRAM_ID } from "@coral-xyz/anchor/dist/cjs/utils/token";
+import { splTokenProgram } from "@coral-xyz/spl-token";
+import {
+  createInitializeMintInstruction,
+  createInitializeTransferFeeConfigInstruction,
+  ExtensionType,
+  getAccount,
+  getAssociatedTokenAddressSync,
+  getMint,
+  getMintLen,
+  getTransferFeeAmount,
+  getTransferFeeConfig,
+  TOKEN_2022_PROGRAM_ID,
+  transferChecked,
+} from "@solana/spl-token";
+import { expect } from "chai";
+
+import { Referral } from "../target/types/referral";
+import {
+  createAssociatedTokenAccountWithOffCurve,
+  createTokenAccount,
+  fundAccount,
+  fundTokenAccount,
+  eventCpiAccounts,
+} from "./helpers/helpers";
+
+describe("claim with transfer fee", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.Referral as Program<Referral>;
+  const admin = anchor.workspace.Referral.provider.wallet;
+  const tokenProgram = splTokenProgram({
+    provider,
+    programId: TOKEN_2022_PROGRAM_ID,
+  });
+
+  let base: anchor.web3.Keypair;
+  let partner: anchor.web3.Keypair;
+  let projectPubkey: anchor.web3.PublicKey;
+  let referralAccountPubkey: anchor.web3.PublicKey;
+  let referralTokenAccount: anchor.web3.PublicKey;
+  let token: anchor.web3.PublicKey;
+  let referralName = "FeeReferral";
+  let projectName = "Referral";
+  let referralAmount = 1e8;
+  let defaultShareBps = 8000;
+  let transferFeeBps = 100;
+
+  const transferFee = (amount: number) =>
+    Math.ceil((amount * transferFeeBps) / 10000);
+
+  const createTransferFeeMint = async (maximumFee: bigint) => {
+    const mint = anchor.web3.Keypair.generate();
+    const mintLen = getMintLen([ExtensionType.TransferFeeConfig]);
+    const lamports =
+      await provider.connection.getMinimumBalanceForRentExemption(mintLen);
+    await provider.sendAndConfirm(
+      new anchor.web3.Transaction().add(
+        anchor.web3.SystemProgram.createAccount({
+          fromPubkey: admin.payer.publicKey,
+          newAccountPubkey: mint.publicKey,
+          space: mintLen,
+          lamports,
+          programId: TOKEN_2022_PROGRAM_ID,
+        }),
+        createInitializeTransferFeeConfigInstruction(
+          mint.publicKey,
+          admin.payer.publicKey,
+          admin.payer.publicKey,
+          transferFeeBps,
+          maximumFee,
+          TOKEN_2022_PROGRAM_ID,
+        ),
+        createInitializeMintInstruction(
+          mint.publicKey,
+          0,
+          admin.payer.publicKey,
+          null,
+          TOKEN_2022_PROGRAM_ID,
+        ),
+      ),
+      [mint],
+    );
+    return mint.publicKey;
+  };
+
+  const claimV2 = (
+    mint: anchor.web3.PublicKey,
+    referralTokenAccount: anchor.web3.PublicKey,
+  ) =>
+    program.methods
+      .claimV2()
+      .accountsStrict({
+        payer: admin.payer.publicKey,
+        admin: admin.payer.publicKey,
+        partner: partner.publicKey,
+        project: projectPubkey,
+        projectAdminTokenAccount: getAssociatedTokenAddressSync(
+          mint,
+          admin.payer.publicKey,
+          false,
+          TOKEN_2022_PROGRAM_ID,
+        ),
+        referralAccount: referralAccountPubkey,
+        referralTokenAccount,
+        partnerTokenAccount: getAssociatedTokenAddressSync(
+          mint,
+          partner.publicKey,
+          false,
+          TOKEN_2022_PROGRAM_ID,
+        ),
+        mint,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+        mintPolicy: null,
+        claimThresholds: null,
+        keeperTokenAccount: null,
+        rebatePoolTokenAccount: null,
+        ...eventCpiAccounts,
+      })
+      .signers([admin.payer])
+      .rpc();
+
+  beforeEach(async () => {
+    base = anchor.web3.Keypair.generate();
+    partner = anchor.web3.Keypair.generate();
+    await fundAccount(partner.publicKey, provider);
+
+    [projectPubkey] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("project"), base.publicKey.toBuffer()],
+      program.programId,
+    );
+
+    await program.methods
+      .initializeProject({ name: projectName, defaultShareBps })
+      .accounts({
+        payer: admin.payer.publicKey,
+        base: base.publicKey,
+        admin: admin.payer.publicKey,
+        project: projectPubkey,
+      })
+      .signers([base, admin.payer])
+      .rpc();
+
+    [referralAccountPubkey] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("referral"),
+        projectPubkey.toBuffer(),
+        Buffer.from(referralName),
+      ],
+      program.programId,
+    );
+
+    await program.methods
+      .initializeReferralAccountWithName({ name: referralName })
+      .accounts({
+        payer: partner.publicKey,
+        project: projectPubkey,
+        partner: partner.publicKey,
+        referralAccount: referralAccountPubkey,
+      })
+      .signers([partner])
+      .rpc();
+
+    token = await createTransferFeeMint(BigInt(referralAmount));
+
+    referralTokenAccount = await createAssociatedTokenAccountWithOffCurve(
+      token,
+      TOKEN_2022_PROGRAM_ID,
+      referralAccountPubkey,
+      provider,
+    );
+    await fundTokenAccount(
+      referralTokenAccount,
+      token,
+      admin.payer,
+      referralAmount,
+      tokenProgram,
+    );
+  });
+
+  it("reports the withheld fee and net amount of each leg", async () => {
+    const projectAdminTokenAccount = getAssociatedTokenAddressSync(
+      token,
+      admin.payer.publicKey,
+      false,
+      TOKEN_2022_PROGRAM_ID,
+    );
+    const partnerTokenAccount = getAssociatedTokenAddressSync(
+      token,
+      partner.publicKey,
+      false,
+      TOKEN_2022_PROGRAM_ID,
+    );
+
+    let claimEvent;
+    const listener = program.addEventListener("ClaimEvent", (event) => {
+      claimEvent = event;
+    });
+
+    await claimV2(token, referralTokenAccount);
+
+    await new Promise((resolve) => setTimeout(resolve, 1000));
+    await program.removeEventListener(listener);
+
+    const referralAmountGross = (referralAmount * defaultShareBps) / 10000;
+    const projectAmountGross = referralAmount - referralAmountGross;
+    const partnerAccount = await getAccount(
+      provider.connection,
+      partnerTokenAccount,
+      undefined,
+      TOKEN_2022_PROGRAM_ID,
+    );
+    expect(Number(partnerAccount.amount)).to.equal(
+      referralAmountGross - transferFee(referralAmountGross),
+    );
+
+    expect(claimEvent.referralAmount.toNumber()).to.equal(referralAmountGross);
+    expect(claimEvent.referralTransferFee.toNumber()).to.equal(
+      transferFee(referralAmountGross),
+    );
+    expect(claimEvent.referralNetAmount.toNumber()).to.equal(
+      Number(partnerAccount.amount),
+    );
+    expect(claimEvent.projectTransferFee.toNumber()).to.equal(
+      transferFee(projectAmountGross),
+    );
+    const projectAdminAccount = await getAccount(
+      provider.connection,
+      projectAdminTokenAccount,
+      undefined,
+      TOKEN_2022_PROGRAM_ID,
+    );
+    expect(claimEvent.projectNetAmount.toNumber()).to.equal(
+      Number(projectAdminAccount.amount),
+    );
+    expect(claimEvent.projectNetAmount.toNumber()).to.equal(
+      projectAmountGross - transferFee(projectAmountGross),
+    );
+  });
+
+  it("charges each leg its own capped fee", async () => {
+    // Both legs pay the maximum fee, so the net amounts drift from the share ratio.
+    const maximumFee = 1000;
+    const cappedToken = await createTransferFeeMint(BigInt(maximumFee));
+    const cappedReferralTokenAccount =
+      await createAssociatedTokenAccountWithOffCurve(
+        cappedToken,
+        TOKEN_2022_PROGRAM_ID,
+        referralAccountPubkey,
+        provider,
+      );
+    await fundTokenAccount(
+      cappedReferralTokenAccount,
+      cappedToken,
+      admin.payer,
+      referralAmount,
+      tokenProgram,
+    );
+
+    await claimV2(cappedToken, cappedReferralTokenAccount);
+
+    const referralAmountGross = (referralAmount * defaultShareBps) / 10000;
+    const projectAmountGross = referralAmount - referralAmountGross;
+    for (const [owner, grossAmount] of [
+      [partner.publicKey, referralAmountGross],
+      [admin.payer.publicKey, projectAmountGross],
+    ] as const) {
+      const account = await getAccount(
+        provider.connection,
+        getAssociatedTokenAddressSync(
+          cappedToken,
+          owner,
+          false,
+          TOKEN_2022_PROGRAM_ID,
+        ),
+        undefined,
+        TOKEN_2022_PROGRAM_ID,
+      );
+      expect(Number(account.amount)).to.equal(grossAmount - maximumFee);
+    }
+  });
+
+  it("harvests the fees withheld on the referral token account", async () => {
+    // Fees paid into the referral token account have a transfer fee withheld on it.
+    const sourceTokenAccount = await createTokenAccount(
+      token,
+      TOKEN_2022_PROGRAM_ID,
+      admin.payer.publicKey,
+      provider,
+    );
+    await fundTokenAccount(
+      sourceTokenAccount,
+      token,
+      admin.payer,
+      referralAmount,
+      tokenProgram,
+    );
+    await transferChecked(
+      provider.connection,
+      admin.payer,
+      sourceTokenAccount,
+      token,
+      referralTokenAccount,
+      admin.payer,
+      referralAmount,
+      0,
+      undefined,
+      undefined,
+      TOKEN_2022_PROGRAM_ID,
+    );
+
+    await program.methods
+      .harvestReferralTokenAccountFees()
+      .accountsStrict({
+        referralAccount: referralAccountPubkey,
+        referralTokenAccount,
+        mint: token,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        ...eventCpiAccounts,
+      })
+      .rpc();
+
+    const referralTokenAccountAfter = await getAccount(
+      provider.connection,
+      referralTokenAccount,
+      undefined,
+      TOKEN_2022_PROGRAM_ID,
+    );
+    expect(
+      Number(getTransferFeeAmount(referralTokenAccountAfter).withheldAmount),
+    ).to.equal(0);
+    const mintAfter = await getMint(
+      provider.connection,
+      token,
+      undefined,
+      TOKEN_2022_PROGRAM_ID,
+    );
+    expect(Number(getTransferFeeConfig(mintAfter).withheldAmount)).to.equal(
+      transferFee(referralAmount),
+    );
+  });
+});
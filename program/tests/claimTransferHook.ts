@@ -0,0 +1,254 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { ASSOCIATED_PROGRAM_ID } from "@coral-xyz/anchor/dist/cjs/utils/token";
+import { splTokenProgram } from "@coral-xyz/spl-token";
+import {
+  createInitializeMintInstruction,
+  createInitializeTransferHookInstruction,
+  ExtensionType,
+  getAssociatedTokenAddressSync,
+  getMintLen,
+  TOKEN_2022_PROGRAM_ID,
+} from "@solana/spl-token";
+import { AssertionError, expect } from "chai";
+
+import { MockTransferHook } from "../target/types/mock_transfer_hook";
+import { Referral } from "../target/types/referral";
+import {
+  createAssociatedTokenAccountWithOffCurve,
+  fundAccount,
+  fundTokenAccount,
+  getAccountBalance,
+  eventCpiAccounts,
+} from "./helpers/helpers";
+
+describe("claim with transfer hook", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.Referral as Program<Referral>;
+  const mockTransferHook = anchor.workspace
+    .MockTransferHook as Program<MockTransferHook>;
+  const admin = anchor.workspace.Referral.provider.wallet;
+  const tokenProgram = splTokenProgram({
+    provider,
+    programId: TOKEN_2022_PROGRAM_ID,
+  });
+
+  let base: anchor.web3.Keypair;
+  let partner: anchor.web3.Keypair;
+  let projectPubkey: anchor.web3.PublicKey;
+  let referralAccountPubkey: anchor.web3.PublicKey;
+  let referralTokenAccount: anchor.web3.PublicKey;
+  let token: anchor.web3.PublicKey;
+  let extraAccountMetaList: anchor.web3.PublicKey;
+  let counter: anchor.web3.PublicKey;
+  let referralName = "HookReferral";
+  let projectName = "Referral";
+  let referralAmount = 1e8;
+  let defaultShareBps = 8000;
+
+  const createTransferHookMint = async () => {
+    const mint = anchor.web3.Keypair.generate();
+    const mintLen = getMintLen([ExtensionType.TransferHook]);
+    const lamports =
+      await provider.connection.getMinimumBalanceForRentExemption(mintLen);
+    await provider.sendAndConfirm(
+      new anchor.web3.Transaction().add(
+        anchor.web3.SystemProgram.createAccount({
+          fromPubkey: admin.payer.publicKey,
+          newAccountPubkey: mint.publicKey,
+          space: mintLen,
+          lamports,
+          programId: TOKEN_2022_PROGRAM_ID,
+        }),
+        createInitializeTransferHookInstruction(
+          mint.publicKey,
+          admin.payer.publicKey,
+          mockTransferHook.programId,
+          TOKEN_2022_PROGRAM_ID,
+        ),
+        createInitializeMintInstruction(
+          mint.publicKey,
+          0,
+          admin.payer.publicKey,
+          null,
+          TOKEN_2022_PROGRAM_ID,
+        ),
+      ),
+      [mint],
+    );
+    return mint.publicKey;
+  };
+
+  const claimV2 = (remainingAccounts: anchor.web3.AccountMeta[]) =>
+    program.methods
+      .claimV2()
+      .accountsStrict({
+        payer: admin.payer.publicKey,
+        admin: admin.payer.publicKey,
+        partner: partner.publicKey,
+        project: projectPubkey,
+        projectAdminTokenAccount: getAssociatedTokenAddressSync(
+          token,
+          admin.payer.publicKey,
+          false,
+          TOKEN_2022_PROGRAM_ID,
+        ),
+        referralAccount: referralAccountPubkey,
+        referralTokenAccount,
+        partnerTokenAccount: getAssociatedTokenAddressSync(
+          token,
+          partner.publicKey,
+          false,
+          TOKEN_2022_PROGRAM_ID,
+        ),
+        mint: token,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+        mintPolicy: null,
+        claimThresholds: null,
+        keeperTokenAccount: null,
+        rebatePoolTokenAccount: null,
+        ...eventCpiAccounts,
+      })
+      .remainingAccounts(remainingAccounts)
+      .signers([admin.payer])
+      .rpc();
+
+  beforeEach(async () => {
+    base = anchor.web3.Keypair.generate();
+    partner = anchor.web3.Keypair.generate();
+    await fundAccount(partner.publicKey, provider);
+
+    [projectPubkey] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("project"), base.publicKey.toBuffer()],
+      program.programId,
+    );
+
+    await program.methods
+      .initializeProject({ name: projectName, defaultShareBps })
+      .accounts({
+        payer: admin.payer.publicKey,
+        base: base.publicKey,
+        admin: admin.payer.publicKey,
+        project: projectPubkey,
+      })
+      .signers([base, admin.payer])
+      .rpc();
+
+    [referralAccountPubkey] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("referral"),
+        projectPubkey.toBuffer(),
+        Buffer.from(referralName),
+      ],
+      program.programId,
+    );
+
+    await program.methods
+      .initializeReferralAccountWithName({ name: referralName })
+      .accounts({
+        payer: partner.publicKey,
+        project: projectPubkey,
+        partner: partner.publicKey,
+        referralAccount: referralAccountPubkey,
+      })
+      .signers([partner])
+      .rpc();
+
+    token = await createTransferHookMint();
+
+    [extraAccountMetaList] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("extra-account-metas"), token.toBuffer()],
+      mockTransferHook.programId,
+    );
+    [counter] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("counter"), token.toBuffer()],
+      mockTransferHook.programId,
+    );
+
+    await mockTransferHook.methods
+      .initializeExtraAccountMetaList()
+      .accounts({
+        payer: admin.payer.publicKey,
+        extraAccountMetaList,
+        mint: token,
+        counter,
+      })
+      .signers([admin.payer])
+      .rpc();
+
+    referralTokenAccount = await createAssociatedTokenAccountWithOffCurve(
+      token,
+      TOKEN_2022_PROGRAM_ID,
+      referralAccountPubkey,
+      provider,
+    );
+    await fundTokenAccount(
+      referralTokenAccount,
+      token,
+      admin.payer,
+      referralAmount,
+      tokenProgram,
+    );
+  });
+
+  it("forwards the hook accounts to both legs", async () => {
+    // The accounts resolve_claim_v2_transfer_hook_accounts returns, once for both legs.
+    await claimV2([
+      { pubkey: counter, isSigner: false, isWritable: true },
+      {
+        pubkey: mockTransferHook.programId,
+        isSigner: false,
+        isWritable: false,
+      },
+      { pubkey: extraAccountMetaList, isSigner: false, isWritable: false },
+    ]);
+
+    const referralPortion = (referralAmount * defaultShareBps) / 10000;
+    expect(await getAccountBalance(referralTokenAccount, provider)).to.equal(0);
+    expect(
+      await getAccountBalance(
+        getAssociatedTokenAddressSync(
+          token,
+          partner.publicKey,
+          false,
+          TOKEN_2022_PROGRAM_ID,
+        ),
+        provider,
+      ),
+    ).to.equal(referralPortion);
+    expect(
+      await getAccountBalance(
+        getAssociatedTokenAddressSync(
+          token,
+          admin.payer.publicKey,
+          false,
+          TOKEN_2022_PROGRAM_ID,
+        ),
+        provider,
+      ),
+    ).to.equal(referralAmount - referralPortion);
+
+    const counterAccount = await mockTransferHook.account.counter.fetch(
+      counter,
+    );
+    expect(counterAccount.transfers.toNumber()).to.equal(2);
+  });
+
+  it("fails without the hook accounts", async () => {
+    try {
+      await claimV2([]);
+
+      expect(false, "should've failed but didn't").to.be.true;
+    } catch (_err) {
+      expect(_err).to.not.be.instanceOf(AssertionError);
+    }
+
+    expect(await getAccountBalance(referralTokenAccount, provider)).to.equal(
+      referralAmount,
+    );
+  });
+});
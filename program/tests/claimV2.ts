@@ -31,6 +31,9 @@ import {
   fundAccount,
   fundTokenAccount,
   getAccountBalance,
+  eventCpiAccounts,
+  setDefaultMinClaimAmount,
+  setReferralAccountRebate,
 } from "./helpers/helpers";
 
 describe("program", () => {
@@ -121,6 +124,7 @@ describe("program", () => {
               admin: admin.payer.publicKey,
               systemProgram: anchor.web3.SystemProgram.programId,
               base: base.publicKey,
+              ...eventCpiAccounts,
             })
             .signers([base, admin.payer])
             .rpc();
@@ -147,6 +151,7 @@ describe("program", () => {
               partner: partner.publicKey,
               referralAccount: referralAccountPubkey,
               systemProgram: anchor.web3.SystemProgram.programId,
+              ...eventCpiAccounts,
             })
             .signers([partner])
             .rpc();
@@ -265,6 +270,11 @@ describe("program", () => {
                 tokenProgram: tokenProgram.programId,
                 systemProgram: anchor.web3.SystemProgram.programId,
                 associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+                mintPolicy: null,
+                claimThresholds: null,
+                keeperTokenAccount: null,
+                rebatePoolTokenAccount: null,
+                ...eventCpiAccounts,
               })
               .signers([admin.payer])
               .rpc();
@@ -294,6 +304,103 @@ describe("program", () => {
           );
         });
 
+        it("Is able to claim part of the balance using V2!", async () => {
+          const claimAmount = referralAmount / 4;
+
+          let projectAdminTokenAccount = getAssociatedTokenAddressSync(
+            token,
+            admin.payer.publicKey,
+            false,
+            tokenProgram.programId,
+          );
+          let partnerTokenAccount = getAssociatedTokenAddressSync(
+            token,
+            partner.publicKey,
+            false,
+            tokenProgram.programId,
+          );
+
+          await program.methods
+            .claimAmountV2({ amount: new BN(claimAmount) })
+            .accountsStrict({
+              payer: admin.payer.publicKey,
+              admin: admin.payer.publicKey,
+              partner: partner.publicKey,
+              project: projectPubkey,
+              projectAdminTokenAccount,
+              referralAccount: referralAccountPubkey,
+              referralTokenAccount,
+              partnerTokenAccount,
+              mint: token,
+              tokenProgram: tokenProgram.programId,
+              systemProgram: anchor.web3.SystemProgram.programId,
+              associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+              mintPolicy: null,
+              claimThresholds: null,
+              keeperTokenAccount: null,
+              rebatePoolTokenAccount: null,
+              ...eventCpiAccounts,
+            })
+            .signers([admin.payer])
+            .rpc();
+
+          expect(
+            await getAccountBalance(referralTokenAccount, provider),
+          ).to.equal(referralAmount - claimAmount);
+          expect(
+            await getAccountBalance(projectAdminTokenAccount, provider),
+          ).to.equal((claimAmount * (10000 - defaultShareBps)) / 10000);
+          expect(
+            await getAccountBalance(partnerTokenAccount, provider),
+          ).to.equal((claimAmount * defaultShareBps) / 10000);
+        });
+
+        it("raised if claim amount exceeds the balance", async () => {
+          try {
+            await program.methods
+              .claimAmountV2({ amount: new BN(referralAmount + 1) })
+              .accountsStrict({
+                payer: admin.payer.publicKey,
+                admin: admin.payer.publicKey,
+                partner: partner.publicKey,
+                project: projectPubkey,
+                projectAdminTokenAccount: getAssociatedTokenAddressSync(
+                  token,
+                  admin.payer.publicKey,
+                  false,
+                  tokenProgram.programId,
+                ),
+                referralAccount: referralAccountPubkey,
+                referralTokenAccount,
+                partnerTokenAccount: getAssociatedTokenAddressSync(
+                  token,
+                  partner.publicKey,
+                  false,
+                  tokenProgram.programId,
+                ),
+                mint: token,
+                tokenProgram: tokenProgram.programId,
+                systemProgram: anchor.web3.SystemProgram.programId,
+                associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+                mintPolicy: null,
+                claimThresholds: null,
+                keeperTokenAccount: null,
+                rebatePoolTokenAccount: null,
+                ...eventCpiAccounts,
+              })
+              .signers([admin.payer])
+              .rpc();
+
+            expect(false, "should've failed but didn't").to.be.true;
+          } catch (_err) {
+            expect(_err).to.be.instanceOf(AnchorError);
+            const err: AnchorError = _err;
+            expect(err.error.errorCode.code).to.equal(
+              "ClaimAmountExceedsBalance",
+            );
+          }
+        });
+
         it("raised if project admin token account is wrong", async () => {
           try {
             let partnerTokenAccount = await createTokenAccount(
@@ -318,6 +425,11 @@ describe("program", () => {
                 tokenProgram: tokenProgram.programId,
                 systemProgram: anchor.web3.SystemProgram.programId,
                 associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+                mintPolicy: null,
+                claimThresholds: null,
+                keeperTokenAccount: null,
+                rebatePoolTokenAccount: null,
+                ...eventCpiAccounts,
               })
               .signers([admin.payer])
               .rpc();
@@ -354,6 +466,11 @@ describe("program", () => {
                 tokenProgram: tokenProgram.programId,
                 systemProgram: anchor.web3.SystemProgram.programId,
                 associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+                mintPolicy: null,
+                claimThresholds: null,
+                keeperTokenAccount: null,
+                rebatePoolTokenAccount: null,
+                ...eventCpiAccounts,
               })
               .signers([admin.payer])
               .rpc();
@@ -396,6 +513,11 @@ describe("program", () => {
                 tokenProgram: tokenProgram.programId,
                 systemProgram: anchor.web3.SystemProgram.programId,
                 associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+                mintPolicy: null,
+                claimThresholds: null,
+                keeperTokenAccount: null,
+                rebatePoolTokenAccount: null,
+                ...eventCpiAccounts,
               })
               .signers([admin.payer])
               .rpc();
@@ -409,6 +531,190 @@ describe("program", () => {
           }
         });
 
+        it("Is able to claim many mints in one instruction with V2!", async () => {
+          const claims = [
+            { mint: token, referralTokenAccount },
+            {
+              mint: extraToken,
+              referralTokenAccount: extraReferralTokenAccount,
+            },
+            {
+              mint: extraToken2,
+              referralTokenAccount: extraReferralTokenAccount2,
+            },
+          ].map((claim) => ({
+            ...claim,
+            partnerTokenAccount: getAssociatedTokenAddressSync(
+              claim.mint,
+              partner.publicKey,
+              false,
+              tokenProgram.programId,
+            ),
+            projectAdminTokenAccount: getAssociatedTokenAddressSync(
+              claim.mint,
+              admin.payer.publicKey,
+              false,
+              tokenProgram.programId,
+            ),
+          }));
+
+          await program.methods
+            .claimManyV2()
+            .accountsStrict({
+              payer: admin.payer.publicKey,
+              project: projectPubkey,
+              admin: admin.payer.publicKey,
+              referralAccount: referralAccountPubkey,
+              partner: partner.publicKey,
+              systemProgram: anchor.web3.SystemProgram.programId,
+              associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+              mintPolicy: null,
+              claimThresholds: null,
+              ...eventCpiAccounts,
+            })
+            .remainingAccounts(
+              claims.flatMap((claim) => [
+                { pubkey: claim.mint, isSigner: false, isWritable: false },
+                {
+                  pubkey: claim.referralTokenAccount,
+                  isSigner: false,
+                  isWritable: true,
+                },
+                {
+                  pubkey: claim.partnerTokenAccount,
+                  isSigner: false,
+                  isWritable: true,
+                },
+                {
+                  pubkey: claim.projectAdminTokenAccount,
+                  isSigner: false,
+                  isWritable: true,
+                },
+                {
+                  pubkey: tokenProgram.programId,
+                  isSigner: false,
+                  isWritable: false,
+                },
+              ]),
+            )
+            .signers([admin.payer])
+            .rpc();
+
+          for (const claim of claims) {
+            expect(
+              await getAccountBalance(claim.referralTokenAccount, provider),
+            ).to.equal(0);
+            expect(
+              await getAccountBalance(claim.partnerTokenAccount, provider),
+            ).to.equal((referralAmount * defaultShareBps) / 10000);
+            expect(
+              await getAccountBalance(claim.projectAdminTokenAccount, provider),
+            ).to.equal((referralAmount * (10000 - defaultShareBps)) / 10000);
+          }
+        });
+
+        it("raised if a mint of claim many is below the minimum claim amount", async () => {
+          const claimThresholds = await setDefaultMinClaimAmount(
+            projectPubkey,
+            admin.payer,
+            referralAmount + 1,
+          );
+
+          try {
+            await program.methods
+              .claimManyV2()
+              .accountsStrict({
+                payer: admin.payer.publicKey,
+                project: projectPubkey,
+                admin: admin.payer.publicKey,
+                referralAccount: referralAccountPubkey,
+                partner: partner.publicKey,
+                systemProgram: anchor.web3.SystemProgram.programId,
+                associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+                mintPolicy: null,
+                claimThresholds,
+                ...eventCpiAccounts,
+              })
+              .remainingAccounts([
+                { pubkey: token, isSigner: false, isWritable: false },
+                {
+                  pubkey: referralTokenAccount,
+                  isSigner: false,
+                  isWritable: true,
+                },
+                {
+                  pubkey: getAssociatedTokenAddressSync(
+                    token,
+                    partner.publicKey,
+                    false,
+                    tokenProgram.programId,
+                  ),
+                  isSigner: false,
+                  isWritable: true,
+                },
+                {
+                  pubkey: getAssociatedTokenAddressSync(
+                    token,
+                    admin.payer.publicKey,
+                    false,
+                    tokenProgram.programId,
+                  ),
+                  isSigner: false,
+                  isWritable: true,
+                },
+                {
+                  pubkey: tokenProgram.programId,
+                  isSigner: false,
+                  isWritable: false,
+                },
+              ])
+              .signers([admin.payer])
+              .rpc();
+
+            expect(false, "should've failed but didn't").to.be.true;
+          } catch (_err) {
+            expect(_err).to.be.instanceOf(AnchorError);
+            const err: AnchorError = _err;
+            expect(err.error.errorCode.code).to.equal(
+              "ClaimAmountBelowMinimum",
+            );
+          }
+        });
+
+        it("raised if the referral account of claim many has a rebate", async () => {
+          await setReferralAccountRebate(
+            projectPubkey,
+            referralAccountPubkey,
+            admin.payer,
+            1000,
+          );
+
+          try {
+            await program.methods
+              .claimManyV2()
+              .accountsStrict({
+                payer: admin.payer.publicKey,
+                project: projectPubkey,
+                admin: admin.payer.publicKey,
+                referralAccount: referralAccountPubkey,
+                partner: partner.publicKey,
+                systemProgram: anchor.web3.SystemProgram.programId,
+                associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+                mintPolicy: null,
+                claimThresholds: null,
+                ...eventCpiAccounts,
+              })
+              .signers([admin.payer])
+              .rpc();
+
+            expect(false, "should've failed but didn't").to.be.true;
+          } catch (_err) {
+            expect(_err).to.be.instanceOf(AnchorError);
+            const err: AnchorError = _err;
+            expect(err.error.errorCode.code).to.equal("RebateRequiresClaimV2");
+          }
+        });
+
         it("Is able to claim all with V2!", async () => {
           // Get latest blockhash
           const blockhash = (await provider.connection.getLatestBlockhash())
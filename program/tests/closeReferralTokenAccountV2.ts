@@ -9,6 +9,7 @@ import {
     createTokenMint,
     fundAccount,
     getAccountBalance,
+    eventCpiAccounts,
 } from "./helpers/helpers";
 import { ASSOCIATED_PROGRAM_ID } from "@coral-xyz/anchor/dist/cjs/utils/token";
 import { createAssociatedTokenAccount, getAssociatedTokenAddressSync } from "@solana/spl-token";
@@ -72,6 +73,7 @@ describe("program", () => {
                             admin: admin.payer.publicKey,
                             systemProgram: anchor.web3.SystemProgram.programId,
                             base: base.publicKey,
+                            ...eventCpiAccounts,
                         })
                         .signers([base, admin.payer])
                         .rpc();
@@ -98,6 +100,7 @@ describe("program", () => {
                             partner: partner.publicKey,
                             referralAccount: referralAccountPubkey,
                             systemProgram: anchor.web3.SystemProgram.programId,
+                            ...eventCpiAccounts,
                         })
                         .signers([partner])
                         .rpc();
@@ -123,7 +126,8 @@ describe("program", () => {
                             partner: partner.publicKey,
                             mint: token,
                             tokenProgram: tokenProgram.programId,
-                            associatedTokenProgram: ASSOCIATED_PROGRAM_ID
+                            associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+                            ...eventCpiAccounts,
                         })
                         .signers([admin.payer])
                         .rpc();
@@ -0,0 +1,220 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN, Program } from "@coral-xyz/anchor";
+import { SPL_TOKEN_PROGRAM_ID, splTokenProgram } from "@coral-xyz/spl-token";
+import { expect } from "chai";
+
+import { Referral } from "../target/types/referral";
+import {
+  createAssociatedTokenAccountWithOffCurve,
+  createTokenAccount,
+  createTokenMint,
+  fundAccount,
+  fundTokenAccount,
+  getAccountBalance,
+  eventCpiAccounts,
+  expectError,
+} from "./helpers/helpers";
+
+describe("collect fee", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.Referral as Program<Referral>;
+  const admin = anchor.workspace.Referral.provider.wallet;
+  const tokenProgram = splTokenProgram({
+    provider,
+    programId: SPL_TOKEN_PROGRAM_ID,
+  });
+
+  let base: anchor.web3.Keypair;
+  let partner: anchor.web3.Keypair;
+  let integrator: anchor.web3.Keypair;
+  let projectPubkey: anchor.web3.PublicKey;
+  let referralAccountPubkey: anchor.web3.PublicKey;
+  let referralTokenAccount: anchor.web3.PublicKey;
+  let sourceTokenAccount: anchor.web3.PublicKey;
+  let token: anchor.web3.PublicKey;
+  let referralName = "FeeSourceReferral";
+  let sourceTag = "perps";
+
+  const feeStatsAddress = (tag: string) =>
+    anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("fee_stats"),
+        referralAccountPubkey.toBuffer(),
+        token.toBuffer(),
+        integrator.publicKey.toBuffer(),
+        Buffer.from(tag),
+      ],
+      program.programId,
+    )[0];
+
+  const collectFee = (
+    feeAmount: number,
+    grossVolume: number,
+    tag = sourceTag,
+    destination = referralTokenAccount,
+  ) =>
+    program.methods
+      .collectFee({
+        feeAmount: new BN(feeAmount),
+        grossVolume: new BN(grossVolume),
+        sourceTag: tag,
+      })
+      .accountsStrict({
+        payer: integrator.publicKey,
+        authority: integrator.publicKey,
+        project: projectPubkey,
+        referralAccount: referralAccountPubkey,
+        referralTokenAccount: destination,
+        sourceTokenAccount,
+        feeStats: feeStatsAddress(tag.length <= 32 ? tag : sourceTag),
+        mint: token,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        tokenProgram: tokenProgram.programId,
+        ...eventCpiAccounts,
+      })
+      .signers([integrator])
+      .rpc();
+
+  beforeEach(async () => {
+    base = anchor.web3.Keypair.generate();
+    partner = anchor.web3.Keypair.generate();
+    integrator = anchor.web3.Keypair.generate();
+    await fundAccount(partner.publicKey, provider);
+    await fundAccount(integrator.publicKey, provider);
+
+    [projectPubkey] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("project"), base.publicKey.toBuffer()],
+      program.programId,
+    );
+
+    await program.methods
+      .initializeProject({ name: "Referral", defaultShareBps: 5000 })
+      .accounts({
+        payer: admin.payer.publicKey,
+        base: base.publicKey,
+        admin: admin.payer.publicKey,
+        project: projectPubkey,
+      })
+      .signers([base, admin.payer])
+      .rpc();
+
+    [referralAccountPubkey] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("referral"),
+        projectPubkey.toBuffer(),
+        Buffer.from(referralName),
+      ],
+      program.programId,
+    );
+
+    await program.methods
+      .initializeReferralAccountWithName({ name: referralName })
+      .accounts({
+        payer: partner.publicKey,
+        project: projectPubkey,
+        partner: partner.publicKey,
+        referralAccount: referralAccountPubkey,
+      })
+      .signers([partner])
+      .rpc();
+
+    token = await createTokenMint(tokenProgram, provider);
+    referralTokenAccount = await createAssociatedTokenAccountWithOffCurve(
+      token,
+      tokenProgram.programId,
+      referralAccountPubkey,
+      provider,
+    );
+    sourceTokenAccount = await createTokenAccount(
+      token,
+      tokenProgram.programId,
+      integrator.publicKey,
+      provider,
+    );
+    await fundTokenAccount(
+      sourceTokenAccount,
+      token,
+      admin.payer,
+      1e6,
+      tokenProgram,
+    );
+  });
+
+  it("pays the referral token account and records the volume per source", async () => {
+    let feeCollectedEvent;
+    const listener = program.addEventListener(
+      "FeeCollectedEvent",
+      (event) => {
+        feeCollectedEvent = event;
+      },
+    );
+
+    await collectFee(1_000, 100_000);
+
+    await new Promise((resolve) => setTimeout(resolve, 1000));
+    await program.removeEventListener(listener);
+
+    await collectFee(500, 50_000);
+
+    expect(await getAccountBalance(referralTokenAccount, provider)).to.equal(
+      1_500,
+    );
+
+    const feeStats = await program.account.feeStats.fetch(
+      feeStatsAddress(sourceTag),
+    );
+    expect(feeStats.referralAccount).to.eql(referralAccountPubkey);
+    expect(feeStats.mint).to.eql(token);
+    expect(feeStats.authority).to.eql(integrator.publicKey);
+    expect(feeStats.sourceTag).to.equal(sourceTag);
+    expect(feeStats.grossVolume.toNumber()).to.equal(150_000);
+    expect(feeStats.feeAmount.toNumber()).to.equal(1_500);
+    expect(feeStats.collections.toNumber()).to.equal(2);
+
+    expect(feeCollectedEvent.referralAccount).to.eql(referralAccountPubkey);
+    expect(feeCollectedEvent.referralTokenAccount).to.eql(referralTokenAccount);
+    expect(feeCollectedEvent.sourceTag).to.equal(sourceTag);
+    expect(feeCollectedEvent.grossVolume.toNumber()).to.equal(100_000);
+    expect(feeCollectedEvent.feeAmount.toNumber()).to.equal(1_000);
+    expect(feeCollectedEvent.netAmount.toNumber()).to.equal(1_000);
+  });
+
+  it("keeps separate stats per source tag", async () => {
+    await collectFee(1_000, 100_000);
+    await collectFee(200, 20_000, "spot");
+
+    const spotStats = await program.account.feeStats.fetch(
+      feeStatsAddress("spot"),
+    );
+    expect(spotStats.grossVolume.toNumber()).to.equal(20_000);
+    expect(spotStats.collections.toNumber()).to.equal(1);
+  });
+
+  it("only pays into the referral token account", async () => {
+    const partnerTokenAccount = await createTokenAccount(
+      token,
+      tokenProgram.programId,
+      partner.publicKey,
+      provider,
+    );
+
+    await expectError(
+      collectFee(1_000, 100_000, sourceTag, partnerTokenAccount),
+      "ConstraintTokenOwner",
+    );
+  });
+
+  it("rejects empty and over-long source tags", async () => {
+    await expectError(collectFee(1_000, 100_000, ""), "InvalidSourceTag");
+    await expectError(
+      collectFee(1_000, 100_000, "x".repeat(33)),
+      "InvalidSourceTag",
+    );
+  });
+
+  it("rejects a zero fee", async () => {
+    await expectError(collectFee(0, 100_000), "InvalidFeeAmount");
+  });
+});
@@ -2,7 +2,13 @@ import * as anchor from "@coral-xyz/anchor";
 import { Program, web3 } from "@coral-xyz/anchor";
 import { expect } from "chai";
 import { Referral } from "../target/types/referral";
-import { createTokenMint, fundAccount } from "./helpers/helpers";
+import {
+  createTokenAccount,
+  createTokenMint,
+  fundAccount,
+  fundTokenAccount,
+  expectError,
+} from "./helpers/helpers";
 import { BN } from "bn.js";
 import { splTokenProgram } from "@coral-xyz/spl-token";
 import {
@@ -143,6 +149,181 @@ describe("create admin token account", () => {
           })
           .rpc();
       });
+
+      const setAdminTokenAccountPolicy = async (
+        requireAllowedMint: boolean,
+        minReferralBalance: number,
+        epochBudgetLamports: number,
+      ) => {
+        await program.methods
+          .setProjectAdminTokenAccountPolicy({
+            policy: {
+              requireAllowedMint,
+              minReferralBalance: new BN(minReferralBalance),
+              epochBudgetLamports: new BN(epochBudgetLamports),
+            },
+          })
+          .accounts({
+            admin: admin.payer.publicKey,
+            project: projectPubkey,
+          })
+          .signers([admin.payer])
+          .rpc();
+      };
+
+      // Allowlists the mint, returns the mint policy account.
+      const allowMint = async (mint: web3.PublicKey) => {
+        const [mintPolicy] = web3.PublicKey.findProgramAddressSync(
+          [Buffer.from("mint_policy"), projectPubkey.toBuffer()],
+          program.programId,
+        );
+
+        await program.methods
+          .initializeProjectMintPolicy()
+          .accounts({
+            payer: admin.payer.publicKey,
+            admin: admin.payer.publicKey,
+            project: projectPubkey,
+            mintPolicy,
+          })
+          .signers([admin.payer])
+          .rpc();
+        await program.methods
+          .setProjectMintPolicyMode({ mode: { allow: {} } })
+          .accounts({
+            admin: admin.payer.publicKey,
+            project: projectPubkey,
+            mintPolicy,
+          })
+          .signers([admin.payer])
+          .rpc();
+        await program.methods
+          .addProjectPolicyMint({ mint })
+          .accounts({
+            admin: admin.payer.publicKey,
+            project: projectPubkey,
+            mintPolicy,
+          })
+          .signers([admin.payer])
+          .rpc();
+
+        return mintPolicy;
+      };
+
+      const createAdminTokenAccount = async (
+        mint: web3.PublicKey,
+        {
+          mintPolicy = null,
+          referralAccount = null,
+          referralTokenAccount = null,
+        }: {
+          mintPolicy?: web3.PublicKey | null;
+          referralAccount?: web3.PublicKey | null;
+          referralTokenAccount?: web3.PublicKey | null;
+        } = {},
+      ) => {
+        await program.methods
+          .createAdminTokenAccount()
+          .accounts({
+            admin: admin.payer.publicKey,
+            mint,
+            project: projectPubkey,
+            projectAdminTokenAccount: getAssociatedTokenAddressSync(
+              mint,
+              admin.payer.publicKey,
+              true,
+              tokenProgram.programId,
+            ),
+            tokenProgram: tokenProgram.programId,
+            projectAuthority: projectAuthorityPubkey,
+            mintPolicy,
+            referralAccount,
+            referralTokenAccount,
+          })
+          .rpc();
+      };
+
+      it("raised if the epoch budget is exceeded", async () => {
+        await setAdminTokenAccountPolicy(false, 0, 1);
+        const mint = await createTokenMint(tokenProgram, provider);
+
+        await expectError(createAdminTokenAccount(mint), "EpochBudgetExceeded");
+      });
+
+      it("doesn't pay for admin token accounts with a zero budget", async () => {
+        await setAdminTokenAccountPolicy(false, 0, 0);
+        const mint = await createTokenMint(tokenProgram, provider);
+
+        await expectError(createAdminTokenAccount(mint), "EpochBudgetExceeded");
+      });
+
+      it("raised if the minimum balance is set without the allowlist", async () => {
+        await expectError(
+          setAdminTokenAccountPolicy(false, 1, 1e8),
+          "MintAllowlistRequired",
+        );
+      });
+
+      it("raised if no referral token account holds the minimum balance", async () => {
+        const mint = await createTokenMint(tokenProgram, provider);
+        const mintPolicy = await allowMint(mint);
+        await setAdminTokenAccountPolicy(true, 1, 1e8);
+
+        await expectError(
+          createAdminTokenAccount(mint, { mintPolicy }),
+          "MissingReferralTokenAccount",
+        );
+      });
+
+      it("raised if the referral token account isn't the referral account's", async () => {
+        const mint = await createTokenMint(tokenProgram, provider);
+        const mintPolicy = await allowMint(mint);
+        await setAdminTokenAccountPolicy(true, 1, 1e8);
+
+        const referralName = "AdminTokenReferral";
+        const [referralAccount] = web3.PublicKey.findProgramAddressSync(
+          [
+            Buffer.from("referral"),
+            projectPubkey.toBuffer(),
+            Buffer.from(referralName),
+          ],
+          program.programId,
+        );
+        await program.methods
+          .initializeReferralAccountWithName({ name: referralName })
+          .accounts({
+            payer: admin.payer.publicKey,
+            project: projectPubkey,
+            partner: partner.publicKey,
+            referralAccount,
+          })
+          .signers([admin.payer])
+          .rpc();
+
+        // Funded, but owned by the partner rather than the referral account.
+        const referralTokenAccount = await createTokenAccount(
+          mint,
+          tokenProgram.programId,
+          partner.publicKey,
+          provider,
+        );
+        await fundTokenAccount(
+          referralTokenAccount,
+          mint,
+          admin.payer,
+          100,
+          tokenProgram,
+        );
+
+        await expectError(
+          createAdminTokenAccount(mint, {
+            mintPolicy,
+            referralAccount,
+            referralTokenAccount,
+          }),
+          "InvalidReferralTokenAccount",
+        );
+      });
     });
   });
 });
@@ -1,7 +1,8 @@
 import * as anchor from "@coral-xyz/anchor";
-import { Program } from "@coral-xyz/anchor";
+import { AnchorError, Program } from "@coral-xyz/anchor";
 import { splTokenProgram } from "@coral-xyz/spl-token";
 import { createAssociatedTokenAccount, createAssociatedTokenAccountInstruction, getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { expect } from "chai";
 
 import { Referral } from "../../target/types/referral";
 import { ASSOCIATED_PROGRAM_ID } from "@coral-xyz/anchor/dist/cjs/utils/token";
@@ -10,6 +11,28 @@ type TokenProgramReturnType = ReturnType<typeof splTokenProgram>;
 
 export const program = anchor.workspace.Referral as Program<Referral>;
 
+// Accounts every event-emitting instruction needs for the self-CPI, for `accountsStrict` callers.
+export const eventCpiAccounts = {
+  eventAuthority: anchor.web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("__event_authority")],
+    program.programId,
+  )[0],
+  program: program.programId,
+};
+
+// Expects the promise to fail with the program error `code`.
+export const expectError = async (promise: Promise<unknown>, code: string) => {
+  try {
+    await promise;
+
+    expect(false, "should've failed but didn't").to.be.true;
+  } catch (_err) {
+    expect(_err).to.be.instanceOf(AnchorError);
+    const err: AnchorError = _err;
+    expect(err.error.errorCode.code).to.equal(code);
+  }
+};
+
 export const fundAccount = async (
   publicKey: anchor.web3.PublicKey,
   provider: anchor.AnchorProvider,
@@ -116,3 +139,50 @@ export const getAccountBalance = async (
 
   return Number(tokenBalance.value.amount);
 };
+
+// Requires at least `minAmount` of any mint per claim of the project, returns the thresholds account.
+export const setDefaultMinClaimAmount = async (
+  project: anchor.web3.PublicKey,
+  admin: anchor.web3.Keypair,
+  minAmount: number,
+): Promise<anchor.web3.PublicKey> => {
+  const [claimThresholds] = anchor.web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("claim_thresholds"), project.toBuffer()],
+    program.programId,
+  );
+
+  await program.methods
+    .initializeProjectClaimThresholds()
+    .accounts({
+      payer: admin.publicKey,
+      admin: admin.publicKey,
+      project,
+      claimThresholds,
+    })
+    .signers([admin])
+    .rpc();
+  await program.methods
+    .setProjectMinClaimAmount({
+      mint: null,
+      minAmount: new anchor.BN(minAmount),
+    })
+    .accounts({ admin: admin.publicKey, project, claimThresholds })
+    .signers([admin])
+    .rpc();
+
+  return claimThresholds;
+};
+
+// Sets aside part of the referral share for traders, which only claim_v2 pays out.
+export const setReferralAccountRebate = async (
+  project: anchor.web3.PublicKey,
+  referralAccount: anchor.web3.PublicKey,
+  admin: anchor.web3.Keypair,
+  rebateBps: number,
+) => {
+  await program.methods
+    .setReferralAccountRebate({ rebateBps })
+    .accounts({ admin: admin.publicKey, project, referralAccount })
+    .signers([admin])
+    .rpc();
+};
@@ -0,0 +1,338 @@
+import * as anchor from "@coral-xyz/anchor";
+import { AnchorError, BN, Program } from "@coral-xyz/anchor";
+import { ASSOCIATED_PROGRAM_ID } from "@coral-xyz/anchor/dist/cjs/utils/token";
+import { SPL_TOKEN_PROGRAM_ID, splTokenProgram } from "@coral-xyz/spl-token";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { expect } from "chai";
+
+import { Referral } from "../target/types/referral";
+import {
+  createAssociatedTokenAccountWithOffCurve,
+  createTokenAccount,
+  createTokenMint,
+  fundAccount,
+  fundTokenAccount,
+  getAccountBalance,
+  eventCpiAccounts,
+} from "./helpers/helpers";
+
+describe("keeper fee", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.Referral as Program<Referral>;
+  const admin = anchor.workspace.Referral.provider.wallet;
+  const tokenProgram = splTokenProgram({
+    provider,
+    programId: SPL_TOKEN_PROGRAM_ID,
+  });
+
+  let base: anchor.web3.Keypair;
+  let partner: anchor.web3.Keypair;
+  let keeper: anchor.web3.Keypair;
+  let projectPubkey: anchor.web3.PublicKey;
+  let referralAccountPubkey: anchor.web3.PublicKey;
+  let referralTokenAccount: anchor.web3.PublicKey;
+  let token: anchor.web3.PublicKey;
+  let referralName = "KeeperReferral";
+  let referralAmount = 1e6;
+  let defaultShareBps = 5000;
+  let keeperFeeBps = 100;
+
+  const claimV2 = async (
+    keeperTokenAccount: anchor.web3.PublicKey | null,
+    {
+      amount = null,
+      claimThresholds = null,
+    }: {
+      amount?: number | null;
+      claimThresholds?: anchor.web3.PublicKey | null;
+    } = {},
+  ) => {
+    const method =
+      amount === null
+        ? program.methods.claimV2()
+        : program.methods.claimAmountV2({ amount: new BN(amount) });
+
+    await method
+      .accountsStrict({
+        payer: keeper.publicKey,
+        admin: admin.payer.publicKey,
+        partner: partner.publicKey,
+        project: projectPubkey,
+        projectAdminTokenAccount: getAssociatedTokenAddressSync(
+          token,
+          admin.payer.publicKey,
+        ),
+        referralAccount: referralAccountPubkey,
+        referralTokenAccount,
+        partnerTokenAccount: getAssociatedTokenAddressSync(
+          token,
+          partner.publicKey,
+        ),
+        mint: token,
+        tokenProgram: tokenProgram.programId,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+        mintPolicy: null,
+        claimThresholds,
+        keeperTokenAccount,
+        rebatePoolTokenAccount: null,
+        ...eventCpiAccounts,
+      })
+      .signers([keeper])
+      .rpc();
+  };
+
+  // Sets the keeper fee with a cap for the test mint, returns the thresholds account holding it.
+  const setKeeperFee = async (maxKeeperFee: number) => {
+    const [claimThresholds] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("claim_thresholds"), projectPubkey.toBuffer()],
+      program.programId,
+    );
+
+    await program.methods
+      .setProjectKeeperFee({ keeperFeeBps })
+      .accounts({
+        admin: admin.payer.publicKey,
+        project: projectPubkey,
+      })
+      .signers([admin.payer])
+      .rpc();
+    await program.methods
+      .initializeProjectClaimThresholds()
+      .accounts({
+        payer: admin.payer.publicKey,
+        admin: admin.payer.publicKey,
+        project: projectPubkey,
+        claimThresholds,
+      })
+      .signers([admin.payer])
+      .rpc();
+    await program.methods
+      .setProjectMaxKeeperFee({
+        mint: token,
+        maxKeeperFee: new BN(maxKeeperFee),
+      })
+      .accounts({
+        admin: admin.payer.publicKey,
+        project: projectPubkey,
+        claimThresholds,
+      })
+      .signers([admin.payer])
+      .rpc();
+
+    return claimThresholds;
+  };
+
+  beforeEach(async () => {
+    base = anchor.web3.Keypair.generate();
+    partner = anchor.web3.Keypair.generate();
+    keeper = anchor.web3.Keypair.generate();
+    await fundAccount(partner.publicKey, provider);
+    await fundAccount(keeper.publicKey, provider);
+
+    [projectPubkey] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("project"), base.publicKey.toBuffer()],
+      program.programId,
+    );
+
+    await program.methods
+      .initializeProject({ name: "Referral", defaultShareBps })
+      .accounts({
+        payer: admin.payer.publicKey,
+        base: base.publicKey,
+        admin: admin.payer.publicKey,
+        project: projectPubkey,
+      })
+      .signers([base, admin.payer])
+      .rpc();
+
+    [referralAccountPubkey] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("referral"),
+        projectPubkey.toBuffer(),
+        Buffer.from(referralName),
+      ],
+      program.programId,
+    );
+
+    await program.methods
+      .initializeReferralAccountWithName({ name: referralName })
+      .accounts({
+        payer: partner.publicKey,
+        project: projectPubkey,
+        partner: partner.publicKey,
+        referralAccount: referralAccountPubkey,
+      })
+      .signers([partner])
+      .rpc();
+
+    token = await createTokenMint(tokenProgram, provider);
+    referralTokenAccount = await createAssociatedTokenAccountWithOffCurve(
+      token,
+      tokenProgram.programId,
+      referralAccountPubkey,
+      provider,
+    );
+    await fundTokenAccount(
+      referralTokenAccount,
+      token,
+      admin.payer,
+      referralAmount,
+      tokenProgram,
+    );
+  });
+
+  it("pays the keeper out of the project amount", async () => {
+    const claimThresholds = await setKeeperFee(referralAmount);
+
+    const keeperTokenAccount = await createTokenAccount(
+      token,
+      tokenProgram.programId,
+      keeper.publicKey,
+      provider,
+    );
+    let claimEvent;
+    const listener = program.addEventListener("ClaimEvent", (event) => {
+      claimEvent = event;
+    });
+    await claimV2(keeperTokenAccount, { claimThresholds });
+    await new Promise((resolve) => setTimeout(resolve, 1000));
+    await program.removeEventListener(listener);
+
+    const keeperAmount = (referralAmount * keeperFeeBps) / 10000;
+    const partnerAmount = (referralAmount * defaultShareBps) / 10000;
+    expect(await getAccountBalance(keeperTokenAccount, provider)).to.equal(
+      keeperAmount,
+    );
+    expect(
+      await getAccountBalance(
+        getAssociatedTokenAddressSync(token, partner.publicKey),
+        provider,
+      ),
+    ).to.equal(partnerAmount);
+    expect(
+      await getAccountBalance(
+        getAssociatedTokenAddressSync(token, admin.payer.publicKey),
+        provider,
+      ),
+    ).to.equal(referralAmount - partnerAmount - keeperAmount);
+    expect(claimEvent.keeperAmount.toNumber()).to.equal(keeperAmount);
+    expect(claimEvent.keeperTransferFee.toNumber()).to.equal(0);
+    expect(claimEvent.keeperNetAmount.toNumber()).to.equal(keeperAmount);
+  });
+
+  it("raised if the keeper fee is above the maximum", async () => {
+    try {
+      await program.methods
+        .setProjectKeeperFee({ keeperFeeBps: 101 })
+        .accounts({
+          admin: admin.payer.publicKey,
+          project: projectPubkey,
+        })
+        .signers([admin.payer])
+        .rpc();
+
+      expect(false, "should've failed but didn't").to.be.true;
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      const err: AnchorError = _err;
+      expect(err.error.errorCode.code).to.equal("InvalidKeeperFee");
+    }
+  });
+
+  it("caps the keeper fee per claim of the mint", async () => {
+    const maxKeeperFee = 10;
+    const claimThresholds = await setKeeperFee(maxKeeperFee);
+
+    const keeperTokenAccount = await createTokenAccount(
+      token,
+      tokenProgram.programId,
+      keeper.publicKey,
+      provider,
+    );
+    await claimV2(keeperTokenAccount, { claimThresholds });
+
+    expect(await getAccountBalance(keeperTokenAccount, provider)).to.equal(
+      maxKeeperFee,
+    );
+  });
+
+  it("doesn't pay the keeper in a mint without a cap", async () => {
+    const claimThresholds = await setKeeperFee(0);
+
+    const keeperTokenAccount = await createTokenAccount(
+      token,
+      tokenProgram.programId,
+      keeper.publicKey,
+      provider,
+    );
+    await claimV2(keeperTokenAccount, { claimThresholds });
+
+    expect(await getAccountBalance(keeperTokenAccount, provider)).to.equal(0);
+    expect(await getAccountBalance(referralTokenAccount, provider)).to.equal(0);
+  });
+
+  it("doesn't pay the keeper for partial claims", async () => {
+    const maxKeeperFee = 10;
+    const claimThresholds = await setKeeperFee(maxKeeperFee);
+
+    const keeperTokenAccount = await createTokenAccount(
+      token,
+      tokenProgram.programId,
+      keeper.publicKey,
+      provider,
+    );
+    await claimV2(keeperTokenAccount, {
+      amount: referralAmount / 4,
+      claimThresholds,
+    });
+    await claimV2(keeperTokenAccount, {
+      amount: referralAmount / 4,
+      claimThresholds,
+    });
+
+    expect(await getAccountBalance(keeperTokenAccount, provider)).to.equal(0);
+
+    await claimV2(keeperTokenAccount, { claimThresholds });
+
+    expect(await getAccountBalance(keeperTokenAccount, provider)).to.equal(
+      maxKeeperFee,
+    );
+  });
+
+  it("raised if the keeper is paid without the claim thresholds", async () => {
+    await setKeeperFee(referralAmount);
+
+    const keeperTokenAccount = await createTokenAccount(
+      token,
+      tokenProgram.programId,
+      keeper.publicKey,
+      provider,
+    );
+
+    try {
+      await claimV2(keeperTokenAccount);
+
+      expect(false, "should've failed but didn't").to.be.true;
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      const err: AnchorError = _err;
+      expect(err.error.errorCode.code).to.equal(
+        "MissingClaimThresholdsAccount",
+      );
+    }
+  });
+
+  it("skips the keeper fee without a keeper token account", async () => {
+    await claimV2(null);
+
+    expect(
+      await getAccountBalance(
+        getAssociatedTokenAddressSync(token, partner.publicKey),
+        provider,
+      ),
+    ).to.equal((referralAmount * defaultShareBps) / 10000);
+  });
+});
@@ -0,0 +1,190 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { expect } from "chai";
+import { createHash } from "crypto";
+
+import { Referral } from "../target/types/referral";
+import { fundAccount, expectError } from "./helpers/helpers";
+
+describe("metadata", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.Referral as Program<Referral>;
+  const admin = anchor.workspace.Referral.provider.wallet;
+  let base: anchor.web3.Keypair;
+  let partner: anchor.web3.Keypair;
+  let projectPubkey: anchor.web3.PublicKey;
+  let projectMetadataPubkey: anchor.web3.PublicKey;
+  let referralAccountPubkey: anchor.web3.PublicKey;
+  let referralMetadataPubkey: anchor.web3.PublicKey;
+  let referralName = "MetadataReferral";
+
+  const contentHash = (content: string) =>
+    Array.from(createHash("sha256").update(content).digest());
+
+  beforeEach(async () => {
+    base = anchor.web3.Keypair.generate();
+    partner = anchor.web3.Keypair.generate();
+    await fundAccount(partner.publicKey, provider);
+
+    [projectPubkey] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("project"), base.publicKey.toBuffer()],
+      program.programId,
+    );
+    [projectMetadataPubkey] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("metadata"), projectPubkey.toBuffer()],
+      program.programId,
+    );
+
+    await program.methods
+      .initializeProject({ name: "Referral", defaultShareBps: 5000 })
+      .accounts({
+        payer: admin.payer.publicKey,
+        base: base.publicKey,
+        admin: admin.payer.publicKey,
+        project: projectPubkey,
+      })
+      .signers([base, admin.payer])
+      .rpc();
+
+    [referralAccountPubkey] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("referral"),
+        projectPubkey.toBuffer(),
+        Buffer.from(referralName),
+      ],
+      program.programId,
+    );
+    [referralMetadataPubkey] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("metadata"), referralAccountPubkey.toBuffer()],
+      program.programId,
+    );
+
+    await program.methods
+      .initializeReferralAccountWithName({ name: referralName })
+      .accounts({
+        payer: partner.publicKey,
+        project: projectPubkey,
+        partner: partner.publicKey,
+        referralAccount: referralAccountPubkey,
+      })
+      .signers([partner])
+      .rpc();
+  });
+
+  it("admin sets project metadata", async () => {
+    await program.methods
+      .initializeProjectMetadata()
+      .accounts({
+        payer: admin.payer.publicKey,
+        admin: admin.payer.publicKey,
+        project: projectPubkey,
+        metadata: projectMetadataPubkey,
+      })
+      .signers([admin.payer])
+      .rpc();
+
+    let metadata = await program.account.metadata.fetch(projectMetadataPubkey);
+    expect(metadata.target).to.eql(projectPubkey);
+    expect(metadata.uri).to.eql("");
+
+    const uri = "https://example.com/project.json";
+    let metadataEvent;
+    const listener = program.addEventListener(
+      "SetProjectMetadataEvent",
+      (event) => {
+        metadataEvent = event;
+      },
+    );
+
+    await program.methods
+      .setProjectMetadata({ uri, contentHash: contentHash("project") })
+      .accounts({
+        admin: admin.payer.publicKey,
+        project: projectPubkey,
+        metadata: projectMetadataPubkey,
+      })
+      .signers([admin.payer])
+      .rpc();
+
+    await new Promise((resolve) => setTimeout(resolve, 1000));
+    await program.removeEventListener(listener);
+
+    metadata = await program.account.metadata.fetch(projectMetadataPubkey);
+    expect(metadata.uri).to.eql(uri);
+    expect(metadata.contentHash).to.eql(contentHash("project"));
+    expect(metadataEvent.uri).to.eql(uri);
+    expect(metadataEvent.metadata).to.eql(projectMetadataPubkey);
+
+    await expectError(
+      program.methods
+        .setProjectMetadata({
+          uri: "x".repeat(201),
+          contentHash: contentHash("project"),
+        })
+        .accounts({
+          admin: admin.payer.publicKey,
+          project: projectPubkey,
+          metadata: projectMetadataPubkey,
+        })
+        .signers([admin.payer])
+        .rpc(),
+      "MetadataUriTooLong",
+    );
+  });
+
+  it("partner sets referral account metadata", async () => {
+    const uri = "https://example.com/partner.json";
+
+    await program.methods
+      .setReferralAccountMetadata({ uri, contentHash: contentHash("partner") })
+      .accounts({
+        payer: partner.publicKey,
+        partner: partner.publicKey,
+        referralAccount: referralAccountPubkey,
+        metadata: referralMetadataPubkey,
+      })
+      .signers([partner])
+      .rpc();
+
+    let metadata = await program.account.metadata.fetch(
+      referralMetadataPubkey,
+    );
+    expect(metadata.target).to.eql(referralAccountPubkey);
+    expect(metadata.uri).to.eql(uri);
+
+    // Updating reuses the existing account.
+    await program.methods
+      .setReferralAccountMetadata({
+        uri: "ipfs://partner",
+        contentHash: contentHash("partner v2"),
+      })
+      .accounts({
+        payer: partner.publicKey,
+        partner: partner.publicKey,
+        referralAccount: referralAccountPubkey,
+        metadata: referralMetadataPubkey,
+      })
+      .signers([partner])
+      .rpc();
+
+    metadata = await program.account.metadata.fetch(referralMetadataPubkey);
+    expect(metadata.uri).to.eql("ipfs://partner");
+    expect(metadata.contentHash).to.eql(contentHash("partner v2"));
+
+    await expectError(
+      program.methods
+        .setReferralAccountMetadata({ uri, contentHash: contentHash("admin") })
+        .accounts({
+          payer: admin.payer.publicKey,
+          partner: admin.payer.publicKey,
+          referralAccount: referralAccountPubkey,
+          metadata: referralMetadataPubkey,
+        })
+        .signers([admin.payer])
+        .rpc(),
+      "ConstraintHasOne",
+    );
+  });
+});
@@ -0,0 +1,487 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import {
+  MINT_SIZE,
+  MintLayout,
+  TOKEN_PROGRAM_ID,
+  getAssociatedTokenAddressSync,
+} from "@solana/spl-token";
+import { BankrunProvider } from "anchor-bankrun";
+import { AssertionError, expect } from "chai";
+import * as fs from "fs";
+import { ProgramTestContext, startAnchor } from "solana-bankrun";
+
+import { IDL, Referral } from "../target/types/referral";
+
+// Account dumps in the layout from before accounts were versioned. The validator can't write
+// program owned accounts, so these tests run against bankrun, which loads them with the project
+// admin patched in.
+const loadFixture = (name: string) => {
+  const { pubkey, account } = JSON.parse(
+    fs.readFileSync(`tests/fixtures/${name}.json`, "utf-8"),
+  );
+
+  return {
+    address: new anchor.web3.PublicKey(pubkey),
+    info: {
+      lamports: account.lamports,
+      data: Buffer.from(account.data[0], "base64"),
+      owner: new anchor.web3.PublicKey(account.owner),
+      executable: false,
+    },
+  };
+};
+
+describe("migrate accounts", () => {
+  const legacyProjectAdmin = anchor.web3.Keypair.generate();
+  const legacyProject = loadFixture("legacy-project");
+  const legacyReferralAccount = loadFixture("legacy-referral-account");
+  // The admin follows the account discriminator and the base.
+  legacyProjectAdmin.publicKey.toBuffer().copy(legacyProject.info.data, 40);
+
+  let context: ProgramTestContext;
+  let program: Program<Referral>;
+  let payer: anchor.web3.Keypair;
+  let projectPubkey: anchor.web3.PublicKey;
+  let referralAccountPubkey: anchor.web3.PublicKey;
+
+  const getAccount = async (address: anchor.web3.PublicKey) =>
+    context.banksClient.getAccount(address);
+
+  const rentExemption = async (len: number) =>
+    (await context.banksClient.getRent()).minimumBalance(BigInt(len));
+
+  // Stores an account of the program, funded for its length.
+  const setAccount = async (address: anchor.web3.PublicKey, data: Buffer) => {
+    context.setAccount(address, {
+      lamports: Number(await rentExemption(data.length)),
+      data,
+      owner: program.programId,
+      executable: false,
+    });
+  };
+
+  // Encodes a project in the layout from before accounts were versioned.
+  const encodeLegacyProject = (
+    base: anchor.web3.PublicKey,
+    admin: anchor.web3.PublicKey,
+    name: string,
+    defaultShareBps: number,
+  ) => {
+    const data = Buffer.alloc(legacyProject.info.data.length);
+    legacyProject.info.data.copy(data, 0, 0, 8);
+    base.toBuffer().copy(data, 8);
+    admin.toBuffer().copy(data, 40);
+    data.writeUInt32LE(Buffer.byteLength(name), 72);
+    const nameEnd = 76 + data.write(name, 76);
+    data.writeUInt16LE(defaultShareBps, nameEnd);
+
+    return data;
+  };
+
+  // Bankrun errors don't carry the program logs, so failures are checked against the logs of the
+  // processed transaction.
+  const expectError = async (
+    transaction: anchor.web3.Transaction,
+    signers: anchor.web3.Keypair[],
+    code: string,
+  ) => {
+    transaction.recentBlockhash = context.lastBlockhash;
+    transaction.feePayer = payer.publicKey;
+    transaction.sign(payer, ...signers);
+
+    const result = await context.banksClient.tryProcessTransaction(transaction);
+    expect(result.result).to.not.be.null;
+    expect(result.meta.logMessages.join("\n")).to.include(code);
+  };
+
+  const migrateProject = (
+    project: anchor.web3.PublicKey,
+    admin: anchor.web3.Keypair,
+  ) =>
+    program.methods
+      .migrateProject()
+      .accounts({ payer: payer.publicKey, admin: admin.publicKey, project })
+      .signers([admin])
+      .rpc();
+
+  const migrateReferralAccount = (referralAccount: anchor.web3.PublicKey) =>
+    program.methods
+      .migrateReferralAccount()
+      .accounts({ payer: payer.publicKey, referralAccount })
+      .rpc();
+
+  before(async () => {
+    context = await startAnchor(".", [], [legacyProject, legacyReferralAccount]);
+    const provider = new BankrunProvider(context);
+    program = new Program<Referral>(
+      IDL,
+      anchor.workspace.Referral.programId,
+      provider,
+    );
+    payer = context.payer;
+
+    const base = anchor.web3.Keypair.generate();
+    [projectPubkey] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("project"), base.publicKey.toBuffer()],
+      program.programId,
+    );
+    await program.methods
+      .initializeProject({ name: "Referral", defaultShareBps: 5000 })
+      .accounts({
+        payer: payer.publicKey,
+        base: base.publicKey,
+        admin: payer.publicKey,
+        project: projectPubkey,
+      })
+      .signers([base])
+      .rpc();
+
+    const referralName = "current";
+    [referralAccountPubkey] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("referral"),
+        projectPubkey.toBuffer(),
+        Buffer.from(referralName),
+      ],
+      program.programId,
+    );
+    await program.methods
+      .initializeReferralAccountWithName({ name: referralName })
+      .accounts({
+        payer: payer.publicKey,
+        project: projectPubkey,
+        partner: payer.publicKey,
+        referralAccount: referralAccountPubkey,
+      })
+      .rpc();
+  });
+
+  it("new accounts start at the current version", async () => {
+    const project = await program.account.project.fetch(projectPubkey);
+    expect(project.version).to.eql(2);
+    const referralAccount = await program.account.referralAccount.fetch(
+      referralAccountPubkey,
+    );
+    expect(referralAccount.version).to.eql(3);
+  });
+
+  it("grows a legacy project to the current layout", async () => {
+    // Its long name leaves too little padding for the fields added since.
+    try {
+      await program.account.project.fetch(legacyProject.address);
+
+      expect(false, "should've failed but didn't").to.be.true;
+    } catch (err) {
+      expect(err).to.not.be.instanceOf(AssertionError);
+    }
+
+    const outsider = anchor.web3.Keypair.generate();
+    await expectError(
+      await program.methods
+        .migrateProject()
+        .accounts({
+          payer: payer.publicKey,
+          admin: outsider.publicKey,
+          project: legacyProject.address,
+        })
+        .transaction(),
+      [outsider],
+      "ConstraintHasOne",
+    );
+
+    await migrateProject(legacyProject.address, legacyProjectAdmin);
+
+    const project = await program.account.project.fetch(legacyProject.address);
+    expect(project.version).to.eql(2);
+    expect(project.admin).to.eql(legacyProjectAdmin.publicKey);
+    expect(project.name).to.eql("\u{1F680}".repeat(49));
+    expect(project.defaultShareBps).to.eql(2500);
+    expect(project.multisigThreshold).to.eql(0);
+    expect(project.multisigSigners).to.eql([]);
+    // The admin token account budget starts at the default rather than the zeroed bytes.
+    const currentProject = await program.account.project.fetch(projectPubkey);
+    expect(project.adminTokenAccountPolicy).to.eql(
+      currentProject.adminTokenAccountPolicy,
+    );
+
+    const [legacyInfo, currentInfo] = await Promise.all([
+      getAccount(legacyProject.address),
+      getAccount(projectPubkey),
+    ]);
+    expect(legacyInfo.data.length).to.eql(currentInfo.data.length);
+    expect(BigInt(legacyInfo.lamports)).to.eql(
+      await rentExemption(legacyInfo.data.length),
+    );
+
+    // Migrating an up to date project changes nothing. The admin pays this time, so the
+    // transaction differs from the previous one.
+    context.setAccount(legacyProjectAdmin.publicKey, {
+      lamports: anchor.web3.LAMPORTS_PER_SOL,
+      data: Buffer.alloc(0),
+      owner: anchor.web3.SystemProgram.programId,
+      executable: false,
+    });
+    await program.methods
+      .migrateProject()
+      .accounts({
+        payer: legacyProjectAdmin.publicKey,
+        admin: legacyProjectAdmin.publicKey,
+        project: legacyProject.address,
+      })
+      .signers([legacyProjectAdmin])
+      .rpc();
+
+    expect((await getAccount(legacyProject.address)).data).to.eql(
+      legacyInfo.data,
+    );
+  });
+
+  it("clears the end of a shortened legacy project name", async () => {
+    // Renaming a legacy project rewrote the name in place, leaving the end of the longer one
+    // after the default share.
+    const data = Buffer.from(legacyProject.info.data);
+    data.writeUInt32LE(Buffer.byteLength("legacy"), 72);
+    const nameEnd = 76 + data.write("legacy", 76);
+    data.writeUInt16LE(1000, nameEnd);
+    expect(data.subarray(nameEnd + 2).some((byte) => byte !== 0)).to.be.true;
+
+    const shortenedProject = anchor.web3.Keypair.generate().publicKey;
+    await setAccount(shortenedProject, data);
+
+    await migrateProject(shortenedProject, legacyProjectAdmin);
+
+    const project = await program.account.project.fetch(shortenedProject);
+    const currentProject = await program.account.project.fetch(projectPubkey);
+    expect(project.version).to.eql(2);
+    expect(project.admin).to.eql(legacyProjectAdmin.publicKey);
+    expect(project.name).to.eql("legacy");
+    expect(project.defaultShareBps).to.eql(1000);
+    expect(project.multisigThreshold).to.eql(0);
+    expect(project.multisigSigners).to.eql([]);
+    expect(project.shareDecreaseNoticePeriod.toNumber()).to.eql(0);
+    expect(project.pendingNoticePeriod).to.be.null;
+    expect(project.pendingDefaultShare).to.be.null;
+    expect(project.swapProgram).to.be.null;
+    expect(project.keeperFeeBps).to.eql(0);
+    expect(project.sponsorshipEnabled).to.be.false;
+    expect(project.adminTokenAccountPolicy).to.eql(
+      currentProject.adminTokenAccountPolicy,
+    );
+    expect(project.attributionPolicy.allowOverride).to.be.false;
+  });
+
+  it("migrates a version 1 project", async () => {
+    // Version 2 took the attribution policy out of the reserved bytes, which version 1 left zeroed.
+    const current = await program.account.project.fetch(projectPubkey);
+    const { data } = await getAccount(projectPubkey);
+    const v1Data = Buffer.alloc(data.length);
+    (
+      await program.coder.accounts.encode("project", { ...current, version: 1 })
+    ).copy(v1Data);
+
+    const v1Project = anchor.web3.Keypair.generate().publicKey;
+    await setAccount(v1Project, v1Data);
+
+    await migrateProject(v1Project, payer);
+
+    const project = await program.account.project.fetch(v1Project);
+    expect(project.version).to.eql(2);
+    expect(project.name).to.eql(current.name);
+    expect(project.defaultShareBps).to.eql(current.defaultShareBps);
+    expect(project.attributionPolicy.allowOverride).to.be.false;
+    expect(project.attributionPolicy.expiry.toNumber()).to.eql(0);
+    expect((await getAccount(v1Project)).data.length).to.eql(data.length);
+  });
+
+  it("an unmigrated project creates admin token accounts within the default budget", async () => {
+    // A short name leaves room for the current layout, so the project deserializes with the
+    // admin token account policy read from the zeroed bytes.
+    const base = anchor.web3.Keypair.generate().publicKey;
+    const shortLegacyProject = anchor.web3.Keypair.generate().publicKey;
+    await setAccount(
+      shortLegacyProject,
+      encodeLegacyProject(base, payer.publicKey, "legacy", 2500),
+    );
+    const project = await program.account.project.fetch(shortLegacyProject);
+    expect(project.version).to.eql(0);
+    expect(
+      project.adminTokenAccountPolicy.epochBudgetLamports.toNumber(),
+    ).to.eql(0);
+
+    const [projectAuthority] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("project_authority"), base.toBuffer()],
+      program.programId,
+    );
+    context.setAccount(projectAuthority, {
+      lamports: anchor.web3.LAMPORTS_PER_SOL,
+      data: Buffer.alloc(0),
+      owner: anchor.web3.SystemProgram.programId,
+      executable: false,
+    });
+    const mint = anchor.web3.Keypair.generate().publicKey;
+    const mintData = Buffer.alloc(MINT_SIZE);
+    MintLayout.encode(
+      {
+        mintAuthorityOption: 1,
+        mintAuthority: payer.publicKey,
+        supply: BigInt(0),
+        decimals: 6,
+        isInitialized: true,
+        freezeAuthorityOption: 0,
+        freezeAuthority: anchor.web3.PublicKey.default,
+      },
+      mintData,
+    );
+    context.setAccount(mint, {
+      lamports: Number(await rentExemption(MINT_SIZE)),
+      data: mintData,
+      owner: TOKEN_PROGRAM_ID,
+      executable: false,
+    });
+
+    const projectAdminTokenAccount = getAssociatedTokenAddressSync(
+      mint,
+      payer.publicKey,
+    );
+    await program.methods
+      .createAdminTokenAccount()
+      .accounts({
+        admin: payer.publicKey,
+        mint,
+        project: shortLegacyProject,
+        projectAdminTokenAccount,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        projectAuthority,
+      })
+      .rpc();
+
+    expect(await getAccount(projectAdminTokenAccount)).to.not.be.null;
+  });
+
+  it("anyone can migrate a legacy referral account", async () => {
+    // Short enough to still deserialize, with the fields added since read as zero.
+    let referralAccount = await program.account.referralAccount.fetch(
+      legacyReferralAccount.address,
+    );
+    expect(referralAccount.version).to.eql(0);
+
+    await migrateReferralAccount(legacyReferralAccount.address);
+
+    referralAccount = await program.account.referralAccount.fetch(
+      legacyReferralAccount.address,
+    );
+    expect(referralAccount.version).to.eql(3);
+    expect(referralAccount.project).to.eql(legacyProject.address);
+    expect(referralAccount.shareBps).to.eql(1500);
+    expect(referralAccount.name).to.eql("legacy");
+    expect(referralAccount.pendingShare).to.be.null;
+    expect(referralAccount.inheritDefault).to.be.false;
+    expect(referralAccount.displayName).to.be.null;
+    expect(referralAccount.rebateBps).to.eql(0);
+  });
+
+  // Encodes the current referral account with `fields` overridden, as `len` bytes.
+  const encodeReferralAccount = async (
+    fields: Partial<anchor.IdlAccounts<Referral>["referralAccount"]>,
+    len: number,
+  ) => {
+    const current = await program.account.referralAccount.fetch(
+      referralAccountPubkey,
+    );
+    const data = Buffer.alloc(
+      (await getAccount(referralAccountPubkey)).data.length,
+    );
+    (
+      await program.coder.accounts.encode("referralAccount", {
+        ...current,
+        ...fields,
+      })
+    ).copy(data);
+
+    // The fields past `len` have to be zero, so that truncating only drops padding.
+    return data.subarray(0, len);
+  };
+
+  it("migrates a version 1 referral account", async () => {
+    // Version 2 appended the display name, which the realloc reads as zero, that is None.
+    const { data } = await getAccount(referralAccountPubkey);
+    const displayNameLen = 1 + 4 + 50 * 4;
+    const v1Data = await encodeReferralAccount(
+      { version: 1, displayName: null, rebateBps: 0 },
+      data.length - displayNameLen,
+    );
+
+    const v1ReferralAccount = anchor.web3.Keypair.generate().publicKey;
+    await setAccount(v1ReferralAccount, v1Data);
+
+    await migrateReferralAccount(v1ReferralAccount);
+
+    const referralAccount = await program.account.referralAccount.fetch(
+      v1ReferralAccount,
+    );
+    expect(referralAccount.version).to.eql(3);
+    expect(referralAccount.name).to.eql("current");
+    expect(referralAccount.displayName).to.be.null;
+    expect(referralAccount.rebateBps).to.eql(0);
+    expect((await getAccount(v1ReferralAccount)).data.length).to.eql(
+      data.length,
+    );
+  });
+
+  it("migrates a version 2 referral account", async () => {
+    // Version 3 took the rebate out of the reserved bytes, which version 2 left zeroed.
+    const { data } = await getAccount(referralAccountPubkey);
+    const v2Data = await encodeReferralAccount(
+      { version: 2, displayName: "Brand", rebateBps: 0 },
+      data.length,
+    );
+
+    const v2ReferralAccount = anchor.web3.Keypair.generate().publicKey;
+    await setAccount(v2ReferralAccount, v2Data);
+
+    await migrateReferralAccount(v2ReferralAccount);
+
+    const referralAccount = await program.account.referralAccount.fetch(
+      v2ReferralAccount,
+    );
+    expect(referralAccount.version).to.eql(3);
+    expect(referralAccount.displayName).to.eql("Brand");
+    expect(referralAccount.rebateBps).to.eql(0);
+  });
+
+  it("setting a display name requires a migrated referral account", async () => {
+    const { data } = await getAccount(referralAccountPubkey);
+    const v1Data = await encodeReferralAccount(
+      { version: 1, displayName: null, rebateBps: 0 },
+      data.length - (1 + 4 + 50 * 4),
+    );
+
+    const v1ReferralAccount = anchor.web3.Keypair.generate().publicKey;
+    await setAccount(v1ReferralAccount, v1Data);
+
+    // The names differ so that the second transaction isn't a duplicate of the first.
+    const setDisplayName = (displayName: string) =>
+      program.methods
+        .setReferralAccountDisplayName({ displayName })
+        .accounts({
+          partner: payer.publicKey,
+          referralAccount: v1ReferralAccount,
+        });
+    await expectError(
+      await setDisplayName("Old Brand").transaction(),
+      [],
+      "ReferralAccountNotMigrated",
+    );
+
+    await migrateReferralAccount(v1ReferralAccount);
+    await setDisplayName("Brand").rpc();
+
+    const referralAccount = await program.account.referralAccount.fetch(
+      v1ReferralAccount,
+    );
+    expect(referralAccount.version).to.eql(3);
+    expect(referralAccount.displayName).to.eql("Brand");
+    expect(referralAccount.rebateBps).to.eql(0);
+  });
+});
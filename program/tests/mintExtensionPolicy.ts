@@ -0,0 +1,259 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import {
+  createAssociatedTokenAccountInstruction,
+  createInitializeMintInstruction,
+  createInitializeNonTransferableMintInstruction,
+  createInitializePermanentDelegateInstruction,
+  ExtensionType,
+  getAssociatedTokenAddressSync,
+  getMintLen,
+  TOKEN_2022_PROGRAM_ID,
+} from "@solana/spl-token";
+import { expect } from "chai";
+
+import { Referral } from "../target/types/referral";
+import { fundAccount, eventCpiAccounts, expectError } from "./helpers/helpers";
+
+describe("mint extension policy", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.Referral as Program<Referral>;
+  const admin = anchor.workspace.Referral.provider.wallet;
+
+  let base: anchor.web3.Keypair;
+  let partner: anchor.web3.Keypair;
+  let projectPubkey: anchor.web3.PublicKey;
+  let referralAccountPubkey: anchor.web3.PublicKey;
+  let referralName = "PolicyReferral";
+
+  const createMintWithExtension = async (
+    extension: ExtensionType,
+    initializeExtensionIx: (
+      mint: anchor.web3.PublicKey,
+    ) => anchor.web3.TransactionInstruction,
+  ) => {
+    const mint = anchor.web3.Keypair.generate();
+    const mintLen = getMintLen([extension]);
+    const lamports =
+      await provider.connection.getMinimumBalanceForRentExemption(mintLen);
+    await provider.sendAndConfirm(
+      new anchor.web3.Transaction().add(
+        anchor.web3.SystemProgram.createAccount({
+          fromPubkey: admin.payer.publicKey,
+          newAccountPubkey: mint.publicKey,
+          space: mintLen,
+          lamports,
+          programId: TOKEN_2022_PROGRAM_ID,
+        }),
+        initializeExtensionIx(mint.publicKey),
+        createInitializeMintInstruction(
+          mint.publicKey,
+          0,
+          admin.payer.publicKey,
+          null,
+          TOKEN_2022_PROGRAM_ID,
+        ),
+      ),
+      [mint],
+    );
+    return mint.publicKey;
+  };
+
+  const initializeReferralTokenAccountV2 = async (
+    mint: anchor.web3.PublicKey,
+  ) => {
+    await program.methods
+      .initializeReferralTokenAccountV2()
+      .accountsStrict({
+        payer: admin.payer.publicKey,
+        project: projectPubkey,
+        referralAccount: referralAccountPubkey,
+        referralTokenAccount: getAssociatedTokenAddressSync(
+          mint,
+          referralAccountPubkey,
+          true,
+          TOKEN_2022_PROGRAM_ID,
+        ),
+        mint,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        associatedTokenProgram: anchor.utils.token.ASSOCIATED_PROGRAM_ID,
+        mintPolicy: null,
+        ...eventCpiAccounts,
+      })
+      .signers([admin.payer])
+      .rpc();
+  };
+
+  beforeEach(async () => {
+    base = anchor.web3.Keypair.generate();
+    partner = anchor.web3.Keypair.generate();
+    await fundAccount(partner.publicKey, provider);
+
+    [projectPubkey] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("project"), base.publicKey.toBuffer()],
+      program.programId,
+    );
+
+    await program.methods
+      .initializeProject({ name: "Referral", defaultShareBps: 5000 })
+      .accounts({
+        payer: admin.payer.publicKey,
+        base: base.publicKey,
+        admin: admin.payer.publicKey,
+        project: projectPubkey,
+      })
+      .signers([base, admin.payer])
+      .rpc();
+
+    [referralAccountPubkey] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("referral"),
+        projectPubkey.toBuffer(),
+        Buffer.from(referralName),
+      ],
+      program.programId,
+    );
+
+    await program.methods
+      .initializeReferralAccountWithName({ name: referralName })
+      .accounts({
+        payer: partner.publicKey,
+        project: projectPubkey,
+        partner: partner.publicKey,
+        referralAccount: referralAccountPubkey,
+      })
+      .signers([partner])
+      .rpc();
+  });
+
+  it("raised for a non-transferable mint", async () => {
+    const mint = await createMintWithExtension(
+      ExtensionType.NonTransferable,
+      (mint) =>
+        createInitializeNonTransferableMintInstruction(
+          mint,
+          TOKEN_2022_PROGRAM_ID,
+        ),
+    );
+
+    await expectError(
+      initializeReferralTokenAccountV2(mint),
+      "MintNonTransferable",
+    );
+  });
+
+  it("raised for a mint with a permanent delegate", async () => {
+    const mint = await createMintWithExtension(
+      ExtensionType.PermanentDelegate,
+      (mint) =>
+        createInitializePermanentDelegateInstruction(
+          mint,
+          admin.payer.publicKey,
+          TOKEN_2022_PROGRAM_ID,
+        ),
+    );
+
+    await expectError(
+      initializeReferralTokenAccountV2(mint),
+      "MintHasPermanentDelegate",
+    );
+  });
+
+  it("Is able to open a referral token account for a mint the policy allows", async () => {
+    const mint = await createMintWithExtension(
+      ExtensionType.PermanentDelegate,
+      (mint) =>
+        createInitializePermanentDelegateInstruction(
+          mint,
+          admin.payer.publicKey,
+          TOKEN_2022_PROGRAM_ID,
+        ),
+    );
+
+    await program.methods
+      .setProjectMintExtensionPolicy({
+        policy: {
+          allowPermanentDelegate: true,
+          allowDefaultFrozen: false,
+          allowNonTransferable: false,
+          allowPausable: false,
+        },
+      })
+      .accounts({
+        admin: admin.payer.publicKey,
+        project: projectPubkey,
+      })
+      .signers([admin.payer])
+      .rpc();
+
+    await initializeReferralTokenAccountV2(mint);
+
+    const referralTokenAccount = await provider.connection.getAccountInfo(
+      getAssociatedTokenAddressSync(
+        mint,
+        referralAccountPubkey,
+        true,
+        TOKEN_2022_PROGRAM_ID,
+      ),
+    );
+    expect(referralTokenAccount).to.not.be.null;
+  });
+
+  it("raised when claiming from a referral token account opened without the program", async () => {
+    const mint = await createMintWithExtension(
+      ExtensionType.PermanentDelegate,
+      (mint) =>
+        createInitializePermanentDelegateInstruction(
+          mint,
+          admin.payer.publicKey,
+          TOKEN_2022_PROGRAM_ID,
+        ),
+    );
+    const ata = (owner: anchor.web3.PublicKey) =>
+      getAssociatedTokenAddressSync(mint, owner, true, TOKEN_2022_PROGRAM_ID);
+    const referralTokenAccount = ata(referralAccountPubkey);
+
+    // Skips the screening of initialize_referral_token_account_v2.
+    await provider.sendAndConfirm(
+      new anchor.web3.Transaction().add(
+        createAssociatedTokenAccountInstruction(
+          admin.payer.publicKey,
+          referralTokenAccount,
+          referralAccountPubkey,
+          mint,
+          TOKEN_2022_PROGRAM_ID,
+        ),
+      ),
+    );
+
+    await expectError(
+      program.methods
+        .claimV2()
+        .accountsStrict({
+          payer: admin.payer.publicKey,
+          admin: admin.payer.publicKey,
+          partner: partner.publicKey,
+          project: projectPubkey,
+          projectAdminTokenAccount: ata(admin.payer.publicKey),
+          referralAccount: referralAccountPubkey,
+          referralTokenAccount,
+          partnerTokenAccount: ata(partner.publicKey),
+          mint,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+          associatedTokenProgram: anchor.utils.token.ASSOCIATED_PROGRAM_ID,
+          mintPolicy: null,
+          claimThresholds: null,
+          keeperTokenAccount: null,
+          rebatePoolTokenAccount: null,
+          ...eventCpiAccounts,
+        })
+        .signers([admin.payer])
+        .rpc(),
+      "MintHasPermanentDelegate",
+    );
+  });
+});
@@ -0,0 +1,176 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { SPL_TOKEN_PROGRAM_ID, splTokenProgram } from "@coral-xyz/spl-token";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { expect } from "chai";
+
+import { Referral } from "../target/types/referral";
+import {
+  createTokenMint,
+  fundAccount,
+  eventCpiAccounts,
+  expectError,
+} from "./helpers/helpers";
+
+describe("project mint policy", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.Referral as Program<Referral>;
+  const admin = anchor.workspace.Referral.provider.wallet;
+  const tokenProgram = splTokenProgram({
+    provider,
+    programId: SPL_TOKEN_PROGRAM_ID,
+  });
+
+  let base: anchor.web3.Keypair;
+  let partner: anchor.web3.Keypair;
+  let projectPubkey: anchor.web3.PublicKey;
+  let mintPolicyPubkey: anchor.web3.PublicKey;
+  let referralAccountPubkey: anchor.web3.PublicKey;
+  let token: anchor.web3.PublicKey;
+  let referralName = "PolicyReferral";
+
+  const initializeReferralTokenAccountV2 = async (
+    mintPolicy: anchor.web3.PublicKey | null,
+  ) => {
+    await program.methods
+      .initializeReferralTokenAccountV2()
+      .accountsStrict({
+        payer: admin.payer.publicKey,
+        project: projectPubkey,
+        referralAccount: referralAccountPubkey,
+        referralTokenAccount: getAssociatedTokenAddressSync(
+          token,
+          referralAccountPubkey,
+          true,
+        ),
+        mint: token,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        tokenProgram: tokenProgram.programId,
+        associatedTokenProgram: anchor.utils.token.ASSOCIATED_PROGRAM_ID,
+        mintPolicy,
+        ...eventCpiAccounts,
+      })
+      .signers([admin.payer])
+      .rpc();
+  };
+
+  beforeEach(async () => {
+    base = anchor.web3.Keypair.generate();
+    partner = anchor.web3.Keypair.generate();
+    await fundAccount(partner.publicKey, provider);
+
+    [projectPubkey] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("project"), base.publicKey.toBuffer()],
+      program.programId,
+    );
+    [mintPolicyPubkey] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("mint_policy"), projectPubkey.toBuffer()],
+      program.programId,
+    );
+
+    await program.methods
+      .initializeProject({ name: "Referral", defaultShareBps: 5000 })
+      .accounts({
+        payer: admin.payer.publicKey,
+        base: base.publicKey,
+        admin: admin.payer.publicKey,
+        project: projectPubkey,
+      })
+      .signers([base, admin.payer])
+      .rpc();
+
+    [referralAccountPubkey] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("referral"),
+        projectPubkey.toBuffer(),
+        Buffer.from(referralName),
+      ],
+      program.programId,
+    );
+
+    await program.methods
+      .initializeReferralAccountWithName({ name: referralName })
+      .accounts({
+        payer: partner.publicKey,
+        project: projectPubkey,
+        partner: partner.publicKey,
+        referralAccount: referralAccountPubkey,
+      })
+      .signers([partner])
+      .rpc();
+
+    token = await createTokenMint(tokenProgram, provider);
+
+    await program.methods
+      .initializeProjectMintPolicy()
+      .accounts({
+        payer: admin.payer.publicKey,
+        admin: admin.payer.publicKey,
+        project: projectPubkey,
+        mintPolicy: mintPolicyPubkey,
+      })
+      .signers([admin.payer])
+      .rpc();
+
+    await program.methods
+      .setProjectMintPolicyMode({ mode: { allow: {} } })
+      .accounts({
+        admin: admin.payer.publicKey,
+        project: projectPubkey,
+        mintPolicy: mintPolicyPubkey,
+      })
+      .signers([admin.payer])
+      .rpc();
+  });
+
+  it("raised if the mint is not on the allow list", async () => {
+    await expectError(
+      initializeReferralTokenAccountV2(mintPolicyPubkey),
+      "MintNotAllowed",
+    );
+  });
+
+  it("raised if the mint policy account is missing", async () => {
+    await expectError(
+      initializeReferralTokenAccountV2(null),
+      "MissingMintPolicyAccount",
+    );
+  });
+
+  it("Is able to open a referral token account for an allowed mint", async () => {
+    await program.methods
+      .addProjectPolicyMint({ mint: token })
+      .accounts({
+        admin: admin.payer.publicKey,
+        project: projectPubkey,
+        mintPolicy: mintPolicyPubkey,
+      })
+      .signers([admin.payer])
+      .rpc();
+
+    const mintPolicy = await program.account.projectMintPolicy.fetch(
+      mintPolicyPubkey,
+    );
+    expect(mintPolicy.mints.map((mint) => mint.toBase58())).to.deep.equal([
+      token.toBase58(),
+    ]);
+
+    await initializeReferralTokenAccountV2(mintPolicyPubkey);
+
+    await program.methods
+      .removeProjectPolicyMint({ mint: token })
+      .accounts({
+        admin: admin.payer.publicKey,
+        project: projectPubkey,
+        mintPolicy: mintPolicyPubkey,
+      })
+      .signers([admin.payer])
+      .rpc();
+
+    expect(
+      (await program.account.projectMintPolicy.fetch(mintPolicyPubkey)).mints,
+    ).to.be.empty;
+  });
+});
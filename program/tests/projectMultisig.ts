@@ -0,0 +1,262 @@
+import * as anchor from "@coral-xyz/anchor";
+import { AnchorError, BN, Program } from "@coral-xyz/anchor";
+import { expect } from "chai";
+
+import { Referral } from "../target/types/referral";
+import { fundAccount } from "./helpers/helpers";
+
+describe("project multisig", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.Referral as Program<Referral>;
+  const admin = anchor.workspace.Referral.provider.wallet;
+  let base: anchor.web3.Keypair;
+  let signerA: anchor.web3.Keypair;
+  let signerB: anchor.web3.Keypair;
+  let projectPubkey: anchor.web3.PublicKey;
+  let projectName = "Referral";
+  let defaultShareBps = 5000;
+
+  const findProposal = (id: BN) =>
+    anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("proposal"),
+        projectPubkey.toBuffer(),
+        id.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId,
+    )[0];
+
+  const expiresAt = () => new BN(Math.floor(Date.now() / 1000) + 3600);
+
+  beforeEach(async () => {
+    base = anchor.web3.Keypair.generate();
+    signerA = anchor.web3.Keypair.generate();
+    signerB = anchor.web3.Keypair.generate();
+    await fundAccount(signerA.publicKey, provider);
+    await fundAccount(signerB.publicKey, provider);
+
+    const [projectProgramAddress] =
+      anchor.web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("project"), base.publicKey.toBuffer()],
+        program.programId,
+      );
+
+    projectPubkey = projectProgramAddress;
+
+    await program.methods
+      .initializeProject({ name: projectName, defaultShareBps })
+      .accounts({
+        payer: admin.payer.publicKey,
+        base: base.publicKey,
+        admin: admin.payer.publicKey,
+        project: projectPubkey,
+      })
+      .signers([base, admin.payer])
+      .rpc();
+
+    await program.methods
+      .setProjectMultisig({
+        signers: [signerA.publicKey, signerB.publicKey],
+        threshold: 2,
+      })
+      .accounts({
+        admin: admin.payer.publicKey,
+        project: projectPubkey,
+      })
+      .signers([admin.payer])
+      .rpc();
+  });
+
+  it("stores the signer set on the project", async () => {
+    const project = await program.account.project.fetch(projectPubkey);
+    expect(project.multisigThreshold).to.eql(2);
+    expect(project.multisigSigners).to.eql([
+      signerA.publicKey,
+      signerB.publicKey,
+    ]);
+  });
+
+  it("rejects direct admin instructions", async () => {
+    try {
+      await program.methods
+        .updateProject({ name: null, defaultShareBps: 1000 })
+        .accounts({
+          admin: admin.payer.publicKey,
+          project: projectPubkey,
+        })
+        .signers([admin.payer])
+        .rpc();
+
+      chai.assert(false, "should've failed but didn't");
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      const err: AnchorError = _err;
+      expect(err.error.errorCode.code).to.equal("MultisigRequired");
+    }
+  });
+
+  it("executes a proposal once the threshold is met", async () => {
+    const id = new BN(1);
+    const proposal = findProposal(id);
+
+    await program.methods
+      .createProjectProposal({
+        id,
+        action: {
+          updateProject: {
+            0: { name: null, defaultShareBps: 1000 },
+          },
+        },
+        expiresAt: expiresAt(),
+      })
+      .accounts({
+        proposer: signerA.publicKey,
+        project: projectPubkey,
+        proposal,
+      })
+      .signers([signerA])
+      .rpc();
+
+    const execute = () =>
+      program.methods
+        .executeProjectProposal()
+        .accounts({
+          executor: signerA.publicKey,
+          project: projectPubkey,
+          proposal,
+          referralAccount: null,
+          projectAuthority: null,
+          admin: null,
+        })
+        .signers([signerA])
+        .rpc();
+
+    try {
+      await execute();
+
+      chai.assert(false, "should've failed but didn't");
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      const err: AnchorError = _err;
+      expect(err.error.errorCode.code).to.equal("ProposalThresholdNotMet");
+    }
+
+    await program.methods
+      .approveProjectProposal()
+      .accounts({
+        signer: signerB.publicKey,
+        project: projectPubkey,
+        proposal,
+      })
+      .signers([signerB])
+      .rpc();
+
+    await execute();
+
+    const project = await program.account.project.fetch(projectPubkey);
+    expect(project.defaultShareBps).to.eql(1000);
+
+    await program.methods
+      .closeProjectProposal()
+      .accounts({
+        proposer: signerA.publicKey,
+        proposal,
+      })
+      .signers([signerA])
+      .rpc();
+
+    const proposalAccount = await provider.connection.getAccountInfo(proposal);
+    expect(proposalAccount).to.be.null;
+  });
+
+  it("drops approvals of signers rotated out of the multisig", async () => {
+    const signerC = anchor.web3.Keypair.generate();
+    await fundAccount(signerC.publicKey, provider);
+
+    const createProposal = (id: BN, action) =>
+      program.methods
+        .createProjectProposal({ id, action, expiresAt: expiresAt() })
+        .accounts({
+          proposer: signerA.publicKey,
+          project: projectPubkey,
+          proposal: findProposal(id),
+        })
+        .signers([signerA])
+        .rpc();
+    const approve = (signer: anchor.web3.Keypair, id: BN) =>
+      program.methods
+        .approveProjectProposal()
+        .accounts({
+          signer: signer.publicKey,
+          project: projectPubkey,
+          proposal: findProposal(id),
+        })
+        .signers([signer])
+        .rpc();
+
+    const pendingId = new BN(3);
+    await createProposal(pendingId, {
+      updateProject: { 0: { name: null, defaultShareBps: 1000 } },
+    });
+    await approve(signerB, pendingId);
+
+    // Rotate signerB out for signerC.
+    const rotateId = new BN(4);
+    await createProposal(rotateId, {
+      setMultisig: {
+        0: { signers: [signerA.publicKey, signerC.publicKey], threshold: 2 },
+      },
+    });
+    await approve(signerB, rotateId);
+    await program.methods
+      .executeProjectProposal()
+      .accounts({
+        executor: signerA.publicKey,
+        project: projectPubkey,
+        proposal: findProposal(rotateId),
+        referralAccount: null,
+        projectAuthority: null,
+        admin: null,
+      })
+      .signers([signerA])
+      .rpc();
+
+    await approve(signerC, pendingId);
+
+    const proposal = await program.account.projectProposal.fetch(
+      findProposal(pendingId),
+    );
+    expect(proposal.approvals).to.eql([signerA.publicKey, signerC.publicKey]);
+  });
+
+  it("fails if the proposer is not a signer", async () => {
+    const outsider = anchor.web3.Keypair.generate();
+    await fundAccount(outsider.publicKey, provider);
+
+    const id = new BN(2);
+
+    try {
+      await program.methods
+        .createProjectProposal({
+          id,
+          action: { transferProject: { newAdmin: outsider.publicKey } },
+          expiresAt: expiresAt(),
+        })
+        .accounts({
+          proposer: outsider.publicKey,
+          project: projectPubkey,
+          proposal: findProposal(id),
+        })
+        .signers([outsider])
+        .rpc();
+
+      chai.assert(false, "should've failed but didn't");
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      const err: AnchorError = _err;
+      expect(err.error.errorCode.code).to.equal("NotMultisigSigner");
+    }
+  });
+});
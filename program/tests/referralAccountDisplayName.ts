@@ -0,0 +1,131 @@
+import * as anchor from "@coral-xyz/anchor";
+import { AnchorError, Program } from "@coral-xyz/anchor";
+import { expect } from "chai";
+
+import { Referral } from "../target/types/referral";
+import { fundAccount } from "./helpers/helpers";
+
+describe("referral account display name", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.Referral as Program<Referral>;
+  const admin = anchor.workspace.Referral.provider.wallet;
+  let base: anchor.web3.Keypair;
+  let partner: anchor.web3.Keypair;
+  let projectPubkey: anchor.web3.PublicKey;
+  let referralAccountPubkey: anchor.web3.PublicKey;
+  let referralName = "SeedName";
+
+  beforeEach(async () => {
+    base = anchor.web3.Keypair.generate();
+    partner = anchor.web3.Keypair.generate();
+    await fundAccount(partner.publicKey, provider);
+
+    [projectPubkey] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("project"), base.publicKey.toBuffer()],
+      program.programId,
+    );
+
+    await program.methods
+      .initializeProject({ name: "Referral", defaultShareBps: 5000 })
+      .accounts({
+        payer: admin.payer.publicKey,
+        base: base.publicKey,
+        admin: admin.payer.publicKey,
+        project: projectPubkey,
+      })
+      .signers([base, admin.payer])
+      .rpc();
+
+    [referralAccountPubkey] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("referral"),
+        projectPubkey.toBuffer(),
+        Buffer.from(referralName),
+      ],
+      program.programId,
+    );
+
+    await program.methods
+      .initializeReferralAccountWithName({ name: referralName })
+      .accounts({
+        payer: partner.publicKey,
+        project: projectPubkey,
+        partner: partner.publicKey,
+        referralAccount: referralAccountPubkey,
+      })
+      .signers([partner])
+      .rpc();
+  });
+
+  it("partner rebrands without touching the seed name", async () => {
+    let updateEvent;
+    const listener = program.addEventListener(
+      "UpdateReferralAccountMetadataEvent",
+      (event) => {
+        updateEvent = event;
+      },
+    );
+
+    await program.methods
+      .setReferralAccountDisplayName({ displayName: "New Brand" })
+      .accounts({
+        partner: partner.publicKey,
+        referralAccount: referralAccountPubkey,
+      })
+      .signers([partner])
+      .rpc();
+
+    await new Promise((resolve) => setTimeout(resolve, 1000));
+    await program.removeEventListener(listener);
+
+    const referralAccount = await program.account.referralAccount.fetch(
+      referralAccountPubkey,
+    );
+    expect(referralAccount.name).to.eql(referralName);
+    expect(referralAccount.displayName).to.eql("New Brand");
+
+    expect(updateEvent.name).to.eql(referralName);
+    expect(updateEvent.previousDisplayName).to.be.null;
+    expect(updateEvent.displayName).to.eql("New Brand");
+  });
+
+  it("failed if the partner is not signer", async () => {
+    try {
+      await program.methods
+        .setReferralAccountDisplayName({ displayName: "Hijacked" })
+        .accounts({
+          partner: admin.payer.publicKey,
+          referralAccount: referralAccountPubkey,
+        })
+        .signers([admin.payer])
+        .rpc();
+
+      expect(false, "should've failed but didn't").to.be.true;
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      const err: AnchorError = _err;
+      expect(err.error.errorCode.code).to.equal("ConstraintHasOne");
+    }
+  });
+
+  it("raised if the display name is too long", async () => {
+    try {
+      await program.methods
+        .setReferralAccountDisplayName({ displayName: "x".repeat(50) })
+        .accounts({
+          partner: partner.publicKey,
+          referralAccount: referralAccountPubkey,
+        })
+        .signers([partner])
+        .rpc();
+
+      expect(false, "should've failed but didn't").to.be.true;
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      const err: AnchorError = _err;
+      expect(err.error.errorCode.code).to.equal("NameTooLong");
+    }
+  });
+});
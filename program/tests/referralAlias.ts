@@ -0,0 +1,131 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { expect } from "chai";
+
+import { Referral } from "../target/types/referral";
+import { fundAccount, expectError } from "./helpers/helpers";
+
+describe("referral alias", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.Referral as Program<Referral>;
+  const admin = anchor.workspace.Referral.provider.wallet;
+  let base: anchor.web3.Keypair;
+  let partner: anchor.web3.Keypair;
+  let projectPubkey: anchor.web3.PublicKey;
+  let referralAccountPubkey: anchor.web3.PublicKey;
+  let referralName = "alice";
+
+  const aliasPubkey = (alias: string) =>
+    anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("alias"), projectPubkey.toBuffer(), Buffer.from(alias)],
+      program.programId,
+    )[0];
+
+  const createAlias = (alias: string, signer = partner) =>
+    program.methods
+      .createReferralAlias({ alias })
+      .accounts({
+        payer: signer.publicKey,
+        partner: signer.publicKey,
+        project: projectPubkey,
+        referralAccount: referralAccountPubkey,
+        alias: aliasPubkey(alias),
+      })
+      .signers([signer])
+      .rpc();
+
+  beforeEach(async () => {
+    base = anchor.web3.Keypair.generate();
+    partner = anchor.web3.Keypair.generate();
+    await fundAccount(partner.publicKey, provider);
+
+    [projectPubkey] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("project"), base.publicKey.toBuffer()],
+      program.programId,
+    );
+
+    await program.methods
+      .initializeProject({ name: "Referral", defaultShareBps: 5000 })
+      .accounts({
+        payer: admin.payer.publicKey,
+        base: base.publicKey,
+        admin: admin.payer.publicKey,
+        project: projectPubkey,
+      })
+      .signers([base, admin.payer])
+      .rpc();
+
+    [referralAccountPubkey] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("referral"),
+        projectPubkey.toBuffer(),
+        Buffer.from(referralName),
+      ],
+      program.programId,
+    );
+
+    await program.methods
+      .initializeReferralAccountWithName({ name: referralName })
+      .accounts({
+        payer: partner.publicKey,
+        project: projectPubkey,
+        partner: partner.publicKey,
+        referralAccount: referralAccountPubkey,
+      })
+      .signers([partner])
+      .rpc();
+  });
+
+  it("several aliases resolve to the same referral account", async () => {
+    await createAlias("alice-yt");
+    await createAlias("alice-tw");
+
+    for (const alias of ["alice-yt", "alice-tw"]) {
+      const resolved = await program.methods
+        .resolveReferralAlias()
+        .accounts({ alias: aliasPubkey(alias) })
+        .view();
+      expect(resolved).to.eql(referralAccountPubkey);
+    }
+
+    const aliasAccount = await program.account.alias.fetch(
+      aliasPubkey("alice-yt"),
+    );
+    expect(aliasAccount.project).to.eql(projectPubkey);
+    expect(aliasAccount.alias).to.eql("alice-yt");
+  });
+
+  it("partner deletes an alias", async () => {
+    await createAlias("alice-yt");
+
+    await program.methods
+      .deleteReferralAlias()
+      .accounts({
+        partner: partner.publicKey,
+        referralAccount: referralAccountPubkey,
+        alias: aliasPubkey("alice-yt"),
+      })
+      .signers([partner])
+      .rpc();
+
+    expect(
+      await provider.connection.getAccountInfo(aliasPubkey("alice-yt")),
+    ).to.be.null;
+
+    // The alias is free to be taken again.
+    await createAlias("alice-yt");
+  });
+
+  it("raised if the alias is empty", async () => {
+    await expectError(createAlias(""), "InvalidAlias");
+  });
+
+  it("failed if the partner is not signer", async () => {
+    await expectError(
+      createAlias("mallory", admin.payer),
+      "ConstraintHasOne",
+    );
+  });
+});
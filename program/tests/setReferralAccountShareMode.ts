@@ -0,0 +1,138 @@
+import * as anchor from "@coral-xyz/anchor";
+import { AnchorError, Program } from "@coral-xyz/anchor";
+import { expect } from "chai";
+
+import { Referral } from "../target/types/referral";
+import { fundAccount } from "./helpers/helpers";
+
+describe("set referral account share mode", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.Referral as Program<Referral>;
+  const admin = anchor.workspace.Referral.provider.wallet;
+  let base: anchor.web3.Keypair;
+  let partner: anchor.web3.Keypair;
+  let projectPubkey: anchor.web3.PublicKey;
+  let referralAccountKeypair: anchor.web3.Keypair;
+  let projectName = "Referral";
+  let defaultShareBps = 5000;
+
+  beforeEach(async () => {
+    base = anchor.web3.Keypair.generate();
+    partner = anchor.web3.Keypair.generate();
+    fundAccount(partner.publicKey, provider);
+
+    const [projectProgramAddress] =
+      await anchor.web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("project"), base.publicKey.toBuffer()],
+        program.programId,
+      );
+
+    projectPubkey = projectProgramAddress;
+
+    await program.methods
+      .initializeProject({ name: projectName, defaultShareBps })
+      .accounts({
+        base: base.publicKey,
+        admin: admin.payer.publicKey,
+        project: projectPubkey,
+      })
+      .signers([base, admin.payer])
+      .rpc();
+
+    referralAccountKeypair = anchor.web3.Keypair.generate();
+
+    await program.methods
+      .initializeReferralAccount({})
+      .accounts({
+        payer: partner.publicKey,
+        project: projectPubkey,
+        partner: partner.publicKey,
+        referralAccount: referralAccountKeypair.publicKey,
+      })
+      .signers([partner, referralAccountKeypair])
+      .rpc();
+  });
+
+  const setShareMode = (inheritDefault: boolean) =>
+    program.methods
+      .setReferralAccountShareMode({ inheritDefault })
+      .accounts({
+        project: projectPubkey,
+        referralAccount: referralAccountKeypair.publicKey,
+        admin: admin.payer.publicKey,
+      })
+      .signers([admin.payer])
+      .rpc();
+
+  const updateDefaultShareBps = (defaultShareBps: number) =>
+    program.methods
+      .updateProject({
+        name: null,
+        defaultShareBps,
+        shareDecreaseNoticePeriod: null,
+      })
+      .accounts({
+        admin: admin.payer.publicKey,
+        project: projectPubkey,
+      })
+      .signers([admin.payer])
+      .rpc();
+
+  it("follows the project default share when inherited", async () => {
+    await setShareMode(true);
+    await updateDefaultShareBps(7000);
+
+    let referralAccount = await program.account.referralAccount.fetch(
+      referralAccountKeypair.publicKey,
+    );
+    expect(referralAccount.inheritDefault).to.eql(true);
+
+    // Pinning snapshots the current default share.
+    await setShareMode(false);
+
+    referralAccount = await program.account.referralAccount.fetch(
+      referralAccountKeypair.publicKey,
+    );
+    expect(referralAccount.inheritDefault).to.eql(false);
+    expect(referralAccount.shareBps).to.eql(7000);
+  });
+
+  it("will fail to inherit a lower default share during a notice period", async () => {
+    await program.methods
+      .updateProject({
+        name: null,
+        defaultShareBps: null,
+        shareDecreaseNoticePeriod: new anchor.BN(3600),
+      })
+      .accounts({
+        admin: admin.payer.publicKey,
+        project: projectPubkey,
+      })
+      .signers([admin.payer])
+      .rpc();
+
+    await program.methods
+      .updateReferralAccount({ shareBps: 9000 })
+      .accounts({
+        project: projectPubkey,
+        referralAccount: referralAccountKeypair.publicKey,
+        admin: admin.payer.publicKey,
+      })
+      .signers([admin.payer])
+      .rpc();
+
+    try {
+      await setShareMode(true);
+
+      chai.assert(false, "should've failed but didn't");
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      const err: AnchorError = _err;
+      expect(err.error.errorCode.code).to.equal(
+        "ShareModeChangeDecreasesShare",
+      );
+    }
+  });
+});
@@ -0,0 +1,169 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN, Program } from "@coral-xyz/anchor";
+import { expect } from "chai";
+
+import { Referral } from "../target/types/referral";
+import { expectError } from "./helpers/helpers";
+
+describe("sponsorship", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.Referral as Program<Referral>;
+  const admin = anchor.workspace.Referral.provider.wallet;
+
+  let base: anchor.web3.Keypair;
+  let partner: anchor.web3.Keypair;
+  let projectPubkey: anchor.web3.PublicKey;
+  let projectAuthorityPubkey: anchor.web3.PublicKey;
+  let partnerSponsorshipPubkey: anchor.web3.PublicKey;
+
+  const initializeSponsoredReferralAccount = async (name: string) => {
+    const [referralAccountPubkey] =
+      anchor.web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("referral"), projectPubkey.toBuffer(), Buffer.from(name)],
+        program.programId,
+      );
+
+    await program.methods
+      .initializeSponsoredReferralAccountWithName({ name })
+      .accounts({
+        payer: admin.payer.publicKey,
+        partner: partner.publicKey,
+        project: projectPubkey,
+        projectAuthority: projectAuthorityPubkey,
+        referralAccount: referralAccountPubkey,
+        partnerSponsorship: partnerSponsorshipPubkey,
+      })
+      .signers([admin.payer, partner])
+      .rpc();
+
+    return referralAccountPubkey;
+  };
+
+  const setSponsorship = async (
+    enabled: boolean,
+    maxSponsoredLamportsPerPartner: number,
+  ) => {
+    await program.methods
+      .setProjectSponsorship({
+        enabled,
+        maxSponsoredLamportsPerPartner: new BN(maxSponsoredLamportsPerPartner),
+      })
+      .accounts({
+        admin: admin.payer.publicKey,
+        project: projectPubkey,
+      })
+      .signers([admin.payer])
+      .rpc();
+  };
+
+  beforeEach(async () => {
+    base = anchor.web3.Keypair.generate();
+    // The partner holds no SOL at all.
+    partner = anchor.web3.Keypair.generate();
+
+    [projectPubkey] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("project"), base.publicKey.toBuffer()],
+      program.programId,
+    );
+    [projectAuthorityPubkey] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("project_authority"), base.publicKey.toBuffer()],
+      program.programId,
+    );
+    [partnerSponsorshipPubkey] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("partner_sponsorship"),
+        projectPubkey.toBuffer(),
+        partner.publicKey.toBuffer(),
+      ],
+      program.programId,
+    );
+
+    await program.methods
+      .initializeProject({ name: "Referral", defaultShareBps: 5000 })
+      .accounts({
+        payer: admin.payer.publicKey,
+        base: base.publicKey,
+        admin: admin.payer.publicKey,
+        project: projectPubkey,
+      })
+      .signers([base, admin.payer])
+      .rpc();
+
+    await program.methods
+      .fundProjectAuthority({ amount: new BN(anchor.web3.LAMPORTS_PER_SOL) })
+      .accounts({
+        funder: admin.payer.publicKey,
+        project: projectPubkey,
+        projectAuthority: projectAuthorityPubkey,
+      })
+      .signers([admin.payer])
+      .rpc();
+  });
+
+  it("raised if sponsorship is disabled", async () => {
+    await expectError(
+      initializeSponsoredReferralAccount("Sponsored"),
+      "SponsorshipDisabled",
+    );
+  });
+
+  it("Is able to onboard a partner with the project authority's SOL", async () => {
+    await setSponsorship(true, anchor.web3.LAMPORTS_PER_SOL / 10);
+
+    const payerBalanceBefore = await provider.connection.getBalance(
+      admin.payer.publicKey,
+    );
+    const projectAuthorityBalanceBefore = await provider.connection.getBalance(
+      projectAuthorityPubkey,
+    );
+
+    const referralAccountPubkey = await initializeSponsoredReferralAccount(
+      "Sponsored",
+    );
+
+    const rent =
+      (await provider.connection.getBalance(referralAccountPubkey)) +
+      (await provider.connection.getBalance(partnerSponsorshipPubkey));
+    expect(
+      await provider.connection.getBalance(projectAuthorityPubkey),
+    ).to.equal(projectAuthorityBalanceBefore - rent);
+    // The payer is only out of the transaction fee.
+    expect(
+      await provider.connection.getBalance(admin.payer.publicKey),
+    ).to.be.greaterThan(payerBalanceBefore - 10000);
+
+    const partnerSponsorship = await program.account.partnerSponsorship.fetch(
+      partnerSponsorshipPubkey,
+    );
+    expect(partnerSponsorship.sponsoredLamports.toNumber()).to.equal(rent);
+  });
+
+  it("raised if the partner cap is exceeded", async () => {
+    await setSponsorship(true, 1);
+
+    await expectError(
+      initializeSponsoredReferralAccount("Sponsored"),
+      "SponsorshipCapExceeded",
+    );
+  });
+
+  it("counts every referral account of the partner against the same cap", async () => {
+    await setSponsorship(true, anchor.web3.LAMPORTS_PER_SOL / 10);
+    await initializeSponsoredReferralAccount("Sponsored");
+
+    const partnerSponsorship = await program.account.partnerSponsorship.fetch(
+      partnerSponsorshipPubkey,
+    );
+    await setSponsorship(
+      true,
+      partnerSponsorship.sponsoredLamports.toNumber(),
+    );
+
+    await expectError(
+      initializeSponsoredReferralAccount("SponsoredAgain"),
+      "SponsorshipCapExceeded",
+    );
+  });
+});
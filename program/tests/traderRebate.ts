@@ -0,0 +1,407 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN, Program } from "@coral-xyz/anchor";
+import { ASSOCIATED_PROGRAM_ID } from "@coral-xyz/anchor/dist/cjs/utils/token";
+import { SPL_TOKEN_PROGRAM_ID, splTokenProgram } from "@coral-xyz/spl-token";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { expect } from "chai";
+import { createHash } from "crypto";
+
+import { Referral } from "../target/types/referral";
+import {
+  createAssociatedTokenAccountWithOffCurve,
+  createTokenAccount,
+  createTokenMint,
+  fundAccount,
+  fundTokenAccount,
+  getAccountBalance,
+  eventCpiAccounts,
+  setReferralAccountRebate,
+  expectError,
+} from "./helpers/helpers";
+
+const u64 = (value: number) => new BN(value).toArrayLike(Buffer, "le", 8);
+
+const rebateLeaf = (
+  round: number,
+  index: number,
+  trader: anchor.web3.PublicKey,
+  amount: number,
+) =>
+  createHash("sha256")
+    .update(
+      Buffer.concat([
+        Buffer.from([0]),
+        u64(round),
+        u64(index),
+        trader.toBuffer(),
+        u64(amount),
+      ]),
+    )
+    .digest();
+
+const rebateNode = (a: Buffer, b: Buffer) => {
+  const [left, right] = Buffer.compare(a, b) <= 0 ? [a, b] : [b, a];
+  return createHash("sha256")
+    .update(Buffer.concat([Buffer.from([1]), left, right]))
+    .digest();
+};
+
+describe("trader rebate", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.Referral as Program<Referral>;
+  const admin = anchor.workspace.Referral.provider.wallet;
+  const tokenProgram = splTokenProgram({
+    provider,
+    programId: SPL_TOKEN_PROGRAM_ID,
+  });
+
+  let base: anchor.web3.Keypair;
+  let partner: anchor.web3.Keypair;
+  let traders: anchor.web3.Keypair[];
+  let projectPubkey: anchor.web3.PublicKey;
+  let referralAccountPubkey: anchor.web3.PublicKey;
+  let referralTokenAccount: anchor.web3.PublicKey;
+  let rebatePoolPubkey: anchor.web3.PublicKey;
+  let rebatePoolTokenAccount: anchor.web3.PublicKey;
+  let token: anchor.web3.PublicKey;
+  let referralName = "RebateReferral";
+  let referralAmount = 1e6;
+  let defaultShareBps = 5000;
+  let rebateBps = 2000;
+
+  const claimV2 = (rebatePoolTokenAccount: anchor.web3.PublicKey | null) =>
+    program.methods
+      .claimV2()
+      .accountsStrict({
+        payer: admin.payer.publicKey,
+        admin: admin.payer.publicKey,
+        partner: partner.publicKey,
+        project: projectPubkey,
+        projectAdminTokenAccount: getAssociatedTokenAddressSync(
+          token,
+          admin.payer.publicKey,
+        ),
+        referralAccount: referralAccountPubkey,
+        referralTokenAccount,
+        partnerTokenAccount: getAssociatedTokenAddressSync(
+          token,
+          partner.publicKey,
+        ),
+        mint: token,
+        tokenProgram: tokenProgram.programId,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+        mintPolicy: null,
+        claimThresholds: null,
+        keeperTokenAccount: null,
+        rebatePoolTokenAccount,
+        ...eventCpiAccounts,
+      })
+      .signers([admin.payer])
+      .rpc();
+
+  const claimRebate = async (
+    trader: anchor.web3.Keypair,
+    round: number,
+    index: number,
+    amount: number,
+    proof: Buffer[],
+  ) => {
+    const [rebateBitmap] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("rebate_bitmap"),
+        rebatePoolPubkey.toBuffer(),
+        u64(round),
+        u64(Math.floor(index / 4096)),
+      ],
+      program.programId,
+    );
+
+    await program.methods
+      .claimRebate({
+        index: new BN(index),
+        amount: new BN(amount),
+        proof: proof.map((node) => Array.from(node)),
+      })
+      .accountsStrict({
+        trader: trader.publicKey,
+        rebatePool: rebatePoolPubkey,
+        rebatePoolTokenAccount,
+        traderTokenAccount: getAssociatedTokenAddressSync(
+          token,
+          trader.publicKey,
+        ),
+        rebateBitmap,
+        mint: token,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        tokenProgram: tokenProgram.programId,
+        associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+        ...eventCpiAccounts,
+      })
+      .signers([trader])
+      .rpc();
+  };
+
+  beforeEach(async () => {
+    base = anchor.web3.Keypair.generate();
+    partner = anchor.web3.Keypair.generate();
+    traders = [anchor.web3.Keypair.generate(), anchor.web3.Keypair.generate()];
+    await fundAccount(partner.publicKey, provider);
+    for (const trader of traders) {
+      await fundAccount(trader.publicKey, provider);
+    }
+
+    [projectPubkey] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("project"), base.publicKey.toBuffer()],
+      program.programId,
+    );
+
+    await program.methods
+      .initializeProject({ name: "Referral", defaultShareBps })
+      .accounts({
+        payer: admin.payer.publicKey,
+        base: base.publicKey,
+        admin: admin.payer.publicKey,
+        project: projectPubkey,
+      })
+      .signers([base, admin.payer])
+      .rpc();
+
+    [referralAccountPubkey] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("referral"),
+        projectPubkey.toBuffer(),
+        Buffer.from(referralName),
+      ],
+      program.programId,
+    );
+
+    await program.methods
+      .initializeReferralAccountWithName({ name: referralName })
+      .accounts({
+        payer: partner.publicKey,
+        project: projectPubkey,
+        partner: partner.publicKey,
+        referralAccount: referralAccountPubkey,
+      })
+      .signers([partner])
+      .rpc();
+
+    await program.methods
+      .setReferralAccountRebate({ rebateBps })
+      .accounts({
+        admin: admin.payer.publicKey,
+        project: projectPubkey,
+        referralAccount: referralAccountPubkey,
+      })
+      .signers([admin.payer])
+      .rpc();
+
+    token = await createTokenMint(tokenProgram, provider);
+    referralTokenAccount = await createAssociatedTokenAccountWithOffCurve(
+      token,
+      tokenProgram.programId,
+      referralAccountPubkey,
+      provider,
+    );
+    await fundTokenAccount(
+      referralTokenAccount,
+      token,
+      admin.payer,
+      referralAmount,
+      tokenProgram,
+    );
+
+    [rebatePoolPubkey] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("rebate_pool"),
+        referralAccountPubkey.toBuffer(),
+        token.toBuffer(),
+      ],
+      program.programId,
+    );
+    rebatePoolTokenAccount = getAssociatedTokenAddressSync(
+      token,
+      rebatePoolPubkey,
+      true,
+    );
+
+    await program.methods
+      .initializeRebatePool()
+      .accountsStrict({
+        payer: admin.payer.publicKey,
+        project: projectPubkey,
+        referralAccount: referralAccountPubkey,
+        rebatePool: rebatePoolPubkey,
+        rebatePoolTokenAccount,
+        mint: token,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        tokenProgram: tokenProgram.programId,
+        associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+        ...eventCpiAccounts,
+      })
+      .signers([admin.payer])
+      .rpc();
+  });
+
+  it("sets aside the rebate out of the referral amount", async () => {
+    let claimEvent;
+    const listener = program.addEventListener("ClaimEvent", (event) => {
+      claimEvent = event;
+    });
+
+    await claimV2(rebatePoolTokenAccount);
+
+    await new Promise((resolve) => setTimeout(resolve, 1000));
+    await program.removeEventListener(listener);
+
+    const referralShare = (referralAmount * defaultShareBps) / 10000;
+    const rebateAmount = (referralShare * rebateBps) / 10000;
+    expect(await getAccountBalance(rebatePoolTokenAccount, provider)).to.equal(
+      rebateAmount,
+    );
+    expect(
+      await getAccountBalance(
+        getAssociatedTokenAddressSync(token, partner.publicKey),
+        provider,
+      ),
+    ).to.equal(referralShare - rebateAmount);
+    expect(
+      await getAccountBalance(
+        getAssociatedTokenAddressSync(token, admin.payer.publicKey),
+        provider,
+      ),
+    ).to.equal(referralAmount - referralShare);
+
+    expect(claimEvent.rebateTokenAccount).to.eql(rebatePoolTokenAccount);
+    expect(claimEvent.rebateAmount.toNumber()).to.equal(rebateAmount);
+    expect(claimEvent.referralAmount.toNumber()).to.equal(
+      referralShare - rebateAmount,
+    );
+  });
+
+  it("requires the rebate pool token account", async () => {
+    await expectError(claimV2(null), "MissingRebatePoolAccount");
+  });
+
+  it("raised if the rebate token account isn't the pool's", async () => {
+    const traderTokenAccount = await createTokenAccount(
+      token,
+      tokenProgram.programId,
+      traders[0].publicKey,
+      provider,
+    );
+
+    await expectError(
+      claimV2(traderTokenAccount),
+      "InvalidRebatePoolAccount",
+    );
+  });
+
+  it("queues rebate increases behind the notice period", async () => {
+    const noticePeriod = 3600;
+    await program.methods
+      .updateProject({
+        name: null,
+        defaultShareBps: null,
+        shareDecreaseNoticePeriod: new BN(noticePeriod),
+      })
+      .accounts({
+        admin: admin.payer.publicKey,
+        project: projectPubkey,
+      })
+      .signers([admin.payer])
+      .rpc();
+
+    await setReferralAccountRebate(
+      projectPubkey,
+      referralAccountPubkey,
+      admin.payer,
+      5000,
+    );
+
+    let referralAccount = await program.account.referralAccount.fetch(
+      referralAccountPubkey,
+    );
+    expect(referralAccount.rebateBps).to.eql(rebateBps);
+    expect(referralAccount.pendingRebate.shareBps).to.eql(5000);
+    expect(
+      referralAccount.pendingRebate.effectiveAt.toNumber() -
+        Math.floor(Date.now() / 1000),
+    ).to.be.greaterThan(noticePeriod - 60);
+
+    // Claims keep the current rebate until the increase is due.
+    await claimV2(rebatePoolTokenAccount);
+    const referralShare = (referralAmount * defaultShareBps) / 10000;
+    expect(await getAccountBalance(rebatePoolTokenAccount, provider)).to.equal(
+      (referralShare * rebateBps) / 10000,
+    );
+
+    // Decreases apply immediately and drop the queued increase.
+    await setReferralAccountRebate(
+      projectPubkey,
+      referralAccountPubkey,
+      admin.payer,
+      1000,
+    );
+
+    referralAccount = await program.account.referralAccount.fetch(
+      referralAccountPubkey,
+    );
+    expect(referralAccount.rebateBps).to.eql(1000);
+    expect(referralAccount.pendingRebate).to.be.null;
+  });
+
+  it("lets traders claim their rebate once against the published root", async () => {
+    await claimV2(rebatePoolTokenAccount);
+
+    const amounts = [60_000, 40_000];
+    const round = 1;
+    const leaves = traders.map((trader, index) =>
+      rebateLeaf(round, index, trader.publicKey, amounts[index]),
+    );
+    const merkleRoot = rebateNode(leaves[0], leaves[1]);
+
+    await program.methods
+      .publishRebateRoot({ merkleRoot: Array.from(merkleRoot) })
+      .accounts({
+        admin: admin.payer.publicKey,
+        project: projectPubkey,
+        rebatePool: rebatePoolPubkey,
+      })
+      .signers([admin.payer])
+      .rpc();
+
+    const rebatePool = await program.account.rebatePool.fetch(
+      rebatePoolPubkey,
+    );
+    expect(rebatePool.round.toNumber()).to.equal(round);
+
+    await expectError(
+      claimRebate(traders[0], round, 0, amounts[0] + 1, [leaves[1]]),
+      "InvalidMerkleProof",
+    );
+
+    await claimRebate(traders[0], round, 0, amounts[0], [leaves[1]]);
+    await claimRebate(traders[1], round, 1, amounts[1], [leaves[0]]);
+
+    for (const [index, trader] of traders.entries()) {
+      expect(
+        await getAccountBalance(
+          getAssociatedTokenAddressSync(token, trader.publicKey),
+          provider,
+        ),
+      ).to.equal(amounts[index]);
+    }
+    expect(await getAccountBalance(rebatePoolTokenAccount, provider)).to.equal(
+      0,
+    );
+
+    await expectError(
+      claimRebate(traders[0], round, 0, amounts[0], [leaves[1]]),
+      "RebateAlreadyClaimed",
+    );
+  });
+});
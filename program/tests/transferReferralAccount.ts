@@ -59,6 +59,14 @@ describe("initialize referral token account", () => {
   });
 
   it("able to transfer referral account!", async () => {
+    let transferEvent;
+    const listener = program.addEventListener(
+      "TransferReferralAccountEvent",
+      (event) => {
+        transferEvent = event;
+      },
+    );
+
     await program.methods
       .transferReferralAccount({})
       .accounts({
@@ -69,6 +77,13 @@ describe("initialize referral token account", () => {
       .signers([partner])
       .rpc();
 
+    await new Promise((resolve) => setTimeout(resolve, 1000));
+    await program.removeEventListener(listener);
+
+    expect(transferEvent.project).to.eql(projectPubkey);
+    expect(transferEvent.previousPartner).to.eql(partner.publicKey);
+    expect(transferEvent.newPartner).to.eql(newPartner.publicKey);
+
     const referralAccount = await program.account.referralAccount.fetch(
       referralAccountKeypair.publicKey,
     );
@@ -76,6 +76,60 @@ describe("update referral account", () => {
     expect(referralAccount.shareBps).to.eql(updateShareBps);
   });
 
+  it("queues share decreases behind the notice period", async () => {
+    const noticePeriod = 3600;
+
+    await program.methods
+      .updateProject({
+        name: null,
+        defaultShareBps: null,
+        shareDecreaseNoticePeriod: new anchor.BN(noticePeriod),
+      })
+      .accounts({
+        admin: admin.payer.publicKey,
+        project: projectPubkey,
+      })
+      .signers([admin.payer])
+      .rpc();
+
+    await program.methods
+      .updateReferralAccount({ shareBps: 1000 })
+      .accounts({
+        project: projectPubkey,
+        referralAccount: referralAccountKeypair.publicKey,
+        admin: admin.payer.publicKey,
+      })
+      .signers([admin.payer])
+      .rpc();
+
+    let referralAccount = await program.account.referralAccount.fetch(
+      referralAccountKeypair.publicKey,
+    );
+    expect(referralAccount.shareBps).to.eql(defaultShareBps);
+    expect(referralAccount.pendingShare.shareBps).to.eql(1000);
+    expect(
+      referralAccount.pendingShare.effectiveAt.toNumber() -
+        Math.floor(Date.now() / 1000),
+    ).to.be.greaterThan(noticePeriod - 60);
+
+    // Increases apply immediately and drop the queued decrease.
+    await program.methods
+      .updateReferralAccount({ shareBps: 6000 })
+      .accounts({
+        project: projectPubkey,
+        referralAccount: referralAccountKeypair.publicKey,
+        admin: admin.payer.publicKey,
+      })
+      .signers([admin.payer])
+      .rpc();
+
+    referralAccount = await program.account.referralAccount.fetch(
+      referralAccountKeypair.publicKey,
+    );
+    expect(referralAccount.shareBps).to.eql(6000);
+    expect(referralAccount.pendingShare).to.be.null;
+  });
+
   it("will failed if signer is not admin!", async () => {
     const updateShareBps = 1000;
 
@@ -0,0 +1,219 @@
+import * as anchor from "@coral-xyz/anchor";
+import { AnchorError, BN, Program } from "@coral-xyz/anchor";
+import { expect } from "chai";
+
+import { Referral } from "../target/types/referral";
+import { fundAccount } from "./helpers/helpers";
+
+describe("update referral accounts", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.Referral as Program<Referral>;
+  const admin = anchor.workspace.Referral.provider.wallet;
+  let partner: anchor.web3.Keypair;
+  let projectName = "Referral";
+  let defaultShareBps = 5000;
+
+  const initializeProject = async () => {
+    const base = anchor.web3.Keypair.generate();
+    const [projectPubkey] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("project"), base.publicKey.toBuffer()],
+      program.programId,
+    );
+
+    await program.methods
+      .initializeProject({ name: projectName, defaultShareBps })
+      .accounts({
+        payer: admin.payer.publicKey,
+        base: base.publicKey,
+        admin: admin.payer.publicKey,
+        project: projectPubkey,
+      })
+      .signers([base, admin.payer])
+      .rpc();
+
+    return projectPubkey;
+  };
+
+  const initializeReferralAccount = async (
+    projectPubkey: anchor.web3.PublicKey,
+  ) => {
+    const referralAccountKeypair = anchor.web3.Keypair.generate();
+
+    await program.methods
+      .initializeReferralAccount({})
+      .accounts({
+        payer: partner.publicKey,
+        project: projectPubkey,
+        partner: partner.publicKey,
+        referralAccount: referralAccountKeypair.publicKey,
+      })
+      .signers([partner, referralAccountKeypair])
+      .rpc();
+
+    return referralAccountKeypair.publicKey;
+  };
+
+  beforeEach(async () => {
+    partner = anchor.web3.Keypair.generate();
+    await fundAccount(partner.publicKey, provider);
+  });
+
+  it("updates every referral account", async () => {
+    const projectPubkey = await initializeProject();
+    const referralAccounts = [
+      await initializeReferralAccount(projectPubkey),
+      await initializeReferralAccount(projectPubkey),
+    ];
+    const shareBps = [1000, 9000];
+
+    await program.methods
+      .updateReferralAccounts({ shareBps })
+      .accounts({
+        admin: admin.payer.publicKey,
+        project: projectPubkey,
+      })
+      .remainingAccounts(
+        referralAccounts.map((pubkey) => ({
+          pubkey,
+          isSigner: false,
+          isWritable: true,
+        })),
+      )
+      .signers([admin.payer])
+      .rpc();
+
+    for (const [index, pubkey] of referralAccounts.entries()) {
+      const referralAccount = await program.account.referralAccount.fetch(
+        pubkey,
+      );
+      expect(referralAccount.shareBps).to.eql(shareBps[index]);
+    }
+  });
+
+  it("will fail if a referral account belongs to another project", async () => {
+    const projectPubkey = await initializeProject();
+    const otherProjectPubkey = await initializeProject();
+    const referralAccounts = [
+      await initializeReferralAccount(projectPubkey),
+      await initializeReferralAccount(otherProjectPubkey),
+    ];
+
+    try {
+      await program.methods
+        .updateReferralAccounts({ shareBps: [1000, 1000] })
+        .accounts({
+          admin: admin.payer.publicKey,
+          project: projectPubkey,
+        })
+        .remainingAccounts(
+          referralAccounts.map((pubkey) => ({
+            pubkey,
+            isSigner: false,
+            isWritable: true,
+          })),
+        )
+        .signers([admin.payer])
+        .rpc();
+
+      chai.assert(false, "should've failed but didn't");
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      const err: AnchorError = _err;
+      expect(err.error.errorCode.code).to.equal(
+        "InvalidReferralAccountsUpdate",
+      );
+    }
+
+    // Nothing was applied since the instruction failed as a whole.
+    const referralAccount = await program.account.referralAccount.fetch(
+      referralAccounts[0],
+    );
+    expect(referralAccount.shareBps).to.eql(defaultShareBps);
+  });
+
+  it("updates every referral account through a multisig proposal", async () => {
+    const projectPubkey = await initializeProject();
+    const referralAccounts = [
+      await initializeReferralAccount(projectPubkey),
+      await initializeReferralAccount(projectPubkey),
+    ];
+    const shareBps = [1000, 9000];
+
+    const signer = anchor.web3.Keypair.generate();
+    await fundAccount(signer.publicKey, provider);
+    await program.methods
+      .setProjectMultisig({ signers: [signer.publicKey], threshold: 1 })
+      .accounts({
+        admin: admin.payer.publicKey,
+        project: projectPubkey,
+      })
+      .signers([admin.payer])
+      .rpc();
+
+    const id = new BN(1);
+    const [proposal] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("proposal"),
+        projectPubkey.toBuffer(),
+        id.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId,
+    );
+    await program.methods
+      .createProjectProposal({
+        id,
+        action: { updateReferralAccounts: { referralAccounts, shareBps } },
+        expiresAt: new BN(Math.floor(Date.now() / 1000) + 3600),
+      })
+      .accounts({
+        proposer: signer.publicKey,
+        project: projectPubkey,
+        proposal,
+      })
+      .signers([signer])
+      .rpc();
+
+    const execute = (pubkeys: anchor.web3.PublicKey[]) =>
+      program.methods
+        .executeProjectProposal()
+        .accounts({
+          executor: signer.publicKey,
+          project: projectPubkey,
+          proposal,
+          referralAccount: null,
+          projectAuthority: null,
+          admin: null,
+        })
+        .remainingAccounts(
+          pubkeys.map((pubkey) => ({
+            pubkey,
+            isSigner: false,
+            isWritable: true,
+          })),
+        )
+        .signers([signer])
+        .rpc();
+
+    // The executor can't swap in other referral accounts than the proposal names.
+    try {
+      await execute([...referralAccounts].reverse());
+
+      chai.assert(false, "should've failed but didn't");
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      const err: AnchorError = _err;
+      expect(err.error.errorCode.code).to.equal("MissingProposalAccount");
+    }
+
+    await execute(referralAccounts);
+
+    for (const [index, pubkey] of referralAccounts.entries()) {
+      const referralAccount = await program.account.referralAccount.fetch(
+        pubkey,
+      );
+      expect(referralAccount.shareBps).to.eql(shareBps[index]);
+    }
+  });
+});
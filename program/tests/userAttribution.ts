@@ -0,0 +1,169 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { expect } from "chai";
+
+import { Referral } from "../target/types/referral";
+import { fundAccount, expectError } from "./helpers/helpers";
+
+describe("user attribution", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.Referral as Program<Referral>;
+  const admin = anchor.workspace.Referral.provider.wallet;
+  let base: anchor.web3.Keypair;
+  let user: anchor.web3.Keypair;
+  let projectPubkey: anchor.web3.PublicKey;
+  let userAttributionPubkey: anchor.web3.PublicKey;
+  let firstReferralPubkey: anchor.web3.PublicKey;
+  let secondReferralPubkey: anchor.web3.PublicKey;
+
+  const initializeReferralAccount = async (name: string) => {
+    const partner = anchor.web3.Keypair.generate();
+    await fundAccount(partner.publicKey, provider);
+
+    const [referralAccountPubkey] =
+      anchor.web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("referral"), projectPubkey.toBuffer(), Buffer.from(name)],
+        program.programId,
+      );
+
+    await program.methods
+      .initializeReferralAccountWithName({ name })
+      .accounts({
+        payer: partner.publicKey,
+        project: projectPubkey,
+        partner: partner.publicKey,
+        referralAccount: referralAccountPubkey,
+      })
+      .signers([partner])
+      .rpc();
+
+    return referralAccountPubkey;
+  };
+
+  const attributeUser = (referralAccount: anchor.web3.PublicKey) =>
+    program.methods
+      .attributeUser()
+      .accounts({
+        payer: user.publicKey,
+        user: user.publicKey,
+        project: projectPubkey,
+        referralAccount,
+        userAttribution: userAttributionPubkey,
+      })
+      .signers([user])
+      .rpc();
+
+  const resolveUserAttribution = (
+    resolvedUser: anchor.web3.PublicKey = user.publicKey,
+  ) =>
+    program.methods
+      .resolveUserAttribution()
+      .accounts({
+        project: projectPubkey,
+        user: resolvedUser,
+        userAttribution: userAttributionPubkey,
+      })
+      .view();
+
+  const setAttributionPolicy = (allowOverride: boolean, expiry: number) =>
+    program.methods
+      .setProjectAttributionPolicy({
+        policy: { allowOverride, expiry: new anchor.BN(expiry) },
+      })
+      .accounts({
+        admin: admin.payer.publicKey,
+        project: projectPubkey,
+      })
+      .signers([admin.payer])
+      .rpc();
+
+  beforeEach(async () => {
+    base = anchor.web3.Keypair.generate();
+    user = anchor.web3.Keypair.generate();
+    await fundAccount(user.publicKey, provider);
+
+    [projectPubkey] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("project"), base.publicKey.toBuffer()],
+      program.programId,
+    );
+    [userAttributionPubkey] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("user_attribution"),
+        projectPubkey.toBuffer(),
+        user.publicKey.toBuffer(),
+      ],
+      program.programId,
+    );
+
+    await program.methods
+      .initializeProject({ name: "Referral", defaultShareBps: 5000 })
+      .accounts({
+        payer: admin.payer.publicKey,
+        base: base.publicKey,
+        admin: admin.payer.publicKey,
+        project: projectPubkey,
+      })
+      .signers([base, admin.payer])
+      .rpc();
+
+    firstReferralPubkey = await initializeReferralAccount("first");
+    secondReferralPubkey = await initializeReferralAccount("second");
+  });
+
+  it("keeps crediting the first referral account", async () => {
+    await attributeUser(firstReferralPubkey);
+
+    const userAttribution = await program.account.userAttribution.fetch(
+      userAttributionPubkey,
+    );
+    expect(userAttribution.user).to.eql(user.publicKey);
+    expect(userAttribution.referralAccount).to.eql(firstReferralPubkey);
+    expect(await resolveUserAttribution()).to.eql(firstReferralPubkey);
+
+    // Coming through the same referral again changes nothing.
+    await attributeUser(firstReferralPubkey);
+
+    await expectError(
+      attributeUser(secondReferralPubkey),
+      "UserAlreadyAttributed",
+    );
+  });
+
+  it("moves the attribution when the project allows overrides", async () => {
+    await setAttributionPolicy(true, 0);
+    await attributeUser(firstReferralPubkey);
+    await attributeUser(secondReferralPubkey);
+
+    expect(await resolveUserAttribution()).to.eql(secondReferralPubkey);
+  });
+
+  it("expired attributions resolve to nothing and can be replaced", async () => {
+    await setAttributionPolicy(false, 1);
+    await attributeUser(firstReferralPubkey);
+
+    await new Promise((resolve) => setTimeout(resolve, 3000));
+
+    expect(await resolveUserAttribution()).to.be.null;
+
+    await attributeUser(secondReferralPubkey);
+    expect(await resolveUserAttribution()).to.eql(secondReferralPubkey);
+  });
+
+  it("doesn't resolve the attribution of another user", async () => {
+    await attributeUser(firstReferralPubkey);
+
+    await expectError(
+      resolveUserAttribution(anchor.web3.Keypair.generate().publicKey),
+      "ConstraintSeeds",
+    );
+  });
+
+  it("raised if the expiry is negative", async () => {
+    await expectError(
+      setAttributionPolicy(false, -1),
+      "InvalidAttributionExpiry",
+    );
+  });
+});